@@ -95,9 +95,11 @@ impl ToQueryString for FightReplaySummary {
         str += format!("replay_uuid={}", self.replay_uuid().to_string()).as_str();
         str += format!("&blue_corner_uuid={}", self.blue_corner_uuid().to_string()).as_str();
         str += format!("&red_corner_uuid={}", self.red_corner_uuid().to_string()).as_str();
-        str += "&winner=";
         if let Some(winner) = self.winner() {
-            str += winner.to_string().as_str();
+            str += format!("&winner={}", winner).as_str();
+        }
+        if let Some(end_reason) = self.end_reason() {
+            str += format!("&end_reason={:?}", end_reason).as_str();
         }
 
         str
@@ -28,6 +28,9 @@ mod view {
     mod main_view;
     pub use main_view::main_view;
     mod shop_view;
+    mod marketplace_view;
+    mod trades_view;
+    mod workshop_view;
     mod warriors_view;
 }
 mod show {
@@ -43,10 +46,24 @@ mod show {
         mod show_item;
         mod show_body_part;
         mod show_body;
+        mod show_fight_end_reason;
+        mod show_tactics;
+        mod show_stance;
+        mod show_shield;
+        mod show_rarity;
+        mod show_consumable;
+        mod show_activity;
+        mod show_status_effect;
+        mod show_perk;
+        mod show_proficiency;
+        mod show_training;
+        mod show_xp_award;
+        mod show_personality;
     
         pub use main::{ShowSelf, ShowSelfExtended};
+        pub use show_activity::show_duration;
     }
-    pub use show_self::{ShowSelf, ShowSelfExtended};
+    pub use show_self::{ShowSelf, ShowSelfExtended, show_duration};
 
     mod show_replay {
         mod main;
@@ -1,23 +1,38 @@
 use shared::assault::attack_attempt::AttackThreshold;
 use shared::assault::parry_attempt::ParryThreshold;
+use shared::consumable::{Consumable, HasTonic, Tonic};
+use shared::equipment::shield::{CanHoldShield, OptionalMutableShield, Shield};
 use shared::equipment::weapon::{OptionalMutableWeapon, Weapon};
-use shared::experience::Experience;
-use shared::health::{Health, MutableHealth};
+use shared::experience::{Experience, GainExperience};
+use shared::health::{Activity, Health, MutableHealth, PassiveHealing};
 use shared::name::Name;
+use shared::stamina::{HasStamina, Stamina};
 use shared::stats::{StatKind, StatModifier, Stats, StatsManager};
+use shared::status_effect::{HasStatusEffects, StatusEffects};
 use shared::warrior::body::{Body, HasBody};
-use shared::warrior::Warrior;
+use shared::warrior::{HasPerks, HasPersonality, HasProficiencies, HasTactics, Perks, Personality, Proficiencies, Tactics, Warrior};
 
 use super::{ShowSelf, ShowSelfExtended};
 
 pub struct CharacterSheet<'a> {
     name: &'a str,
     health: &'a Health,
+    stamina: &'a Stamina,
     body: &'a Body,
     stats: &'a StatsManager,
     weapon: &'a Option<Weapon>,
+    shield: &'a Option<Shield>,
+    usable_shield: Option<&'a Shield>,
     experience: u64,
     level: u8,
+    attribute_points: u8,
+    tactics: &'a Tactics,
+    tonic: &'a Option<Tonic>,
+    activity: Activity,
+    status_effects: &'a StatusEffects,
+    perks: &'a Perks,
+    proficiencies: &'a Proficiencies,
+    personality: &'a Personality,
 }
 
 impl<'a> CharacterSheet<'a> {
@@ -25,21 +40,40 @@ impl<'a> CharacterSheet<'a> {
         Self {
             name: warrior.name(),
             health: warrior.health(),
+            stamina: warrior.stamina(),
             body: warrior.body(),
             stats: warrior.stats(),
             weapon: warrior.weapon(),
+            shield: warrior.shield(),
+            usable_shield: warrior.usable_shield(),
             experience: warrior.xp(),
             level: warrior.level(),
+            attribute_points: warrior.attribute_points(),
+            tactics: warrior.tactics(),
+            tonic: warrior.tonic(),
+            activity: warrior.activity(),
+            status_effects: warrior.status_effects(),
+            perks: warrior.perks(),
+            proficiencies: warrior.proficiencies(),
+            personality: warrior.personality(),
         }
     }
 }
 
 impl<'a> ShowSelf for CharacterSheet<'a> {
     fn show_self(&self) -> String {
+        let perks = self.perks.modifier(self.weapon);
+        let proficiency = self.proficiencies.rank(self.weapon);
         let mut stat_modifiers: Vec<Box<&dyn StatModifier>> = vec![Box::new(self.body)];
         if let Some(weapon) = self.weapon {
             stat_modifiers.push(Box::new(weapon));
         }
+        if let Some(shield) = self.usable_shield {
+            stat_modifiers.push(Box::new(shield));
+        }
+        stat_modifiers.push(Box::new(&perks));
+        stat_modifiers.push(Box::new(&proficiency));
+        stat_modifiers.push(Box::new(self.personality));
         let mut str = String::new();
         str += format!(
             "{}\nHP: {}/{}",
@@ -47,10 +81,18 @@ impl<'a> ShowSelf for CharacterSheet<'a> {
             self.health.current(),
             self.health.max(),
         ).as_str();
+        str += format!(
+            "\tStamina: {}/{}",
+            self.stamina.current(),
+            self.stamina.max(),
+        ).as_str();
         str += format!(
             "\nWeapon: {}",
             self.weapon.show_self(),
         ).as_str();
+        if let Some(shield) = self.shield {
+            str += format!("\nShield: {}", shield.show_self()).as_str();
+        }
         str += format!(
             "\nAT: {}\tPRD: {}\nCOU: {} ({})\tDEX: {} ({})\tSTR: {} ({})",
             self.attack_threshold(),
@@ -67,6 +109,9 @@ impl<'a> ShowSelf for CharacterSheet<'a> {
             self.level(),
             self.xp(),
         ).as_str();
+        if self.attribute_points > 0 {
+            str += format!(", {} attribute points to spend", self.attribute_points).as_str();
+        }
 
         str
     }
@@ -74,20 +119,38 @@ impl<'a> ShowSelf for CharacterSheet<'a> {
 
 impl<'a> AttackThreshold for CharacterSheet<'a> {
     fn attack_threshold(&self) -> u8 {
+        let perks = self.perks.modifier(self.weapon);
+        let proficiency = self.proficiencies.rank(self.weapon);
         let mut modifiers: Vec<Box<&dyn StatModifier>> = vec![Box::new(self.body)];
         if let Some(weapon) = self.weapon {
             modifiers.push(Box::new(weapon));
         }
+        if let Some(shield) = self.usable_shield {
+            modifiers.push(Box::new(shield));
+        }
+        modifiers.push(Box::new(self.stamina));
+        modifiers.push(Box::new(&perks));
+        modifiers.push(Box::new(&proficiency));
+        modifiers.push(Box::new(self.personality));
         self.stats.stat(&modifiers, &StatKind::Attack).value()
     }
 }
 
 impl<'a> ParryThreshold for CharacterSheet<'a> {
     fn parry_threshold(&self) -> u8 {
+        let perks = self.perks.modifier(self.weapon);
+        let proficiency = self.proficiencies.rank(self.weapon);
         let mut modifiers: Vec<Box<&dyn StatModifier>> = vec![Box::new(self.body)];
         if let Some(weapon) = self.weapon {
             modifiers.push(Box::new(weapon));
         }
+        if let Some(shield) = self.usable_shield {
+            modifiers.push(Box::new(shield));
+        }
+        modifiers.push(Box::new(self.stamina));
+        modifiers.push(Box::new(&perks));
+        modifiers.push(Box::new(&proficiency));
+        modifiers.push(Box::new(self.personality));
         self.stats.stat(&modifiers, &StatKind::Parry).value()
     }
 }
@@ -111,10 +174,22 @@ impl<'a> ShowSelfExtended for CharacterSheet<'a> {
             self.health.max(),
         ).as_str();
         str += format!("\nWeapon: {}", self.weapon.show_self_extended()).as_str();
+        str += format!("\nShield: {}", self.shield.show_self_extended()).as_str();
+        if self.shield.is_some() && self.usable_shield.is_none() {
+            str += " (can't be held)";
+        }
+        let perks = self.perks.modifier(self.weapon);
+        let proficiency = self.proficiencies.rank(self.weapon);
         let mut modifiers: Vec<Box<&dyn StatModifier>> = vec![Box::new(self.body)];
         if let Some(weapon) = self.weapon {
             modifiers.push(Box::new(weapon));
         }
+        if let Some(shield) = self.usable_shield {
+            modifiers.push(Box::new(shield));
+        }
+        modifiers.push(Box::new(&perks));
+        modifiers.push(Box::new(&proficiency));
+        modifiers.push(Box::new(self.personality));
         str += format!("\n\n{}", show_stats_with_modifiers(self.stats, &modifiers, &StatKind::Attack)).as_str();
         str += format!("\n{}", show_stats_with_modifiers(self.stats, &modifiers, &StatKind::Parry)).as_str();
         str += format!("\n{}", show_stats_with_modifiers(self.stats, &modifiers, &StatKind::Courage)).as_str();
@@ -128,6 +203,43 @@ impl<'a> ShowSelfExtended for CharacterSheet<'a> {
             self.level(),
             self.xp(),
         ).as_str();
+        if self.attribute_points > 0 {
+            str += format!(", {} attribute points to spend", self.attribute_points).as_str();
+        }
+        str += format!("\nBackground: {}", self.personality.show_self()).as_str();
+        if !self.personality.biography().is_empty() {
+            str += format!("\n{}", self.personality.biography()).as_str();
+        }
+        str += format!("\nTactics: {}", self.tactics.show_self()).as_str();
+        str += format!("\nActivity: {}", self.activity.show_self()).as_str();
+        let prosthetics: Vec<String> = self.body.prosthetics()
+            .iter()
+            .map(|prosthetic| prosthetic.show_self())
+            .collect();
+        if !prosthetics.is_empty() {
+            str += format!("\nProsthetics: {}", prosthetics.join(", ")).as_str();
+        }
+        if !self.proficiencies.list().is_empty() {
+            let proficiencies: Vec<String> = self.proficiencies.list()
+                .iter()
+                .map(|proficiency| proficiency.show_self())
+                .collect();
+            str += format!("\nProficiencies: {}", proficiencies.join(", ")).as_str();
+        }
+        if !self.perks.list().is_empty() {
+            let perks: Vec<String> = self.perks.list()
+                .iter()
+                .map(|perk| perk.show_self())
+                .collect();
+            str += format!("\nPerks: {}", perks.join(", ")).as_str();
+        }
+        if !self.status_effects.list().is_empty() {
+            str += format!("\nAfflicted by: {}", self.status_effects.show_self()).as_str();
+        }
+        if let Some(tonic) = self.tonic {
+            let consumable = Consumable::Tonic(tonic.stat().clone());
+            str += format!("\nUnder effect: {}", consumable.show_self_extended()).as_str();
+        }
 
         str
     }
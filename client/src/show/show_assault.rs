@@ -1,6 +1,11 @@
+use shared::assault::assault_consequence::AssaultConsequences;
 use shared::assault::assault_summary::AssaultSummary;
+use shared::equipment::protection::OptionalMutableProtection;
+use shared::warrior::body::body_part::{BodyPartKind, OptionalBodyPart};
 
-use super::{AssaultReplay, ShowReplay, ReplayActor};
+use crate::show::show_resolution::show_rupture;
+
+use super::{AssaultReplay, ShowReplay, ReplayActor, ShowSelf};
 
 impl AssaultReplay for AssaultSummary {
     fn assault_replay(
@@ -18,11 +23,14 @@ impl AssaultReplay for AssaultSummary {
             return miss.show_replay(assailant, victim, self.consequences());
         }
         if let Some(attack) = self.attack_success() {
-            let mut str = attack.show_replay(assailant, victim, self.consequences());
+            let mut str = match self.hit_location() {
+                Some(location) => show_hit_location(assailant, victim, location, self.consequences()),
+                None => attack.show_replay(assailant, victim, self.consequences()),
+            };
             if let Some(impossible_parry) = self.parry_not_possible() {
                 return format!(
                     "{}, {}",
-                    attack.show_replay(assailant, victim, self.consequences()),
+                    str,
                     impossible_parry.show_replay(assailant, victim, self.consequences()),
                 )
             }
@@ -36,6 +44,15 @@ impl AssaultReplay for AssaultSummary {
             }
             if let Some(_) = self.parry_success() {
                 str += ", but he managed to parry the blow";
+                if let Some(rupture_damages) = self.consequences().for_victim().shield_damages() {
+                    let shield = victim.shield().as_ref().unwrap();
+                    str = format!(
+                        "{} with his {}, which {}",
+                        str,
+                        shield.show_self(),
+                        show_rupture(shield, *rupture_damages),
+                    );
+                }
             }
             if let Some(critical) = self.parry_critical() {
                 str = format!(
@@ -52,3 +69,28 @@ impl AssaultReplay for AssaultSummary {
         return String::from("???");
     }
 }
+
+fn show_hit_location(
+    assailant: &dyn ReplayActor,
+    victim: &dyn ReplayActor,
+    location: &BodyPartKind,
+    consequences: &AssaultConsequences,
+) -> String {
+    let mut str = format!(
+        "{} strikes {}'s {}",
+        assailant.show_self(),
+        victim.show_self(),
+        location.show_self(),
+    );
+    if let Some(armor_damages) = consequences.for_victim().armor_damages() {
+        let body_part = victim.body().body_part(armor_damages.body_part_kind()).as_ref().unwrap();
+        let protection = body_part.protection().as_ref().unwrap();
+        str = format!(
+            "{}, his {} {}",
+            str,
+            protection.show_self(),
+            show_rupture(protection, armor_damages.damages()),
+        );
+    }
+    str
+}
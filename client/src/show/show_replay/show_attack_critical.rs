@@ -141,7 +141,7 @@ fn show_wound_genitals(
     victim: &dyn ReplayActor,
     consequences: &AssaultConsequences,
 ) -> String {
-    if let Some(_) = consequences.for_victim().duration_damages() {
+    if let Some(_) = consequences.for_victim().status_effect() {
         format!(
             "{} slices through {}'s {}",
             assailant.show_self(),
@@ -69,6 +69,12 @@ impl ShowReplay for CriticalParry {
                 victim.show_self(),
                 assailant.show_self(),
             ),
+            CriticalParry::ShieldBash => format!(
+                "{} bashes {} with his {} and throws him off-balance",
+                victim.show_self(),
+                assailant.show_self(),
+                victim.shield().as_ref().unwrap().show_self(),
+            ),
             CriticalParry::RegularParry => format!(
                 "{} parries the blow majestically",
                 victim.show_self(),
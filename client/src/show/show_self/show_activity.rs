@@ -0,0 +1,24 @@
+use shared::health::Activity;
+
+use super::ShowSelf;
+
+impl ShowSelf for Activity {
+    fn show_self(&self) -> String {
+        match self {
+            Activity::Resting => String::from("resting"),
+            Activity::Training => String::from("training"),
+            Activity::Infirmary => String::from("in the infirmary"),
+            Activity::Registered => String::from("registered to a tournament"),
+        }
+    }
+}
+
+// Rounded to the minute, an ETA does not need more
+pub fn show_duration(seconds: u64) -> String {
+    let minutes = seconds.div_ceil(60);
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{minutes}m"),
+        (hours, 0) => format!("{hours}h"),
+        (hours, minutes) => format!("{hours}h {minutes}m"),
+    }
+}
@@ -1,6 +1,6 @@
 use shared::{
     equipment::protection::OptionalMutableProtection,
-    warrior::body::body_part::BodyPart,
+    warrior::body::{body_part::BodyPart, prosthetic::Prosthetic},
 };
 
 use super::{ShowSelf, ShowSelfExtended};
@@ -8,6 +8,9 @@ use super::{ShowSelf, ShowSelfExtended};
 impl ShowSelfExtended for BodyPart {
     fn show_self_extended(&self) -> String {
         let mut str = self.kind().show_self();
+        if let Some(prosthetic) = self.prosthetic() {
+            str += format!(" ({})", prosthetic.show_self()).as_str();
+        }
         if self.is_broken() {
             str += " (broken)";
         }
@@ -18,3 +21,13 @@ impl ShowSelfExtended for BodyPart {
         str
     }
 }
+
+impl ShowSelf for Prosthetic {
+    fn show_self(&self) -> String {
+        match self {
+            Prosthetic::PegLeg => "peg leg".to_string(),
+            Prosthetic::HookHand => "hook hand".to_string(),
+            Prosthetic::GlassEye => "glass eye".to_string(),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use shared::consumable::{Consumable, HEALING_POTION_HP, TONIC_BONUS};
+use shared::stats::StatKind;
+
+use super::{ShowSelf, ShowSelfExtended};
+
+impl ShowSelf for Consumable {
+    fn show_self(&self) -> String {
+        match self {
+            Consumable::HealingPotion => "Healing Potion".to_string(),
+            Consumable::Bandage => "Bandage".to_string(),
+            Consumable::Antidote => "Antidote".to_string(),
+            Consumable::RageDraught => "Rage Draught".to_string(),
+            Consumable::Tonic(stat) => format!("Tonic of {}", match stat {
+                StatKind::Attack => "Attack",
+                StatKind::Parry => "Parry",
+                StatKind::Courage => "Courage",
+                StatKind::Dexterity => "Dexterity",
+                StatKind::Strength => "Strength",
+            }),
+        }
+    }
+}
+
+impl ShowSelfExtended for Consumable {
+    fn show_self_extended(&self) -> String {
+        let effect = match self {
+            Consumable::HealingPotion => format!("restores {HEALING_POTION_HP} HP"),
+            Consumable::Bandage => "stops a bleeding".to_string(),
+            Consumable::Tonic(_) => format!("+{TONIC_BONUS} for the next tournament"),
+            Consumable::Antidote => "cures poison".to_string(),
+            Consumable::RageDraught => "enrages for the first turns of the next fight".to_string(),
+        };
+        format!("{} ({})", self.show_self(), effect)
+    }
+}
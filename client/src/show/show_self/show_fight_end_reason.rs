@@ -0,0 +1,16 @@
+use shared::replay::FightEndReason;
+
+use super::ShowSelf;
+
+impl ShowSelf for FightEndReason {
+    fn show_self(&self) -> String {
+        match self {
+            FightEndReason::Death => String::from("death"),
+            FightEndReason::KnockOut => String::from("knock out"),
+            FightEndReason::Disarmed => String::from("disarmament"),
+            FightEndReason::Surrender => String::from("surrender"),
+            FightEndReason::JudgeDecision => String::from("judge decision on damages dealt"),
+            FightEndReason::Draw => String::from("draw, both fighters dealt the same damages"),
+        }
+    }
+}
@@ -7,6 +7,8 @@ impl ShowSelf for Item {
         match self {
             Item::Protection(protection) => protection.show_self(),
             Item::Weapon(weapon) => weapon.show_self(),
+            Item::Shield(shield) => shield.show_self(),
+            Item::Consumable(consumable) => consumable.show_self(),
         }
     }
 }
@@ -16,6 +18,8 @@ impl ShowSelfExtended for Item {
         match self {
             Item::Protection(protection) => protection.show_self_extended(),
             Item::Weapon(weapon) => weapon.show_self_extended(),
+            Item::Shield(shield) => shield.show_self_extended(),
+            Item::Consumable(consumable) => consumable.show_self_extended(),
         }
     }
 }
@@ -0,0 +1,41 @@
+use shared::warrior::Perk;
+
+use super::{ShowSelf, ShowSelfExtended};
+
+impl ShowSelf for Perk {
+    fn show_self(&self) -> String {
+        match self {
+            Perk::SwordMaster => String::from("Sword master"),
+            Perk::AxeMaster => String::from("Axe master"),
+            Perk::HammerMaster => String::from("Hammer master"),
+            Perk::IronSkin => String::from("Iron skin"),
+            Perk::FastHealer => String::from("Fast healer"),
+            Perk::Fearless => String::from("Fearless"),
+            Perk::Nimble => String::from("Nimble"),
+        }
+    }
+}
+
+impl ShowSelfExtended for Perk {
+    fn show_self_extended(&self) -> String {
+        let effect = match self {
+            Perk::SwordMaster => "+1 AT with swords",
+            Perk::AxeMaster => "+1 AT with axes",
+            Perk::HammerMaster => "+1 AT with hammers",
+            Perk::IronSkin => "+1 natural armor",
+            Perk::FastHealer => "+1 HP healed every interval",
+            Perk::Fearless => "+2 COU",
+            Perk::Nimble => "+1 DEX, +1 PRD",
+        };
+        match self.prerequisite() {
+            Some(prerequisite) => format!(
+                "{}: {} (level {}, after {})",
+                self.show_self(),
+                effect,
+                self.required_level(),
+                prerequisite.show_self(),
+            ),
+            None => format!("{}: {} (level {})", self.show_self(), effect, self.required_level()),
+        }
+    }
+}
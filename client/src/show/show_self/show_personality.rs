@@ -0,0 +1,43 @@
+use shared::warrior::{Background, Personality, Trait};
+
+use super::ShowSelf;
+
+impl ShowSelf for Background {
+    fn show_self(&self) -> String {
+        match self {
+            Background::Drifter => String::from("Drifter"),
+            Background::Farmhand => String::from("Farmhand"),
+            Background::Deserter => String::from("Deserter"),
+            Background::Blacksmith => String::from("Blacksmith"),
+            Background::Noble => String::from("Noble"),
+            Background::StreetUrchin => String::from("Street urchin"),
+            Background::Hunter => String::from("Hunter"),
+        }
+    }
+}
+
+impl ShowSelf for Trait {
+    fn show_self(&self) -> String {
+        match self {
+            Trait::LeftHanded => String::from("left-handed"),
+            Trait::Cowardly => String::from("cowardly"),
+            Trait::Reckless => String::from("reckless"),
+            Trait::Tough => String::from("tough"),
+            Trait::Nimble => String::from("nimble"),
+        }
+    }
+}
+
+impl ShowSelf for Personality {
+    fn show_self(&self) -> String {
+        let mut str = self.background().show_self();
+        if !self.traits().is_empty() {
+            let traits: Vec<String> = self.traits()
+                .iter()
+                .map(|warrior_trait| warrior_trait.show_self())
+                .collect();
+            str += format!(" ({})", traits.join(", ")).as_str();
+        }
+        str
+    }
+}
@@ -0,0 +1,34 @@
+use shared::equipment::weapon::WeaponKind;
+use shared::warrior::{Proficiency, ProficiencyRank};
+
+use super::ShowSelf;
+
+impl ShowSelf for WeaponKind {
+    fn show_self(&self) -> String {
+        match self {
+            WeaponKind::Sword => String::from("Sword"),
+            WeaponKind::GreatSword => String::from("Great sword"),
+            WeaponKind::Axe => String::from("Axe"),
+            WeaponKind::BattleAxe => String::from("Battle axe"),
+            WeaponKind::Hammer => String::from("Hammer"),
+            WeaponKind::WarHammer => String::from("War hammer"),
+        }
+    }
+}
+
+impl ShowSelf for ProficiencyRank {
+    fn show_self(&self) -> String {
+        match self {
+            ProficiencyRank::Novice => String::from("novice"),
+            ProficiencyRank::Trained => String::from("trained"),
+            ProficiencyRank::Skilled => String::from("skilled"),
+            ProficiencyRank::Master => String::from("master"),
+        }
+    }
+}
+
+impl ShowSelf for Proficiency {
+    fn show_self(&self) -> String {
+        format!("{} ({}, {}xp)", self.kind().show_self(), self.rank().show_self(), self.xp())
+    }
+}
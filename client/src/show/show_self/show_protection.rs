@@ -2,6 +2,7 @@ use shared::equipment::{protection::Protection, rupture::Rupture};
 use shared::name::Name;
 use shared::stats::{StatKind, StatModifier};
 
+use super::show_rarity::show_rarity_tag;
 use super::{ShowSelf, ShowSelfExtended};
 
 impl ShowSelf for Protection {
@@ -12,7 +13,8 @@ impl ShowSelf for Protection {
 
 impl ShowSelfExtended for Protection {
     fn show_self_extended(&self) -> String {
-        format!("{} (PR: {} RUP:{} COU: {} DEX: {})",
+        format!("{}{} (PR: {} RUP:{} COU: {} DEX: {})",
+            show_rarity_tag(self),
             self.show_self().as_str(),
             self.amount(),
             match self.rupture() { Some(rup) => rup.to_string(), None => "None".to_string() },
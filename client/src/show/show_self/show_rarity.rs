@@ -0,0 +1,23 @@
+use shared::loot::{Lootable, Rarity};
+
+use super::ShowSelf;
+
+impl ShowSelf for Rarity {
+    fn show_self(&self) -> String {
+        match self {
+            Rarity::Common => String::from("Common"),
+            Rarity::Uncommon => String::from("Uncommon"),
+            Rarity::Rare => String::from("Rare"),
+            Rarity::Epic => String::from("Epic"),
+            Rarity::Unique => String::from("Unique"),
+        }
+    }
+}
+
+// Common items are displayed without any tag
+pub fn show_rarity_tag(item: &dyn Lootable) -> String {
+    match item.rarity() {
+        Rarity::Common => String::new(),
+        rarity => format!("[{}] ", rarity.show_self()),
+    }
+}
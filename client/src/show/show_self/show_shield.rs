@@ -0,0 +1,26 @@
+use shared::equipment::{rupture::Rupture, shield::Shield};
+use shared::name::Name;
+use shared::stats::{StatKind, StatModifier};
+
+use super::show_rarity::show_rarity_tag;
+use super::{ShowSelf, ShowSelfExtended};
+
+impl ShowSelf for Shield {
+    fn show_self(&self) -> String {
+        self.name().to_string()
+    }
+}
+
+impl ShowSelfExtended for Shield {
+    fn show_self_extended(&self) -> String {
+        format!("{}{} (ABS: {} RUP: {} AT: {} PRD: {} DEX: {})",
+            show_rarity_tag(self),
+            self.show_self().as_str(),
+            self.absorption(),
+            match self.rupture() { Some(rup) => rup.to_string(), None => "None".to_string() },
+            self.value(&StatKind::Attack),
+            self.value(&StatKind::Parry),
+            self.value(&StatKind::Dexterity),
+        )
+    }
+}
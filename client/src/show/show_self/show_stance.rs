@@ -0,0 +1,29 @@
+use shared::warrior::{Stance, StanceCondition, StanceSwitch};
+
+use super::ShowSelf;
+
+impl ShowSelf for Stance {
+    fn show_self(&self) -> String {
+        match self {
+            Stance::Aggressive => String::from("aggressive"),
+            Stance::Defensive => String::from("defensive"),
+            Stance::Balanced => String::from("balanced"),
+            Stance::Berserk => String::from("berserk"),
+        }
+    }
+}
+
+impl ShowSelf for StanceCondition {
+    fn show_self(&self) -> String {
+        match self {
+            StanceCondition::Bleeding => String::from("when bleeding"),
+            StanceCondition::HealthBelowPercent(percent) => format!("below {percent}% HP"),
+        }
+    }
+}
+
+impl ShowSelf for StanceSwitch {
+    fn show_self(&self) -> String {
+        format!("goes {} {}", self.stance().show_self(), self.condition().show_self())
+    }
+}
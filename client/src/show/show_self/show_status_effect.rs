@@ -0,0 +1,38 @@
+use shared::status_effect::{StatusEffect, StatusEffectKind, StatusEffects};
+
+use super::ShowSelf;
+
+impl ShowSelf for StatusEffectKind {
+    fn show_self(&self) -> String {
+        match self {
+            StatusEffectKind::Bleeding => String::from("Bleeding"),
+            StatusEffectKind::Stunned => String::from("Stunned"),
+            StatusEffectKind::Poisoned => String::from("Poisoned"),
+            StatusEffectKind::Enraged => String::from("Enraged"),
+            StatusEffectKind::Blinded => String::from("Blinded"),
+            StatusEffectKind::Exhausted => String::from("Exhausted"),
+        }
+    }
+}
+
+impl ShowSelf for StatusEffect {
+    fn show_self(&self) -> String {
+        if self.stacks() > 1 {
+            format!("{} x{}", self.kind().show_self(), self.stacks())
+        } else {
+            self.kind().show_self()
+        }
+    }
+}
+
+impl ShowSelf for StatusEffects {
+    fn show_self(&self) -> String {
+        if self.list().is_empty() {
+            return String::from("None");
+        }
+        self.list().iter()
+            .map(|effect| effect.show_self())
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
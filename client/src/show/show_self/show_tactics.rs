@@ -0,0 +1,22 @@
+use shared::warrior::Tactics;
+
+use super::ShowSelf;
+
+impl ShowSelf for Tactics {
+    fn show_self(&self) -> String {
+        let mut rules = vec![format!("{} stance", self.stance().show_self())];
+        for switch in self.stance_switches() {
+            rules.push(switch.show_self());
+        }
+        if let Some(percent) = self.surrender_health_percent() {
+            rules.push(format!("surrenders below {percent}% HP"));
+        }
+        if self.surrender_on_severed_limb() {
+            rules.push(String::from("surrenders after losing a limb"));
+        }
+        if self.surrender_health_percent().is_none() && !self.surrender_on_severed_limb() {
+            rules.push(String::from("fights to the end"));
+        }
+        rules.join(", ")
+    }
+}
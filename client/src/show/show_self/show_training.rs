@@ -0,0 +1,39 @@
+use shared::stats::StatKind;
+use shared::warrior::TrainingProgram;
+
+use super::{ShowSelf, ShowSelfExtended};
+
+impl ShowSelf for StatKind {
+    fn show_self(&self) -> String {
+        match self {
+            StatKind::Attack => String::from("Attack"),
+            StatKind::Parry => String::from("Parry"),
+            StatKind::Courage => String::from("Courage"),
+            StatKind::Dexterity => String::from("Dexterity"),
+            StatKind::Strength => String::from("Strength"),
+        }
+    }
+}
+
+impl ShowSelf for TrainingProgram {
+    fn show_self(&self) -> String {
+        match self {
+            TrainingProgram::Sparring(hours) => format!("Sparring for {hours}h"),
+            TrainingProgram::StatPractice(stat) => format!(
+                "{} practice for {}h",
+                stat.show_self(),
+                self.hours(),
+            ),
+        }
+    }
+}
+
+impl ShowSelfExtended for TrainingProgram {
+    fn show_self_extended(&self) -> String {
+        let reward = match self {
+            TrainingProgram::Sparring(_) => format!("+{}xp", self.xp()),
+            TrainingProgram::StatPractice(stat) => format!("+1 {}", stat.show_self()),
+        };
+        format!("{} ({})", self.show_self(), reward)
+    }
+}
@@ -2,6 +2,7 @@ use shared::equipment::{rupture::Rupture, weapon::Weapon};
 use shared::name::Name;
 use shared::stats::{StatKind, StatModifier};
 
+use super::show_rarity::show_rarity_tag;
 use super::{ShowSelf, ShowSelfExtended};
 
 impl ShowSelf for Weapon {
@@ -12,7 +13,7 @@ impl ShowSelf for Weapon {
 
 impl ShowSelfExtended for Weapon {
     fn show_self_extended(&self) -> String {
-        let mut str = self.show_self();
+        let mut str = show_rarity_tag(self) + self.show_self().as_str();
         if self.is_sharp() {
             str += " Sh";
         } else {
@@ -27,12 +28,13 @@ impl ShowSelfExtended for Weapon {
         
         
         str += format!(
-            " DMG: {} RUP: {} AT: {} PRD: {} COU: {}",
+            " DMG: {} RUP: {} AT: {} PRD: {} COU: {} DEX: {}",
             self.additional_damages(),
             match self.rupture() { Some(rup) => rup.to_string(), None => "None".to_string() },
             self.value(&StatKind::Attack),
             self.value(&StatKind::Parry),
             self.value(&StatKind::Courage),
+            self.value(&StatKind::Dexterity),
         ).as_str();
 
         str
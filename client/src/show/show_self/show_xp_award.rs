@@ -0,0 +1,29 @@
+use shared::replay::{TournamentPlacement, XpAward, XpSource};
+
+use super::ShowSelf;
+
+impl ShowSelf for XpSource {
+    fn show_self(&self) -> String {
+        match self {
+            XpSource::Participation => String::from("Took part in the fight"),
+            XpSource::Surrender => String::from("Surrendered and lived"),
+            XpSource::Victory => String::from("Won the fight"),
+            XpSource::Kill => String::from("Killed the opponent"),
+            XpSource::CriticalHits(count) => format!("Landed {count} critical hit(s)"),
+            XpSource::CriticalParries(count) => format!("Made {count} critical parry(ies)"),
+            XpSource::Underdog(level_gap) => format!("Beat an opponent {level_gap} level(s) higher"),
+            XpSource::Placement(TournamentPlacement::Champion) => String::from("Won the tournament"),
+            XpSource::Placement(TournamentPlacement::RunnerUp) => String::from("Reached the final"),
+        }
+    }
+}
+
+impl ShowSelf for XpAward {
+    fn show_self(&self) -> String {
+        let mut str = format!("{} XP earned", self.total());
+        for source in self.sources() {
+            str += format!("\n  {}: +{}", source.show_self(), source.xp()).as_str();
+        }
+        str
+    }
+}
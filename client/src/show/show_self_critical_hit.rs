@@ -137,7 +137,7 @@ fn show_wound_genitals(
     idiot: &dyn ReplayActor,
     consequences: &IndividualConsequences,
 ) -> String {
-    if let Some(_) = consequences.duration_damages() {
+    if let Some(_) = consequences.status_effect() {
         format!(
             "{} slices through his own {}",
             idiot.show_self(),
@@ -7,10 +7,12 @@ use shared::health::MutableHealth;
 use shared::name::Name;
 use shared::tournament::Fighter;
 use shared::assault::assault_summary::AssaultSummary;
-use shared::assault::common_traits::TakeDamage;
 use shared::assault::end_turn_consequences::EndTurnConsequences;
 use shared::replay::turn_summary::TurnSummary;
+use shared::stamina::HasStamina;
+use shared::status_effect::HasStatusEffects;
 use shared::unique_entity::UniqueEntity;
+use shared::warrior::HasStance;
 
 use super::{AssaultReplay, ReplayActor, ShowSelf};
 
@@ -28,6 +30,7 @@ impl ShowTurnSummary for TurnSummary {
         blue_corner: &mut Fighter,
         red_corner: &mut Fighter,
     ) -> String {
+        let stances_str = apply_stances(self, blue_corner, red_corner);
         let assaults = self.assaults();
         let mut roles = get_roles(
             &assaults[0],
@@ -36,7 +39,7 @@ impl ShowTurnSummary for TurnSummary {
         );
         let assailant = roles.remove("assailant").unwrap();
         let victim = roles.remove("victim").unwrap();
-        let mut str = format!("{}", assaults[0].assault_replay(assailant, victim));
+        let mut str = format!("{}{}", stances_str, assaults[0].assault_replay(assailant, victim));
         assaults[0].consequences().apply(
             assailant,
             victim,
@@ -55,17 +58,22 @@ impl ShowTurnSummary for TurnSummary {
             victim,
         );
 
-        let first_assailant_turn_end_str = show_end_turn(self.first_assailant_turn_end(), blue_corner);
-        if !first_assailant_turn_end_str.is_empty() {
-            str = format!("{}\n{}", str, first_assailant_turn_end_str);
+        for fighter in [blue_corner as &mut Fighter, red_corner as &mut Fighter] {
+            let turn_end = if fighter.uuid() == self.first_assailant_uuid() {
+                self.first_assailant_turn_end()
+            } else {
+                self.second_assailant_turn_end()
+            };
+            let turn_end_str = show_end_turn(turn_end, fighter);
+            if !turn_end_str.is_empty() {
+                str = format!("{}\n{}", str, turn_end_str);
+            }
+            let was_winded = fighter.stamina().is_low();
+            turn_end.apply(fighter);
+            if !was_winded && fighter.stamina().is_low() {
+                str = format!("{}\n{} is out of breath", str, fighter.show_self());
+            }
         }
-        blue_corner.take_damage(self.first_assailant_turn_end().duration_damages());
-
-        let second_assailant_turn_end_str = show_end_turn(self.second_assailant_turn_end(), red_corner);
-        if !second_assailant_turn_end_str.is_empty() {
-            str = format!("{}\n{}", str, second_assailant_turn_end_str);
-        }
-        red_corner.take_damage(self.second_assailant_turn_end().duration_damages());
 
         str += "\n\n";
         str += display_fighters(blue_corner, red_corner).as_str();
@@ -75,6 +83,22 @@ impl ShowTurnSummary for TurnSummary {
     }
 }
 
+fn apply_stances(turn: &TurnSummary, blue_corner: &mut Fighter, red_corner: &mut Fighter) -> String {
+    let mut str = String::new();
+    for fighter in [blue_corner, red_corner] {
+        let stance = if fighter.uuid() == turn.first_assailant_uuid() {
+            turn.first_assailant_stance()
+        } else {
+            turn.second_assailant_stance()
+        };
+        if stance != fighter.stance() {
+            str += format!("{} takes a {} stance\n", fighter.show_self(), stance.show_self()).as_str();
+            fighter.set_stance(stance.clone());
+        }
+    }
+    str
+}
+
 fn display_fighters(blue_corner: &Fighter, red_corner: &Fighter) -> String {
     let mut str = String::new();
     str += format!("{}\t\t\t\t{}\n", blue_corner.name(), red_corner.name()).as_str();
@@ -90,6 +114,18 @@ fn display_fighters(blue_corner: &Fighter, red_corner: &Fighter) -> String {
         blue_corner.weapon().show_self(),
         red_corner.weapon().show_self(),
     ).as_str();
+    str += format!(
+        "Stance: {}\t\t\tStance: {}\n",
+        blue_corner.stance().show_self(),
+        red_corner.stance().show_self(),
+    ).as_str();
+    str += format!(
+        "Stamina: {}/{}\t\t\tStamina: {}/{}\n",
+        blue_corner.stamina().current(),
+        blue_corner.stamina().max(),
+        red_corner.stamina().current(),
+        red_corner.stamina().max(),
+    ).as_str();
     str += format!(
         "AT: {}\t\t\t\tAT: {}\n",
         blue_corner.attack_threshold(),
@@ -100,6 +136,13 @@ fn display_fighters(blue_corner: &Fighter, red_corner: &Fighter) -> String {
         blue_corner.parry_threshold(),
         red_corner.parry_threshold(),
     ).as_str();
+    if !blue_corner.status_effects().list().is_empty() || !red_corner.status_effects().list().is_empty() {
+        str += format!(
+            "\n{}\t\t\t{}",
+            blue_corner.status_effects().show_self(),
+            red_corner.status_effects().show_self(),
+        ).as_str();
+    }
     str
 }
 
@@ -120,10 +163,13 @@ fn get_roles<'a>(
 }
 
 fn show_end_turn(end: &EndTurnConsequences, victim: &dyn ReplayActor) -> String {
+    let mut lines = vec![];
     let damages = end.duration_damages();
     if damages > 0 {
-        format!("{} lost {} hp from duration damages", damages, victim.show_self())
-    } else {
-        String::from("")
+        lines.push(format!("{} lost {} hp from bleeding and poison", victim.show_self(), damages));
+    }
+    for effect in end.expired_effects() {
+        lines.push(format!("{} is no longer {}", victim.show_self(), effect.show_self().to_lowercase()));
     }
+    lines.join("\n")
 }
@@ -7,12 +7,12 @@ use shared::player::Player;
 use shared::replay::{FightReplay, FightReplaySummary};
 use shared::tournament::Tournament;
 use shared::unique_entity::UniqueEntity;
-use shared::warrior::{Warrior, WarriorCollection};
+use shared::warrior::{HasPersonality, Warrior, WarriorCollection};
 use uuid::Uuid;
 
 use crate::fetcher::{ApiFetcher, ToQueryString};
 use crate::prompt::{prompt_bool, swap_select_with_keys};
-use crate::show::ShowWarriorFightReplay;
+use crate::show::{ShowSelf, ShowWarriorFightReplay};
 
 use super::view_error::ViewError;
 
@@ -89,10 +89,16 @@ fn show_warrior_tournament(
         } else {
             warrior_lost = true;
             format!(
-                "Both warriors were eliminated during the {}th round, do you want to see a replay of the fight ?",
+                "No winner was declared for the {}th round, do you want to see a replay of the fight ?",
                 round_index + 1,
             )
         };
+        if let Some(end_reason) = fight_summary.end_reason() {
+            println!("The fight ended by {}", end_reason.show_self());
+        }
+        if let Some(award) = fight_summary.xp_award_for(warrior.uuid()) {
+            println!("{}", award.show_self());
+        }
         let show_fight_replay = prompt_bool(&prompt)?;
         if show_fight_replay {
             let path = format!("/replays/{tournament_uuid}/fight?{}", fight_summary.to_query_string());
@@ -121,6 +127,7 @@ fn replace_dead_warriors(session: &Session) -> Result<(), ViewError> {
             )?;
             let new_warrior: Warrior = fetcher.post("/player/warriors/random", ())?;
             println!("{} will join your team", new_warrior.name());
+            println!("{}", new_warrior.personality().biography());
         }
     }
     Ok(())
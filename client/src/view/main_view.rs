@@ -6,6 +6,9 @@ use crate::prompt::select_with_keys;
 
 use super::{
     shop_view,
+    marketplace_view::marketplace_view,
+    trades_view::trades_view,
+    workshop_view::workshop_view,
     register_to_tournament,
     returning_warriors,
     warriors_view::warriors_view,
@@ -16,13 +19,19 @@ enum MainViewChoice {
     ManageWarriors,
     ManageTournaments,
     ManageItems,
+    Marketplace,
+    Trades,
+    Workshop,
     // Quit,
 }
 
-const MAIN_VIEW_OPTIONS: [&'static MainViewChoice; 3] = [
+const MAIN_VIEW_OPTIONS: [&'static MainViewChoice; 6] = [
     &MainViewChoice::ManageTournaments,
     &MainViewChoice::ManageWarriors,
     &MainViewChoice::ManageItems,
+    &MainViewChoice::Marketplace,
+    &MainViewChoice::Trades,
+    &MainViewChoice::Workshop,
     // &MainViewChoice::Quit,
 ];
 
@@ -32,6 +41,9 @@ impl fmt::Display for MainViewChoice {
             MainViewChoice::ManageTournaments => write!(f, "Manage Tournaments"),
             MainViewChoice::ManageWarriors => write!(f, "Manage Warriors"),
             MainViewChoice::ManageItems => write!(f, "Manage Items"),
+            MainViewChoice::Marketplace => write!(f, "Visit the Marketplace"),
+            MainViewChoice::Trades => write!(f, "Trade with Players"),
+            MainViewChoice::Workshop => write!(f, "Salvage and Craft at the Workshop"),
             // MainViewChoice::Quit => write!(f, "Quit"),
         }
     }
@@ -56,7 +68,16 @@ pub fn main_view(session: &Session) -> Result<(), ViewError> {
                     },
                     MainViewChoice::ManageItems => {
                         shop_view::shop_view(session)?;
-                    }
+                    },
+                    MainViewChoice::Marketplace => {
+                        marketplace_view(session)?;
+                    },
+                    MainViewChoice::Trades => {
+                        trades_view(session)?;
+                    },
+                    MainViewChoice::Workshop => {
+                        workshop_view(session)?;
+                    },
                 }
             }
             None => {
@@ -0,0 +1,207 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use shared::{
+    auth::Session,
+    inventory::{HasInventory, Item},
+    marketplace::{Listing, ListingKind, NewListing, TRANSACTION_FEE_PERCENT},
+    player::Player,
+    unique_entity::UniqueEntity,
+};
+use uuid::Uuid;
+
+use crate::{
+    fetcher::ApiFetcher,
+    prompt::{prompt, select_with_keys},
+    show::ShowSelfExtended,
+};
+
+use super::ViewError;
+
+enum MarketplaceViewChoice {
+    Browse,
+    List,
+    MyListings,
+}
+
+const MARKETPLACE_VIEW_OPTIONS: [&MarketplaceViewChoice; 3] = [
+    &MarketplaceViewChoice::Browse,
+    &MarketplaceViewChoice::List,
+    &MarketplaceViewChoice::MyListings,
+];
+
+impl fmt::Display for MarketplaceViewChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Browse => write!(f, "Browse listings"),
+            Self::List => write!(f, "List an item"),
+            Self::MyListings => write!(f, "My listings"),
+        }
+    }
+}
+
+const LISTING_KIND_OPTIONS: [&ListingKind; 2] = [
+    &ListingKind::FixedPrice,
+    &ListingKind::Auction,
+];
+
+pub fn marketplace_view(session: &Session) -> Result<(), ViewError> {
+    loop {
+        let choice = select_with_keys(
+            "Welcome to the marketplace",
+            &MARKETPLACE_VIEW_OPTIONS,
+            |option| { format!("{}", option) }
+        )?;
+        match choice {
+            Some(MarketplaceViewChoice::Browse) => { browse_listings_view(session)?; },
+            Some(MarketplaceViewChoice::List) => { create_listing_view(session)?; },
+            Some(MarketplaceViewChoice::MyListings) => { my_listings_view(session)?; },
+            None => { return Ok(()) },
+        }
+    }
+}
+
+fn browse_listings_view(session: &Session) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    loop {
+        let player: Player = fetcher.get("/player")?;
+        let listings: Vec<Listing> = fetcher.get("/marketplace")?;
+        let options: Vec<&Listing> = listings.iter()
+            .filter(|listing| listing.seller() != player.uuid())
+            .collect();
+        let listing = match select_with_keys(
+            &format!("You have {} gold\nSelect a listing:", player.inventory().gold()),
+            &options,
+            |listing| { show_listing(listing) },
+        )? {
+            Some(listing) => listing,
+            None => return Ok(()),
+        };
+        match listing.kind() {
+            ListingKind::FixedPrice => {
+                if listing.price() > player.inventory().gold() {
+                    println!("You can't afford this item");
+                    continue;
+                }
+                fetcher.patch::<(), Player>(
+                    format!("/marketplace/{}/buy", listing.uuid()).as_str(),
+                    (),
+                )?;
+            },
+            ListingKind::Auction => {
+                let amount = prompt(&format!("Your bid (at least {} gold):", listing.min_bid()))?;
+                let amount = match amount.parse::<u32>() {
+                    Ok(amount) => amount,
+                    Err(_) => {
+                        println!("Not a valid amount");
+                        continue;
+                    },
+                };
+                let own_bid = listing.bid()
+                    .filter(|bid| bid.bidder() == player.uuid())
+                    .map_or(0, |bid| bid.amount());
+                if amount < listing.min_bid() || amount > player.inventory().gold() + own_bid {
+                    println!("You can't bid {amount} gold");
+                    continue;
+                }
+                fetcher.patch::<u32, Listing>(
+                    format!("/marketplace/{}/bid", listing.uuid()).as_str(),
+                    amount,
+                )?;
+            },
+        }
+    }
+}
+
+fn create_listing_view(session: &Session) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    let player: Player = fetcher.get("/player")?;
+    let options: Vec<(&Uuid, &Item)> = player.inventory().items()
+        .iter()
+        .collect();
+    let options_as_reference: Vec<&(&Uuid, &Item)> = options.iter().collect();
+    let slot_uuid = match select_with_keys(
+        "Select an item to list:",
+        &options_as_reference,
+        |(_, item)| { item.show_self_extended() },
+    )? {
+        Some((id, _)) => **id,
+        None => return Ok(()),
+    };
+    let kind = match select_with_keys(
+        "How do you want to sell it ?",
+        &LISTING_KIND_OPTIONS,
+        |kind| { show_listing_kind(kind).to_string() },
+    )? {
+        Some(kind) => kind.clone(),
+        None => return Ok(()),
+    };
+    let price = prompt(&format!(
+        "Price in gold (the marketplace keeps {}% of the sale):",
+        TRANSACTION_FEE_PERCENT,
+    ))?;
+    match price.parse::<u32>() {
+        Ok(price) if price > 0 => {
+            fetcher.post::<NewListing, Listing>("/marketplace", NewListing { slot_uuid, kind, price })?;
+        },
+        _ => println!("Not a valid price"),
+    }
+    Ok(())
+}
+
+fn my_listings_view(session: &Session) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    loop {
+        let player: Player = fetcher.get("/player")?;
+        let listings: Vec<Listing> = fetcher.get("/marketplace")?;
+        let options: Vec<&Listing> = listings.iter()
+            .filter(|listing| listing.seller() == player.uuid())
+            .collect();
+        let listing = match select_with_keys(
+            "Select a listing to cancel:",
+            &options,
+            |listing| { show_listing(listing) },
+        )? {
+            Some(listing) => listing,
+            None => return Ok(()),
+        };
+        if listing.bid().is_some() {
+            println!("This auction already has bids");
+            continue;
+        }
+        fetcher.delete::<Player>(format!("/marketplace/{}", listing.uuid()).as_str())?;
+    }
+}
+
+fn show_listing_kind(kind: &ListingKind) -> &'static str {
+    match kind {
+        ListingKind::FixedPrice => "Fixed price",
+        ListingKind::Auction => "Auction",
+    }
+}
+
+fn show_listing(listing: &Listing) -> String {
+    let price = match listing.kind() {
+        ListingKind::FixedPrice => format!("{} gold", listing.price()),
+        ListingKind::Auction => match listing.bid() {
+            Some(bid) => format!("bid at {} gold", bid.amount()),
+            None => format!("starts at {} gold", listing.price()),
+        },
+    };
+    format!(
+        "{} - {} {} by {}, {}",
+        listing.item().show_self_extended(),
+        show_listing_kind(listing.kind()),
+        price,
+        listing.seller_name(),
+        show_time_left(listing.expires_at()),
+    )
+}
+
+fn show_time_left(expires_at: i64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64);
+    let remaining_minutes = (expires_at - now).max(0) / 60;
+    format!("ends in {}h{:02}", remaining_minutes / 60, remaining_minutes % 60)
+}
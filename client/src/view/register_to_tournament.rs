@@ -1,10 +1,11 @@
 use shared::auth::Session;
+use shared::health::{Activity, PassiveHealing};
 use shared::name::Name;
 use shared::player::Player;
 use shared::tournament::contestant::TournamentContestant;
 use shared::tournament::Tournament;
 use shared::unique_entity::UniqueEntity;
-use shared::warrior::{Warrior, WarriorCollection};
+use shared::warrior::{HasTraining, Warrior, WarriorCollection};
 use uuid::Uuid;
 
 use crate::fetcher::ApiFetcher;
@@ -30,6 +31,8 @@ pub fn register_to_tournament(session: &Session) -> Result<(), ViewError> {
     let mut warriors: Vec<&Warrior> = player.warriors()
         .iter()
         .filter(|w| w.current_tournament().is_none())
+        .filter(|w| w.activity() != Activity::Infirmary)
+        .filter(|w| w.training().is_none())
         .collect();
     let warrior = swap_select_with_arrows(
         "Select a warrior:",
@@ -1,25 +1,49 @@
 use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use shared::{
     auth::Session,
-    inventory::{GoldValue, HasInventory, Item},
+    equipment::{
+        protection::OptionalMutableProtection,
+        repair::{EquipmentSlot, RepairOutcome, Repairable},
+        shield::OptionalMutableShield,
+        weapon::OptionalMutableWeapon,
+    },
+    inventory::{GoldValue, HasInventory, Item, StashedItem, CAPACITY_UPGRADE_SLOTS},
+    name::Name,
     player::Player,
     shop::Shop,
+    unique_entity::UniqueEntity,
+    warrior::{
+        body::{body_part::{OptionalBodyPart, PROTECTABLE_BODY_PARTS}, HasBody},
+        Warrior,
+        WarriorCollection,
+    },
 };
 use uuid::Uuid;
 
-use crate::{fetcher::ApiFetcher, prompt::select_with_keys, show::ShowSelfExtended};
+use crate::{
+    fetcher::ApiFetcher,
+    prompt::{prompt_bool, select_with_keys},
+    show::ShowSelfExtended,
+};
 
 use super::ViewError;
 
 enum ShopViewChoice {
     Buy,
     Sell,
+    Repair,
+    UpgradeInventory,
+    Stash,
 }
 
-const SHOP_VIEW_OPTIONS: [&'static ShopViewChoice; 2] = [
+const SHOP_VIEW_OPTIONS: [&'static ShopViewChoice; 5] = [
     &ShopViewChoice::Buy,
     &ShopViewChoice::Sell,
+    &ShopViewChoice::Repair,
+    &ShopViewChoice::UpgradeInventory,
+    &ShopViewChoice::Stash,
 ];
 
 impl fmt::Display for ShopViewChoice {
@@ -27,6 +51,9 @@ impl fmt::Display for ShopViewChoice {
         match self {
             ShopViewChoice::Buy => write!(f, "Buy"),
             Self::Sell => write!(f, "Sell"),
+            Self::Repair => write!(f, "Repair at the blacksmith"),
+            Self::UpgradeInventory => write!(f, "Buy a bigger bag"),
+            Self::Stash => write!(f, "Claim stashed items"),
         }
     }
 }
@@ -34,7 +61,7 @@ impl fmt::Display for ShopViewChoice {
 pub fn shop_view(session: &Session) -> Result<(), ViewError> {
     loop {
         let choice = select_with_keys(
-            "Do you want to sell or buy ?",
+            "Do you want to buy, sell or repair ?",
             &SHOP_VIEW_OPTIONS,
             |option| { format!("{}", option) }
         )?;
@@ -43,6 +70,9 @@ pub fn shop_view(session: &Session) -> Result<(), ViewError> {
                 match c {
                     ShopViewChoice::Buy => { buy_items_view(session)?; },
                     ShopViewChoice::Sell => { sell_items_view(session)?; },
+                    ShopViewChoice::Repair => { repair_view(session)?; },
+                    ShopViewChoice::UpgradeInventory => { upgrade_inventory_view(session)?; },
+                    ShopViewChoice::Stash => { stash_view(session)?; },
                 }
             }
             None => { return Ok(()) }
@@ -53,25 +83,130 @@ pub fn shop_view(session: &Session) -> Result<(), ViewError> {
 fn buy_items_view(session: &Session) -> Result<(), ViewError> {
     let fetcher = ApiFetcher::new(session);
     let mut player: Player = fetcher.get("/player")?;
-    let shop: Shop = ApiFetcher::new(session).get("/shop")?;
-    let options: Vec<(&Uuid, &Item)> = shop.inventory().items()
-        .iter()
-        .collect();
-    let options_as_reference: Vec<&(&Uuid, &Item)> = options.iter().collect();
-    while let Some((id, _)) = select_with_keys(
-        &format!("You have {} gold\nSelect an item:", player.inventory().gold()),
-        &options_as_reference,
-        |(_, item)| { format!("{} ({} gold)", item.show_self_extended(), item.gold_value()) },
-    )? {
+    let mut shop: Shop = fetcher.get("/shop")?;
+    let next_restock: i64 = fetcher.get("/shop/next-restock")?;
+    println!("{}", show_next_restock(next_restock));
+    loop {
+        let options: Vec<(&Uuid, &Item)> = shop.inventory().items()
+            .iter()
+            .collect();
+        let options_as_reference: Vec<&(&Uuid, &Item)> = options.iter().collect();
+        let id = match select_with_keys(
+            &format!("You have {} gold\nSelect an item:", player.inventory().gold()),
+            &options_as_reference,
+            |(id, item)| {
+                format!(
+                    "{} x{} ({} gold)",
+                    item.show_self_extended(),
+                    shop.stock(id).map_or(0, |stock| stock.count()),
+                    shop.price(id).unwrap_or(item.gold_value()),
+                )
+            },
+        )? {
+            Some((id, _)) => **id,
+            None => return Ok(()),
+        };
+        if shop.price(&id).is_some_and(|price| price > player.inventory().gold()) {
+            println!("You can't afford this item");
+            continue;
+        }
+        if player.inventory().free_slots() == 0 {
+            println!("Your inventory is full");
+            continue;
+        }
         fetcher.patch::<(), Item>(
-            format!("/player/buy-item/{}", id.to_string()).as_str(),
+            format!("/player/buy-item/{}", id).as_str(),
             (),
         )?;
         player = fetcher.get("/player")?;
+        shop = fetcher.get("/shop")?;
+    }
+}
+
+fn show_next_restock(next_restock: i64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64);
+    let remaining_minutes = (next_restock - now).max(0) / 60;
+    format!(
+        "The merchant expects new goods in {}h{:02}",
+        remaining_minutes / 60,
+        remaining_minutes % 60,
+    )
+}
+
+fn upgrade_inventory_view(session: &Session) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    let player: Player = fetcher.get("/player")?;
+    let inventory = player.inventory();
+    println!("Your inventory has {} slots", inventory.capacity());
+    let cost = match inventory.upgrade_cost() {
+        None => {
+            println!("Your inventory cannot be upgraded anymore");
+            return Ok(());
+        },
+        Some(cost) => cost,
+    };
+    if !prompt_bool(&format!(
+        "Add {CAPACITY_UPGRADE_SLOTS} slots for {cost} gold ? (you have {} gold)",
+        inventory.gold(),
+    ))? {
+        return Ok(());
+    }
+    if cost > inventory.gold() {
+        println!("You can't afford this upgrade");
+        return Ok(());
     }
+    fetcher.patch::<(), u32>("/player/upgrade-inventory", ())?;
     Ok(())
 }
 
+fn stash_view(session: &Session) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    loop {
+        let player: Player = fetcher.get("/player")?;
+        let stash: Vec<(usize, &StashedItem)> = player.inventory().stash()
+            .iter()
+            .enumerate()
+            .collect();
+        if stash.is_empty() {
+            println!("Your stash is empty");
+            return Ok(());
+        }
+        let options: Vec<&(usize, &StashedItem)> = stash.iter().collect();
+        let index = match select_with_keys(
+            &format!(
+                "Items that did not fit in your inventory ({} free slots):",
+                player.inventory().free_slots(),
+            ),
+            &options,
+            |(_, stashed)| {
+                format!(
+                    "{} ({})",
+                    stashed.item().show_self_extended(),
+                    show_stash_expiry(stashed.expires_at()),
+                )
+            },
+        )? {
+            Some((index, _)) => *index,
+            None => return Ok(()),
+        };
+        if player.inventory().free_slots() == 0 {
+            println!("Your inventory is full");
+            continue;
+        }
+        fetcher.patch::<usize, Uuid>("/player/claim-stashed", index)?;
+    }
+}
+
+fn show_stash_expiry(expires_at: i64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64);
+    let remaining_hours = (expires_at - now).max(0) / 3600;
+    format!("lost in {}d{:02}h", remaining_hours / 24, remaining_hours % 24)
+}
+
 fn sell_items_view(session: &Session) -> Result<(), ViewError> {
     loop {
         let fetcher = ApiFetcher::new(session);
@@ -98,3 +233,103 @@ fn sell_items_view(session: &Session) -> Result<(), ViewError> {
         }
     }
 }
+
+enum RepairTarget<'a> {
+    InventorySlot(&'a Uuid),
+    Equipment(&'a Warrior, EquipmentSlot),
+}
+
+struct RepairOption<'a> {
+    description: String,
+    cost: u32,
+    target: RepairTarget<'a>,
+}
+
+fn equipment_repair_options(warrior: &Warrior) -> Vec<RepairOption<'_>> {
+    let mut options = vec![];
+    if let Some(weapon) = warrior.weapon() {
+        if weapon.needs_repair() {
+            options.push(RepairOption {
+                description: format!("{}'s {}", warrior.name(), weapon.show_self_extended()),
+                cost: weapon.repair_cost(),
+                target: RepairTarget::Equipment(warrior, EquipmentSlot::Weapon),
+            });
+        }
+    }
+    if let Some(shield) = warrior.shield() {
+        if shield.needs_repair() {
+            options.push(RepairOption {
+                description: format!("{}'s {}", warrior.name(), shield.show_self_extended()),
+                cost: shield.repair_cost(),
+                target: RepairTarget::Equipment(warrior, EquipmentSlot::Shield),
+            });
+        }
+    }
+    for body_part_kind in PROTECTABLE_BODY_PARTS {
+        if let Some(body_part) = warrior.body().body_part(&body_part_kind) {
+            if let Some(protection) = body_part.protection() {
+                if protection.needs_repair() {
+                    options.push(RepairOption {
+                        description: format!("{}'s {}", warrior.name(), protection.show_self_extended()),
+                        cost: protection.repair_cost(),
+                        target: RepairTarget::Equipment(warrior, EquipmentSlot::Protection(body_part_kind)),
+                    });
+                }
+            }
+        }
+    }
+    options
+}
+
+fn repair_view(session: &Session) -> Result<(), ViewError> {
+    loop {
+        let fetcher = ApiFetcher::new(session);
+        let player: Player = fetcher.get("/player")?;
+        let mut options: Vec<RepairOption> = player.inventory().items()
+            .iter()
+            .filter_map(|(id, item)| item.repairable().map(|repairable| (id, item, repairable)))
+            .filter(|(_, _, repairable)| repairable.needs_repair())
+            .map(|(id, item, repairable)| RepairOption {
+                description: item.show_self_extended(),
+                cost: repairable.repair_cost(),
+                target: RepairTarget::InventorySlot(id),
+            })
+            .collect();
+        for warrior in player.warriors() {
+            options.append(&mut equipment_repair_options(warrior));
+        }
+        if options.is_empty() {
+            println!("The blacksmith finds nothing to repair");
+            return Ok(());
+        }
+        let options_as_reference: Vec<&RepairOption> = options.iter().collect();
+        let option = match select_with_keys(
+            &format!("You have {} gold\nSelect an item to repair:", player.inventory().gold()),
+            &options_as_reference,
+            |option| { format!("{} ({} gold)", option.description, option.cost) },
+        )? {
+            Some(option) => option,
+            None => return Ok(()),
+        };
+        if option.cost > player.inventory().gold() {
+            println!("You can't afford this repair");
+            continue;
+        }
+        let outcome: RepairOutcome = match &option.target {
+            RepairTarget::InventorySlot(id) => fetcher.patch::<(), RepairOutcome>(
+                format!("/player/repair-item/{}", id).as_str(),
+                (),
+            )?,
+            RepairTarget::Equipment(warrior, slot) => fetcher.patch::<&EquipmentSlot, RepairOutcome>(
+                format!("/player/warriors/{}/repair", warrior.uuid()).as_str(),
+                slot,
+            )?,
+        };
+        match outcome {
+            RepairOutcome::Repaired => println!("The blacksmith repaired your item"),
+            RepairOutcome::Masterwork => println!(
+                "The blacksmith outdid himself, your item is sturdier than new!"
+            ),
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt;
+
+use shared::{
+    auth::Session,
+    inventory::{HasInventory, Item},
+    player::Player,
+    trade::{NewTradeOffer, TradeOffer, TradeStatus},
+    unique_entity::UniqueEntity,
+};
+use uuid::Uuid;
+
+use crate::{
+    fetcher::ApiFetcher,
+    prompt::{prompt, prompt_bool, select_with_keys, swap_select_with_keys},
+    show::ShowSelfExtended,
+};
+
+use super::ViewError;
+
+enum TradesViewChoice {
+    Propose,
+    Incoming,
+    Outgoing,
+    History,
+}
+
+const TRADES_VIEW_OPTIONS: [&TradesViewChoice; 4] = [
+    &TradesViewChoice::Propose,
+    &TradesViewChoice::Incoming,
+    &TradesViewChoice::Outgoing,
+    &TradesViewChoice::History,
+];
+
+impl fmt::Display for TradesViewChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Propose => write!(f, "Propose a trade or a gift"),
+            Self::Incoming => write!(f, "Offers made to me"),
+            Self::Outgoing => write!(f, "My offers"),
+            Self::History => write!(f, "Completed trades"),
+        }
+    }
+}
+
+pub fn trades_view(session: &Session) -> Result<(), ViewError> {
+    loop {
+        let choice = select_with_keys(
+            "Trade with other players",
+            &TRADES_VIEW_OPTIONS,
+            |option| { format!("{}", option) }
+        )?;
+        match choice {
+            Some(TradesViewChoice::Propose) => { propose_offer_view(session)?; },
+            Some(TradesViewChoice::Incoming) => { incoming_offers_view(session)?; },
+            Some(TradesViewChoice::Outgoing) => { outgoing_offers_view(session)?; },
+            Some(TradesViewChoice::History) => { history_view(session)?; },
+            None => { return Ok(()) },
+        }
+    }
+}
+
+fn propose_offer_view(session: &Session) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    let player: Player = fetcher.get("/player")?;
+    let recipient_username = prompt("Username of the player:")?;
+    let items: Vec<(&Uuid, &Item)> = player.inventory().items()
+        .iter()
+        .collect();
+    let mut options: Vec<&(&Uuid, &Item)> = items.iter().collect();
+    let mut slot_uuids = vec![];
+    while let Some((id, _)) = swap_select_with_keys(
+        "Select items to offer, then leave to go on:",
+        &mut options,
+        |(_, item)| { item.show_self_extended() },
+    )? {
+        slot_uuids.push(**id);
+    }
+    let gold = prompt(&format!("Gold to offer (you have {}):", player.inventory().gold()))?;
+    let requested_gold = prompt("Gold asked in return (0 for a gift):")?;
+    let (gold, requested_gold) = match (gold.parse::<u32>(), requested_gold.parse::<u32>()) {
+        (Ok(gold), Ok(requested_gold)) => (gold, requested_gold),
+        _ => {
+            println!("Not a valid amount");
+            return Ok(());
+        },
+    };
+    if gold > player.inventory().gold() {
+        println!("You don't have {gold} gold");
+        return Ok(());
+    }
+    if slot_uuids.is_empty() && gold == 0 {
+        println!("You have nothing to offer");
+        return Ok(());
+    }
+    fetcher.post::<NewTradeOffer, TradeOffer>("/trades", NewTradeOffer {
+        recipient_username,
+        slot_uuids,
+        gold,
+        requested_gold,
+    })?;
+    Ok(())
+}
+
+fn incoming_offers_view(session: &Session) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    loop {
+        let player: Player = fetcher.get("/player")?;
+        let offers: Vec<TradeOffer> = fetcher.get("/trades")?;
+        let options: Vec<&TradeOffer> = offers.iter()
+            .filter(|offer| offer.recipient() == player.uuid())
+            .filter(|offer| offer.status() == &TradeStatus::Pending)
+            .collect();
+        let offer = match select_with_keys(
+            &format!("You have {} gold\nSelect an offer:", player.inventory().gold()),
+            &options,
+            |offer| { show_offer(offer) },
+        )? {
+            Some(offer) => offer,
+            None => return Ok(()),
+        };
+        if prompt_bool("Do you accept this offer ?")? {
+            if offer.requested_gold() > player.inventory().gold() {
+                println!("You can't afford this offer");
+                continue;
+            }
+            if offer.offered().items().len() > player.inventory().free_slots() {
+                println!("You don't have enough room in your inventory");
+                continue;
+            }
+            fetcher.patch::<(), TradeOffer>(
+                format!("/trades/{}/accept", offer.uuid()).as_str(),
+                (),
+            )?;
+        } else {
+            fetcher.patch::<(), TradeOffer>(
+                format!("/trades/{}/decline", offer.uuid()).as_str(),
+                (),
+            )?;
+        }
+    }
+}
+
+fn outgoing_offers_view(session: &Session) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    loop {
+        let player: Player = fetcher.get("/player")?;
+        let offers: Vec<TradeOffer> = fetcher.get("/trades")?;
+        let options: Vec<&TradeOffer> = offers.iter()
+            .filter(|offer| offer.sender() == player.uuid())
+            .filter(|offer| offer.status() != &TradeStatus::Accepted)
+            .collect();
+        let offer = match select_with_keys(
+            "Select an offer to take back:",
+            &options,
+            |offer| { show_offer(offer) },
+        )? {
+            Some(offer) => offer,
+            None => return Ok(()),
+        };
+        if offer.offered().items().len() > player.inventory().free_slots() {
+            println!("You don't have enough room in your inventory");
+            continue;
+        }
+        fetcher.delete::<Player>(format!("/trades/{}", offer.uuid()).as_str())?;
+    }
+}
+
+fn history_view(session: &Session) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    let offers: Vec<TradeOffer> = fetcher.get("/trades")?;
+    let completed: Vec<&TradeOffer> = offers.iter()
+        .filter(|offer| offer.status() == &TradeStatus::Accepted)
+        .collect();
+    if completed.is_empty() {
+        println!("No completed trade yet");
+    }
+    for offer in completed {
+        println!("{}", show_offer(offer));
+    }
+    Ok(())
+}
+
+fn show_offer(offer: &TradeOffer) -> String {
+    let mut offered: Vec<String> = offer.offered().items()
+        .values()
+        .map(|item| item.show_self_extended())
+        .collect();
+    if offer.offered().gold() > 0 {
+        offered.push(format!("{} gold", offer.offered().gold()));
+    }
+    let terms = if offer.is_gift() {
+        String::from("as a gift")
+    } else {
+        format!("for {} gold", offer.requested_gold())
+    };
+    let status = match offer.status() {
+        TradeStatus::Pending => "",
+        TradeStatus::Declined => " (declined)",
+        TradeStatus::Accepted => " (accepted)",
+    };
+    format!(
+        "{} offers {} to {} {}{}",
+        offer.sender_name(),
+        offered.join(", "),
+        offer.recipient_name(),
+        terms,
+        status,
+    )
+}
@@ -1,35 +1,58 @@
 use std::fmt;
 
 use shared::auth::Session;
+use shared::consumable::{Consumable, UseConsumable};
+use shared::equipment::loadout::{BulkEquip, EquipRequest, HasLoadouts, Loadout};
 use shared::equipment::protection::{CanWearProtection, OptionalMutableProtection, Protection};
+use shared::equipment::shield::{CanHoldShield, Shield};
 use shared::equipment::weapon::Weapon;
 use shared::experience::{Experience, GainExperience};
-use shared::inventory::{HasInventory, Item};
+use shared::health::{Activity, HealingConfig, PassiveHealing, ACTIVITIES};
+use shared::inventory::{GoldValue, HasInventory, Item};
 use shared::name::Name;
 use shared::player::Player;
 use shared::stats::StatKind;
 use shared::tournament::contestant::TournamentContestant;
 use shared::unique_entity::UniqueEntity;
-use shared::warrior::body::body_part::{BodyPart, BodyPartKind, OptionalBodyPart, PROTECTABLE_BODY_PARTS};
+use shared::warrior::body::body_part::{BodyPart, BodyPartKind, BodySide, OptionalBodyPart, PROTECTABLE_BODY_PARTS};
+use shared::warrior::body::prosthetic::{ProstheticRequest, PROSTHETICS};
+use shared::warrior::body::recovery::{Treatment, TreatmentRequest, TREATMENTS};
 use shared::warrior::body::HasBody;
-use shared::warrior::{Warrior, WarriorCollection};
+use shared::warrior::{perk_slots, respec_cost, stat_practice_limit, HasPerks, HasTactics, HasTraining, Perk, Stance, StanceCondition, StanceSwitch, Tactics, TrainingProgram, Warrior, WarriorCollection, PERKS, SPARRING_DURATIONS};
 use uuid::Uuid;
 
 use crate::fetcher::ApiFetcher;
-use crate::prompt::{select_with_arrows, select_with_keys};
-use crate::show::{CharacterSheet, ShowSelf, ShowSelfExtended};
+use crate::prompt::{prompt, prompt_bool, select_with_arrows, select_with_keys};
+use crate::show::{show_duration, CharacterSheet, ShowSelf, ShowSelfExtended};
 
 use super::ViewError;
 
 enum WarriorManagementChoice {
     ReplaceWeapon,
     EquipProtection,
+    EquipArmorSet,
+    ManageLoadouts,
+    EquipShield,
     LevelUp,
+    SpendAttributePoints,
+    SetTactics,
+    UseConsumable,
+    VisitHealer,
+    FitProsthetic,
+    SetActivity,
+    ManagePerks,
+    Train,
 }
 
-const CHOICES: [&'static WarriorManagementChoice; 2] = [
+const CHOICES: [&'static WarriorManagementChoice; 8] = [
     &WarriorManagementChoice::ReplaceWeapon,
     &WarriorManagementChoice::EquipProtection,
+    &WarriorManagementChoice::EquipArmorSet,
+    &WarriorManagementChoice::ManageLoadouts,
+    &WarriorManagementChoice::SetTactics,
+    &WarriorManagementChoice::UseConsumable,
+    &WarriorManagementChoice::SetActivity,
+    &WarriorManagementChoice::ManagePerks,
 ];
 
 impl fmt::Display for WarriorManagementChoice {
@@ -37,13 +60,26 @@ impl fmt::Display for WarriorManagementChoice {
         match self {
             WarriorManagementChoice::ReplaceWeapon => write!(f, "Replace weapon"),
             WarriorManagementChoice::EquipProtection => write!(f, "Equip protection"),
+            WarriorManagementChoice::EquipArmorSet => write!(f, "Equip a full set of armor"),
+            WarriorManagementChoice::ManageLoadouts => write!(f, "Manage loadouts"),
+            WarriorManagementChoice::EquipShield => write!(f, "Equip shield"),
             WarriorManagementChoice::LevelUp => write!(f, "Level Up"),
+            WarriorManagementChoice::SpendAttributePoints => write!(f, "Spend attribute points"),
+            WarriorManagementChoice::SetTactics => write!(f, "Set tactics"),
+            WarriorManagementChoice::UseConsumable => write!(f, "Use a consumable"),
+            WarriorManagementChoice::VisitHealer => write!(f, "Visit the healer"),
+            WarriorManagementChoice::FitProsthetic => write!(f, "Fit a prosthetic"),
+            WarriorManagementChoice::SetActivity => write!(f, "Change activity"),
+            WarriorManagementChoice::ManagePerks => write!(f, "Manage perks"),
+            WarriorManagementChoice::Train => write!(f, "Send to the training grounds"),
         }
     }
 }
 
 pub fn warriors_view(session: &Session) -> Result<(), ViewError> {
     let fetcher = ApiFetcher::new(session);
+    let healing_config: HealingConfig = fetcher.get("/player/healing-config")?;
+    show_training_reports(session)?;
     loop {
         let player: Player = fetcher.get("/player")?;
         let warriors: Vec<&Warrior> = player.warriors()
@@ -63,11 +99,33 @@ pub fn warriors_view(session: &Session) -> Result<(), ViewError> {
                 format!("/player/warriors/{}", warrior_uuid.to_string()).as_str()
             )?;
             let mut choices = CHOICES.to_vec();
+            if warrior.can_hold_shield() {
+                choices.push(&WarriorManagementChoice::EquipShield);
+            }
             if warrior.can_level_up() {
                 choices.push(&WarriorManagementChoice::LevelUp);
             }
+            if warrior.attribute_points() > 0 {
+                choices.push(&WarriorManagementChoice::SpendAttributePoints);
+            }
+            if !warrior.body().broken_parts().is_empty() {
+                choices.push(&WarriorManagementChoice::VisitHealer);
+            }
+            if !fittable_prosthetics(&warrior).is_empty() {
+                choices.push(&WarriorManagementChoice::FitProsthetic);
+            }
+            if warrior.training().is_none() {
+                choices.push(&WarriorManagementChoice::Train);
+            }
             match select_with_keys(
-                &format!("What do you want to do to {}", warrior.name()),
+                &format!(
+                    "{} is {}, {}{}\nWhat do you want to do to {}",
+                    warrior.name(),
+                    warrior.activity().show_self(),
+                    show_full_health_eta(&warrior, &healing_config),
+                    show_training_eta(&warrior),
+                    warrior.name(),
+                ),
                 &choices,
                 |option| { format!("{option}") }
             )? {
@@ -76,7 +134,18 @@ pub fn warriors_view(session: &Session) -> Result<(), ViewError> {
                     match choice {
                         WarriorManagementChoice::ReplaceWeapon => replace_weapon_view(session, &warrior)?,
                         WarriorManagementChoice::EquipProtection => equip_protection_view(session, &warrior)?,
+                        WarriorManagementChoice::EquipArmorSet => equip_armor_set_view(session, &warrior)?,
+                        WarriorManagementChoice::ManageLoadouts => loadouts_view(session, &warrior)?,
+                        WarriorManagementChoice::EquipShield => equip_shield_view(session, &warrior)?,
                         WarriorManagementChoice::LevelUp => level_up_view(session, &warrior)?,
+                        WarriorManagementChoice::SpendAttributePoints => attribute_points_view(session, warrior.uuid())?,
+                        WarriorManagementChoice::SetTactics => set_tactics_view(session, &warrior)?,
+                        WarriorManagementChoice::UseConsumable => use_consumable_view(session, &warrior)?,
+                        WarriorManagementChoice::VisitHealer => healer_view(session, &warrior)?,
+                        WarriorManagementChoice::FitProsthetic => fit_prosthetic_view(session, &warrior)?,
+                        WarriorManagementChoice::SetActivity => set_activity_view(session, &warrior)?,
+                        WarriorManagementChoice::ManagePerks => perks_view(session, &warrior)?,
+                        WarriorManagementChoice::Train => training_view(session, &warrior)?,
                     }
                 },
             }
@@ -172,33 +241,588 @@ fn equip_protection_view(session: &Session, warrior: &Warrior) -> Result<(), Vie
     Ok(())
 }
 
-fn level_up_view(session: &Session, warrior: &Warrior) -> Result<(), ViewError> {
-    let sheet = CharacterSheet::new(warrior);
-    let possible_stats = if (warrior.level() + 1) % 2 == 0 {
-        [&StatKind::Courage, &StatKind::Dexterity, &StatKind::Strength].to_vec()
-    } else {
-        [&StatKind::Attack, &StatKind::Parry].to_vec()
+// Picks a protection for every limb and sends them in a single request
+fn equip_armor_set_view(session: &Session, warrior: &Warrior) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    let player: Player = fetcher.get("/player")?;
+    let mut request = EquipRequest::default();
+    for kind in PROTECTABLE_BODY_PARTS.iter() {
+        let body_part = match warrior.body().body_part(kind) {
+            Some(body_part) => body_part,
+            None => continue,
+        };
+        let available_protections: Vec<(&Uuid, &Protection)> = player.inventory().items()
+            .iter()
+            .filter_map(|(id, item)| match item {
+                Item::Protection(protection) => Some((id, protection)),
+                _ => None,
+            })
+            .filter(|(id, protection)| {
+                body_part.can_wear_protection(protection)
+                    && !request.protections.iter().any(|(_, picked)| picked == *id)
+            })
+            .collect();
+        if available_protections.is_empty() {
+            continue;
+        }
+        let available_protections_ref: Vec<&(&Uuid, &Protection)> = available_protections.iter().collect();
+        let current = match body_part.protection() {
+            Some(protection) => protection.show_self(),
+            None => String::from("nothing"),
+        };
+        if let Some((id, _)) = select_with_keys(
+            &format!("{} (wears {current}):", kind.show_self()),
+            &available_protections_ref,
+            |(_, protection)| { protection.show_self() },
+        )? {
+            request.protections.push((kind.clone(), **id));
+        }
+    }
+    if request.is_empty() {
+        return Ok(());
+    }
+    let displaced: Vec<Item> = fetcher.patch(
+        format!("/player/warriors/{}/equip", warrior.uuid()).as_str(),
+        request,
+    )?;
+    for item in displaced {
+        println!("{} goes back to the inventory", item.show_self());
+    }
+    Ok(())
+}
+
+enum LoadoutChoice {
+    Equip,
+    Save,
+    Remove,
+}
+
+const LOADOUT_CHOICES: [&LoadoutChoice; 3] = [
+    &LoadoutChoice::Equip,
+    &LoadoutChoice::Save,
+    &LoadoutChoice::Remove,
+];
+
+impl fmt::Display for LoadoutChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadoutChoice::Equip => write!(f, "Equip a loadout"),
+            LoadoutChoice::Save => write!(f, "Save current equipment as a loadout"),
+            LoadoutChoice::Remove => write!(f, "Remove a loadout"),
+        }
+    }
+}
+
+fn loadouts_view(session: &Session, warrior: &Warrior) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    let choice = match select_with_keys(
+        &format!("{} has {} loadouts", warrior.name(), warrior.loadouts().len()),
+        &LOADOUT_CHOICES,
+        |option| { format!("{option}") },
+    )? {
+        Some(choice) => choice,
+        None => return Ok(()),
+    };
+    if let LoadoutChoice::Save = choice {
+        let name = prompt("Name of the loadout:")?;
+        if name.is_empty() {
+            return Ok(());
+        }
+        fetcher.post::<String, Warrior>(
+            format!("/player/warriors/{}/loadouts", warrior.uuid()).as_str(),
+            name,
+        )?;
+        return Ok(());
+    }
+    let indexes: Vec<usize> = (0..warrior.loadouts().len()).collect();
+    let indexes_ref: Vec<&usize> = indexes.iter().collect();
+    let index = match select_with_keys(
+        "Select a loadout:",
+        &indexes_ref,
+        |index| { show_loadout(&warrior.loadouts()[*index]) },
+    )? {
+        Some(index) => *index,
+        None => return Ok(()),
+    };
+    match choice {
+        LoadoutChoice::Equip => {
+            let player: Player = fetcher.get("/player")?;
+            let request = warrior.resolve_loadout(&warrior.loadouts()[index], player.inventory());
+            if let Err(e) = request {
+                println!("{e}");
+                return Ok(());
+            }
+            let displaced: Vec<Item> = fetcher.patch(
+                format!("/player/warriors/{}/loadouts/equip", warrior.uuid()).as_str(),
+                index,
+            )?;
+            for item in displaced {
+                println!("{} goes back to the inventory", item.show_self());
+            }
+        },
+        _ => {
+            fetcher.patch::<usize, Warrior>(
+                format!("/player/warriors/{}/loadouts/remove", warrior.uuid()).as_str(),
+                index,
+            )?;
+        },
+    }
+    Ok(())
+}
+
+fn show_loadout(loadout: &Loadout) -> String {
+    let mut pieces: Vec<&str> = vec![];
+    if let Some(weapon) = loadout.weapon() {
+        pieces.push(weapon);
+    }
+    if let Some(shield) = loadout.shield() {
+        pieces.push(shield);
+    }
+    for (_, protection) in loadout.protections() {
+        pieces.push(protection);
+    }
+    format!("{}: {}", loadout.name(), pieces.join(", "))
+}
+
+fn equip_shield_view(session: &Session, warrior: &Warrior) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    let player: Player = fetcher.get("/player")?;
+    let available_shields: Vec<(&Uuid, &Shield)> = player.inventory().items()
+        .iter()
+        .filter_map(|(id, item)| {
+            match item {
+                Item::Shield(shield) => Some((id, shield)),
+                _ => None,
+            }
+        })
+        .collect();
+    let available_shields_ref: Vec<&(&Uuid, &Shield)> = available_shields.iter().collect();
+    let inventory_slot_uuid = match select_with_keys(
+        &format!("Select a shield to give to {}:", warrior.name()),
+        &available_shields_ref,
+        |(_, shield)| { shield.show_self_extended() },
+    )? {
+        Some((id, _)) => **id,
+        None => return Ok(()),
+    };
+
+    fetcher.patch::<Uuid, ()>(
+        format!(
+            "/player/warriors/{}/replace-shield",
+            warrior.uuid(),
+        ).as_str(),
+        inventory_slot_uuid,
+    )?;
+    Ok(())
+}
+
+fn use_consumable_view(session: &Session, warrior: &Warrior) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    let player: Player = fetcher.get("/player")?;
+    let available_consumables: Vec<(&Uuid, &Consumable)> = player.inventory().items()
+        .iter()
+        .filter_map(|(id, item)| {
+            match item {
+                Item::Consumable(consumable) => Some((id, consumable)),
+                _ => None,
+            }
+        })
+        .collect();
+    if available_consumables.is_empty() {
+        println!("You have no consumable, the shop sells some");
+        return Ok(());
+    }
+    let available_consumables_ref: Vec<&(&Uuid, &Consumable)> = available_consumables.iter().collect();
+    let (inventory_slot_uuid, consumable) = match select_with_keys(
+        &format!("Select a consumable to use on {}:", warrior.name()),
+        &available_consumables_ref,
+        |(_, consumable)| { consumable.show_self_extended() },
+    )? {
+        Some((id, consumable)) => (**id, *consumable),
+        None => return Ok(()),
+    };
+    if let Err(e) = warrior.check_consumable(consumable) {
+        println!("{e}");
+        return Ok(());
+    }
+
+    fetcher.patch::<Uuid, Warrior>(
+        format!(
+            "/player/warriors/{}/use-consumable",
+            warrior.uuid(),
+        ).as_str(),
+        inventory_slot_uuid,
+    )?;
+    Ok(())
+}
+
+fn healer_view(session: &Session, warrior: &Warrior) -> Result<(), ViewError> {
+    let broken_parts = warrior.body().broken_parts();
+    let body_part = match select_with_keys(
+        &format!("Which injury of {} should the healer look at ?", warrior.name()),
+        &broken_parts,
+        |part| { format!("{} ({} hours to recover)", part.kind().show_self(), part.recovery()) },
+    )? {
+        Some(part) => part,
+        None => return Ok(()),
+    };
+    let treatments: Vec<&Treatment> = TREATMENTS.iter()
+        .filter(|treatment| treatment.saved_hours(body_part) > 0)
+        .collect();
+    let treatment = match select_with_keys(
+        "Select a treatment:",
+        &treatments,
+        |treatment| {
+            let name = match treatment {
+                Treatment::Splint => "Splint it, halving the recovery",
+                Treatment::Set => "Set it right away",
+            };
+            format!("{name} ({} gold)", treatment.cost(body_part))
+        },
+    )? {
+        Some(treatment) => (*treatment).clone(),
+        None => return Ok(()),
     };
-    let prompt_str = format!("{}\nWhat stat do you want to update ?", sheet.show_self());
-    let stat_to_increment = match select_with_keys(
-        &prompt_str,
-        &possible_stats,
-        |stat| {
-            match stat {
-                &StatKind::Attack => "Attack".to_string(),
-                &StatKind::Parry => "Parry".to_string(),
-                &StatKind::Courage => "Courage".to_string(),
-                &StatKind::Dexterity => "Dexterity".to_string(),
-                &StatKind::Strength => "Strength".to_string(),
+    let player: Player = ApiFetcher::new(session).get("/player")?;
+    if player.inventory().gold() < treatment.cost(body_part) {
+        println!("You can't afford this treatment");
+        return Ok(());
+    }
+    let recovery: u32 = ApiFetcher::new(session).patch(
+        format!("/player/warriors/{}/treat", warrior.uuid()).as_str(),
+        TreatmentRequest { body_part: body_part.kind().clone(), treatment },
+    )?;
+    match recovery {
+        0 => println!("{} is healed", body_part.kind().show_self()),
+        hours => println!("{} will heal in {hours} hours", body_part.kind().show_self()),
+    }
+    Ok(())
+}
+
+fn fittable_prosthetics(warrior: &Warrior) -> Vec<ProstheticRequest> {
+    let mut requests = vec![];
+    for prosthetic in PROSTHETICS {
+        for side in [BodySide::Right, BodySide::Left] {
+            if prosthetic.can_fit(warrior.body(), &side).is_ok() {
+                requests.push(ProstheticRequest { prosthetic: prosthetic.clone(), side });
             }
         }
+    }
+    requests
+}
+
+fn fit_prosthetic_view(session: &Session, warrior: &Warrior) -> Result<(), ViewError> {
+    let requests = fittable_prosthetics(warrior);
+    let requests_ref: Vec<&ProstheticRequest> = requests.iter().collect();
+    let request = match select_with_keys(
+        &format!("Select a prosthetic for {}:", warrior.name()),
+        &requests_ref,
+        |request| {
+            format!(
+                "{} for the {} ({} gold)",
+                request.prosthetic.show_self(),
+                request.prosthetic.body_part_kind(&request.side).show_self(),
+                request.prosthetic.gold_value(),
+            )
+        },
+    )? {
+        Some(request) => request,
+        None => return Ok(()),
+    };
+    let player: Player = ApiFetcher::new(session).get("/player")?;
+    if player.inventory().gold() < request.prosthetic.gold_value() {
+        println!("You can't afford this prosthetic");
+        return Ok(());
+    }
+    ApiFetcher::new(session).patch::<&ProstheticRequest, Warrior>(
+        format!("/player/warriors/{}/fit-prosthetic", warrior.uuid()).as_str(),
+        request,
+    )?;
+    Ok(())
+}
+
+fn show_full_health_eta(warrior: &Warrior, config: &HealingConfig) -> String {
+    match warrior.time_to_full_health(config) {
+        Some(0) => "at full health".to_string(),
+        Some(seconds) => format!("full health in {}", show_duration(seconds)),
+        None => "not healing".to_string(),
+    }
+}
+
+fn show_training_eta(warrior: &Warrior) -> String {
+    match warrior.training() {
+        Some(training) => format!(
+            "\n{} ends in {}",
+            training.program().show_self(),
+            show_duration(training.seconds_left()),
+        ),
+        None => String::new(),
+    }
+}
+
+fn show_training_reports(session: &Session) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    let player: Player = fetcher.get("/player")?;
+    for warrior in player.warriors() {
+        if let Some(program) = warrior.training_report() {
+            println!(
+                "{} is back from the training grounds: {}",
+                warrior.name(),
+                program.show_self_extended(),
+            );
+            fetcher.patch::<(), ()>(
+                format!("/player/warriors/{}/training/dismiss-report", warrior.uuid()).as_str(),
+                (),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn training_view(session: &Session, warrior: &Warrior) -> Result<(), ViewError> {
+    let mut programs: Vec<TrainingProgram> = SPARRING_DURATIONS.iter()
+        .map(|hours| TrainingProgram::Sparring(*hours))
+        .collect();
+    if warrior.stat_practices() < stat_practice_limit(warrior.level()) {
+        for stat in STATS {
+            programs.push(TrainingProgram::StatPractice(stat.clone()));
+        }
+    }
+    let programs_ref: Vec<&TrainingProgram> = programs.iter().collect();
+    let program = match select_with_keys(
+        &format!("What should {} train ? (can not fight meanwhile)", warrior.name()),
+        &programs_ref,
+        |program| { format!("{} - {} gold", program.show_self_extended(), program.cost()) },
     )? {
-        Some(stat) => stat,
+        Some(program) => (*program).clone(),
         None => return Ok(()),
     };
-    ApiFetcher::new(session).patch(
+    let player: Player = ApiFetcher::new(session).get("/player")?;
+    if player.inventory().gold() < program.cost() {
+        println!("You can't afford this training");
+        return Ok(());
+    }
+    ApiFetcher::new(session).patch::<TrainingProgram, Warrior>(
+        format!("/player/warriors/{}/training", warrior.uuid()).as_str(),
+        program,
+    )?;
+    Ok(())
+}
+
+fn set_activity_view(session: &Session, warrior: &Warrior) -> Result<(), ViewError> {
+    let activities: Vec<&Activity> = ACTIVITIES.iter().collect();
+    let activity = match select_with_keys(
+        &format!("What should {} do while waiting for the next tournament ?", warrior.name()),
+        &activities,
+        |activity| {
+            match activity {
+                Activity::Resting => "Rest".to_string(),
+                Activity::Training => "Train (heals slower)".to_string(),
+                Activity::Infirmary => "Stay in the infirmary (heals faster, can not fight)".to_string(),
+                Activity::Registered => activity.show_self(),
+            }
+        },
+    )? {
+        Some(activity) => (*activity).clone(),
+        None => return Ok(()),
+    };
+    ApiFetcher::new(session).patch::<Activity, Warrior>(
+        format!("/player/warriors/{}/activity", warrior.uuid()).as_str(),
+        activity,
+    )?;
+    Ok(())
+}
+
+fn perks_view(session: &Session, warrior: &Warrior) -> Result<(), ViewError> {
+    println!(
+        "{} has picked {}/{} perks",
+        warrior.name(),
+        warrior.perks().list().len(),
+        perk_slots(warrior.level()),
+    );
+    for perk in PERKS.iter() {
+        let status = if warrior.perks().has(perk) {
+            "known"
+        } else if warrior.level() < perk.required_level()
+            || perk.prerequisite().is_some_and(|prerequisite| !warrior.perks().has(&prerequisite)) {
+            "locked"
+        } else {
+            "available"
+        };
+        println!("- {} [{}]", perk.show_self_extended(), status);
+    }
+    let learnable: Vec<&Perk> = PERKS.iter()
+        .filter(|perk| warrior.perks().can_learn(perk, warrior.level()).is_ok())
+        .collect();
+    if !learnable.is_empty() {
+        if let Some(perk) = select_with_keys(
+            "Select a perk to learn:",
+            &learnable,
+            |perk| { perk.show_self_extended() },
+        )? {
+            ApiFetcher::new(session).patch::<Perk, Warrior>(
+                format!("/player/warriors/{}/perks", warrior.uuid()).as_str(),
+                perk.clone(),
+            )?;
+            return Ok(());
+        }
+    }
+    if warrior.perks().list().is_empty() {
+        return Ok(());
+    }
+    let cost = respec_cost(warrior.level());
+    if !prompt_bool(&format!("Forget all perks for {cost} gold ?"))? {
+        return Ok(());
+    }
+    let player: Player = ApiFetcher::new(session).get("/player")?;
+    if player.inventory().gold() < cost {
+        println!("You can't afford to respec");
+        return Ok(());
+    }
+    ApiFetcher::new(session).patch::<(), Warrior>(
+        format!("/player/warriors/{}/respec", warrior.uuid()).as_str(),
+        (),
+    )?;
+    Ok(())
+}
+
+fn level_up_view(session: &Session, warrior: &Warrior) -> Result<(), ViewError> {
+    ApiFetcher::new(session).patch::<(), ()>(
         format!("/player/warriors/{}/level-up", warrior.uuid().to_string()).as_str(),
-        stat_to_increment.clone(),
+        (),
+    )?;
+    attribute_points_view(session, warrior.uuid())
+}
+
+const STATS: [&StatKind; 5] = [
+    &StatKind::Attack,
+    &StatKind::Parry,
+    &StatKind::Courage,
+    &StatKind::Dexterity,
+    &StatKind::Strength,
+];
+
+fn attribute_points_view(session: &Session, warrior_uuid: &Uuid) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    loop {
+        let warrior: Warrior = fetcher.get(
+            format!("/player/warriors/{}", warrior_uuid).as_str()
+        )?;
+        if warrior.attribute_points() == 0 {
+            return Ok(());
+        }
+        let sheet = CharacterSheet::new(&warrior);
+        let prompt_str = format!(
+            "{}\n{} attribute points left, what stat do you want to update ?",
+            sheet.show_self(),
+            warrior.attribute_points(),
+        );
+        let stat_to_increment = match select_with_keys(
+            &prompt_str,
+            &STATS,
+            |stat| {
+                match stat {
+                    &StatKind::Attack => "Attack".to_string(),
+                    &StatKind::Parry => "Parry".to_string(),
+                    &StatKind::Courage => "Courage".to_string(),
+                    &StatKind::Dexterity => "Dexterity".to_string(),
+                    &StatKind::Strength => "Strength".to_string(),
+                }
+            }
+        )? {
+            Some(stat) => stat,
+            None => return Ok(()),
+        };
+        fetcher.patch::<_, ()>(
+            format!("/player/warriors/{}/attribute-points", warrior_uuid).as_str(),
+            (*stat_to_increment).clone(),
+        )?;
+    }
+}
+
+fn set_tactics_view(session: &Session, warrior: &Warrior) -> Result<(), ViewError> {
+    println!("{} currently {}", warrior.name(), warrior.tactics().show_self());
+    let surrender_health_percent = loop {
+        let answer = prompt("Surrender below what percentage of HP ? (leave empty to never surrender)")?;
+        if answer.is_empty() {
+            break None;
+        }
+        match answer.parse::<u8>() {
+            Ok(percent) if percent <= 100 => break Some(percent),
+            _ => println!("Please enter a number between 0 and 100"),
+        }
+    };
+    let surrender_on_severed_limb = prompt_bool("Surrender after losing a limb ?")?;
+    let stance = match select_stance("Select the stance to start fights with:")? {
+        Some(stance) => stance,
+        None => return Ok(()),
+    };
+    let mut stance_switches = vec![];
+    while prompt_bool("Add a rule to switch stance during the fight ?")? {
+        let condition = match select_with_keys(
+            "When should the stance change ?",
+            &STANCE_CONDITION_CHOICES,
+            |condition| { condition.to_string() },
+        )? {
+            Some(StanceConditionChoice::Bleeding) => StanceCondition::Bleeding,
+            Some(StanceConditionChoice::LowHealth) => {
+                let percent = prompt_percent("Below what percentage of HP ?")?;
+                StanceCondition::HealthBelowPercent(percent)
+            },
+            None => break,
+        };
+        if let Some(stance) = select_stance("Switch to what stance ?")? {
+            stance_switches.push(StanceSwitch::new(condition, stance));
+        }
+    }
+    ApiFetcher::new(session).patch::<_, ()>(
+        format!("/player/warriors/{}/tactics", warrior.uuid()).as_str(),
+        Tactics::new(surrender_health_percent, surrender_on_severed_limb, stance, stance_switches),
     )?;
     Ok(())
 }
+
+const STANCES: [&Stance; 4] = [
+    &Stance::Balanced,
+    &Stance::Aggressive,
+    &Stance::Defensive,
+    &Stance::Berserk,
+];
+
+fn select_stance(message: &str) -> Result<Option<Stance>, ViewError> {
+    let stance = select_with_keys(
+        message,
+        &STANCES,
+        |stance| { stance.show_self() },
+    )?;
+    Ok(stance.cloned())
+}
+
+enum StanceConditionChoice {
+    Bleeding,
+    LowHealth,
+}
+
+const STANCE_CONDITION_CHOICES: [&StanceConditionChoice; 2] = [
+    &StanceConditionChoice::Bleeding,
+    &StanceConditionChoice::LowHealth,
+];
+
+impl fmt::Display for StanceConditionChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StanceConditionChoice::Bleeding => write!(f, "When bleeding"),
+            StanceConditionChoice::LowHealth => write!(f, "When HP is low"),
+        }
+    }
+}
+
+fn prompt_percent(message: &str) -> Result<u8, ViewError> {
+    loop {
+        let answer = prompt(message)?;
+        match answer.parse::<u8>() {
+            Ok(percent) if percent <= 100 => return Ok(percent),
+            _ => println!("Please enter a number between 0 and 100"),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt;
+
+use shared::{
+    auth::Session,
+    crafting::{CraftedKind, Material, Recipe, Salvage, MATERIALS},
+    inventory::{HasInventory, Item},
+    player::Player,
+};
+use uuid::Uuid;
+
+use crate::{fetcher::ApiFetcher, prompt::{prompt_bool, select_with_keys}, show::ShowSelfExtended};
+
+use super::ViewError;
+
+enum WorkshopViewChoice {
+    Salvage,
+    Craft,
+}
+
+const WORKSHOP_VIEW_OPTIONS: [&WorkshopViewChoice; 2] = [
+    &WorkshopViewChoice::Salvage,
+    &WorkshopViewChoice::Craft,
+];
+
+impl fmt::Display for WorkshopViewChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Salvage => write!(f, "Salvage items into materials"),
+            Self::Craft => write!(f, "Craft equipment"),
+        }
+    }
+}
+
+pub fn workshop_view(session: &Session) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    loop {
+        let player: Player = fetcher.get("/player")?;
+        let choice = select_with_keys(
+            &format!("Materials: {}", show_materials(&player)),
+            &WORKSHOP_VIEW_OPTIONS,
+            |option| { format!("{}", option) }
+        )?;
+        match choice {
+            Some(WorkshopViewChoice::Salvage) => { salvage_view(session)?; },
+            Some(WorkshopViewChoice::Craft) => { craft_view(session)?; },
+            None => { return Ok(()) },
+        }
+    }
+}
+
+fn salvage_view(session: &Session) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    loop {
+        let player: Player = fetcher.get("/player")?;
+        let options: Vec<(&Uuid, &Item)> = player.inventory().items()
+            .iter()
+            .filter(|(_, item)| !item.salvage().is_empty())
+            .collect();
+        let options_as_reference: Vec<&(&Uuid, &Item)> = options.iter().collect();
+        let id = match select_with_keys(
+            "Select an item to salvage:",
+            &options_as_reference,
+            |(_, item)| {
+                format!("{} => {}", item.show_self_extended(), show_amounts(&item.salvage()))
+            },
+        )? {
+            Some((id, _)) => **id,
+            None => return Ok(()),
+        };
+        if !prompt_bool("The item will be destroyed, are you sure ?")? {
+            continue;
+        }
+        fetcher.patch::<(), Vec<(Material, u32)>>(
+            format!("/crafting/salvage/{}", id).as_str(),
+            (),
+        )?;
+    }
+}
+
+fn craft_view(session: &Session) -> Result<(), ViewError> {
+    let fetcher = ApiFetcher::new(session);
+    let recipes: Vec<Recipe> = fetcher.get("/crafting/recipes")?;
+    loop {
+        let player: Player = fetcher.get("/player")?;
+        let options: Vec<&Recipe> = recipes.iter().collect();
+        let recipe = match select_with_keys(
+            &format!(
+                "You have {} gold and {}\nSelect a recipe:",
+                player.inventory().gold(),
+                show_materials(&player),
+            ),
+            &options,
+            |recipe| { show_recipe(recipe, &player) },
+        )? {
+            Some(recipe) => recipe,
+            None => return Ok(()),
+        };
+        if !recipe.can_afford(player.inventory()) {
+            println!("You lack gold or materials for this recipe");
+            continue;
+        }
+        if player.inventory().free_slots() == 0 {
+            println!("Your inventory is full");
+            continue;
+        }
+        fetcher.patch::<String, Uuid>("/crafting/craft", recipe.name().to_string())?;
+        println!("Crafted {}", recipe.item().show_self_extended());
+    }
+}
+
+fn show_material(material: &Material) -> &str {
+    match material {
+        Material::Metal => "metal",
+        Material::Leather => "leather",
+        Material::Cloth => "cloth",
+        Material::Wood => "wood",
+    }
+}
+
+fn show_amounts(materials: &[(Material, u32)]) -> String {
+    materials.iter()
+        .map(|(material, amount)| format!("{amount} {}", show_material(material)))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn show_materials(player: &Player) -> String {
+    let materials: Vec<(Material, u32)> = MATERIALS.iter()
+        .map(|material| ((*material).clone(), player.inventory().material(material)))
+        .collect();
+    show_amounts(&materials)
+}
+
+fn show_recipe(recipe: &Recipe, player: &Player) -> String {
+    let kind = match recipe.result() {
+        CraftedKind::Weapon(_) => "weapon",
+        CraftedKind::Protection(_) => "protection",
+    };
+    let marker = if recipe.can_afford(player.inventory()) { "" } else { " (missing resources)" };
+    format!(
+        "{} [{kind}] for {} gold and {}{marker}",
+        recipe.item().show_self_extended(),
+        recipe.gold(),
+        show_amounts(recipe.materials()),
+    )
+}
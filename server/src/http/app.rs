@@ -6,6 +6,9 @@ pub async fn run_server() {
     let app = Router::new()
         .nest("/auth", super::auth::auth_routes())
         .nest("/shop", super::shop::shop_routes())
+        .nest("/marketplace", super::marketplace::marketplace_routes())
+        .nest("/trades", super::trades::trades_routes())
+        .nest("/crafting", super::crafting::crafting_routes())
         .nest("/player", super::player::player_routes())
         .nest("/tournaments", super::tournaments::tournaments_routes())
         .nest("/replays", super::replays::replay_routes());
@@ -0,0 +1,32 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    crafting::craft_item as craft,
+    inventory::HasMutableInventory,
+    player::Player,
+    unique_entity::UniqueEntity,
+};
+
+use crate::repository::{PlayerRepository, Repository};
+
+use super::status::crafting_status;
+
+pub async fn craft_item(
+    Extension(mut player): Extension<Player>,
+    Json(recipe_name): Json<String>,
+) -> Result<Json<Value>, StatusCode> {
+    let slot_uuid = craft(player.inventory_mut(), &recipe_name);
+    if let Err(e) = slot_uuid {
+        return Err(crafting_status(&e));
+    }
+    let slot_uuid = slot_uuid.unwrap();
+    let player_repo = PlayerRepository::build();
+    if player_repo.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let player_repo = player_repo.unwrap();
+    if player_repo.update(player.uuid(), &player).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(slot_uuid)))
+}
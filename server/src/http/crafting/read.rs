@@ -0,0 +1,7 @@
+use axum::{http::StatusCode, Json};
+use serde_json::{json, Value};
+use shared::crafting::recipes;
+
+pub async fn read_recipes() -> Result<Json<Value>, StatusCode> {
+    Ok(Json(json!(recipes())))
+}
@@ -0,0 +1,17 @@
+use axum::{routing::{get, patch}, Router};
+
+use crate::http::middlewares::session_auth;
+
+use super::{
+    read::read_recipes,
+    salvage_item::salvage_item,
+    craft_item::craft_item,
+};
+
+pub fn crafting_routes() -> Router {
+    Router::new()
+        .route("/recipes", get(read_recipes))
+        .route("/salvage/:slot_uuid", patch(salvage_item))
+        .route("/craft", patch(craft_item))
+        .layer(axum::middleware::from_fn(session_auth))
+}
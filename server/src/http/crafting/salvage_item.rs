@@ -0,0 +1,33 @@
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    crafting::salvage_item as salvage,
+    inventory::HasMutableInventory,
+    player::Player,
+    unique_entity::UniqueEntity,
+};
+use uuid::Uuid;
+
+use crate::repository::{PlayerRepository, Repository};
+
+use super::status::crafting_status;
+
+pub async fn salvage_item(
+    Extension(mut player): Extension<Player>,
+    Path(slot_uuid): Path<Uuid>,
+) -> Result<Json<Value>, StatusCode> {
+    let materials = salvage(player.inventory_mut(), &slot_uuid);
+    if let Err(e) = materials {
+        return Err(crafting_status(&e));
+    }
+    let materials = materials.unwrap();
+    let player_repo = PlayerRepository::build();
+    if player_repo.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let player_repo = player_repo.unwrap();
+    if player_repo.update(player.uuid(), &player).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(materials)))
+}
@@ -0,0 +1,14 @@
+use axum::http::StatusCode;
+
+use shared::crafting::{CraftingError, CraftingErrorKind};
+
+pub fn crafting_status(error: &CraftingError) -> StatusCode {
+    match error.kind() {
+        CraftingErrorKind::ItemNotFound => StatusCode::NOT_FOUND,
+        CraftingErrorKind::RecipeNotFound => StatusCode::NOT_FOUND,
+        CraftingErrorKind::NotSalvageable => StatusCode::BAD_REQUEST,
+        CraftingErrorKind::MissingMaterials => StatusCode::CONFLICT,
+        CraftingErrorKind::InventoryFull => StatusCode::CONFLICT,
+        CraftingErrorKind::NotEnoughGold => StatusCode::PAYMENT_REQUIRED,
+    }
+}
@@ -0,0 +1,23 @@
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::player::Player;
+use uuid::Uuid;
+
+use crate::marketplace::MarketplaceManager;
+
+use super::status::marketplace_status;
+
+pub async fn bid_listing(
+    Extension(mut player): Extension<Player>,
+    Path(listing_uuid): Path<Uuid>,
+    Json(amount): Json<u32>,
+) -> Result<Json<Value>, StatusCode> {
+    let manager = MarketplaceManager::build();
+    if let Err(e) = manager {
+        return Err(marketplace_status(&e));
+    }
+    match manager.unwrap().bid(&mut player, &listing_uuid, amount) {
+        Ok(listing) => Ok(Json(json!(listing))),
+        Err(e) => Err(marketplace_status(&e)),
+    }
+}
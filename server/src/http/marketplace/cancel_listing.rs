@@ -0,0 +1,22 @@
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::player::Player;
+use uuid::Uuid;
+
+use crate::marketplace::MarketplaceManager;
+
+use super::status::marketplace_status;
+
+pub async fn cancel_listing(
+    Extension(mut player): Extension<Player>,
+    Path(listing_uuid): Path<Uuid>,
+) -> Result<Json<Value>, StatusCode> {
+    let manager = MarketplaceManager::build();
+    if let Err(e) = manager {
+        return Err(marketplace_status(&e));
+    }
+    match manager.unwrap().cancel(&mut player, &listing_uuid) {
+        Ok(_) => Ok(Json(json!(player))),
+        Err(e) => Err(marketplace_status(&e)),
+    }
+}
@@ -0,0 +1,21 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{marketplace::NewListing, player::Player};
+
+use crate::marketplace::MarketplaceManager;
+
+use super::status::marketplace_status;
+
+pub async fn create_listing(
+    Extension(mut player): Extension<Player>,
+    Json(new_listing): Json<NewListing>,
+) -> Result<Json<Value>, StatusCode> {
+    let manager = MarketplaceManager::build();
+    if let Err(e) = manager {
+        return Err(marketplace_status(&e));
+    }
+    match manager.unwrap().create_listing(&mut player, &new_listing) {
+        Ok(listing) => Ok(Json(json!(listing))),
+        Err(e) => Err(marketplace_status(&e)),
+    }
+}
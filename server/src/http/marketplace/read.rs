@@ -0,0 +1,17 @@
+use axum::{http::StatusCode, Json};
+use serde_json::{json, Value};
+
+use crate::marketplace::MarketplaceManager;
+
+use super::status::marketplace_status;
+
+pub async fn read_listings() -> Result<Json<Value>, StatusCode> {
+    let manager = MarketplaceManager::build();
+    if let Err(e) = manager {
+        return Err(marketplace_status(&e));
+    }
+    match manager.unwrap().list_listings() {
+        Ok(listings) => Ok(Json(json!(listings))),
+        Err(e) => Err(marketplace_status(&e)),
+    }
+}
@@ -0,0 +1,20 @@
+use axum::{routing::{delete, get, patch}, Router};
+
+use crate::http::middlewares::session_auth;
+
+use super::{
+    read::read_listings,
+    create_listing::create_listing,
+    buy_listing::buy_listing,
+    bid_listing::bid_listing,
+    cancel_listing::cancel_listing,
+};
+
+pub fn marketplace_routes() -> Router {
+    Router::new()
+        .route("/", get(read_listings).post(create_listing))
+        .route("/:listing_uuid", delete(cancel_listing))
+        .route("/:listing_uuid/buy", patch(buy_listing))
+        .route("/:listing_uuid/bid", patch(bid_listing))
+        .layer(axum::middleware::from_fn(session_auth))
+}
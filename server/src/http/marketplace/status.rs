@@ -0,0 +1,20 @@
+use axum::http::StatusCode;
+
+use shared::marketplace::MarketplaceErrorKind;
+
+use crate::marketplace::{MarketplaceManagerError, MarketplaceManagerErrorKind};
+
+pub fn marketplace_status(error: &MarketplaceManagerError) -> StatusCode {
+    match error.kind() {
+        MarketplaceManagerErrorKind::ListingNotFound => StatusCode::NOT_FOUND,
+        MarketplaceManagerErrorKind::ItemNotFound => StatusCode::NOT_FOUND,
+        MarketplaceManagerErrorKind::NotEnoughGold => StatusCode::PAYMENT_REQUIRED,
+        MarketplaceManagerErrorKind::InventoryFull => StatusCode::CONFLICT,
+        MarketplaceManagerErrorKind::Rejected(kind) => match kind {
+            MarketplaceErrorKind::BidTooLow => StatusCode::BAD_REQUEST,
+            MarketplaceErrorKind::InvalidPrice => StatusCode::BAD_REQUEST,
+            _ => StatusCode::CONFLICT,
+        },
+        MarketplaceManagerErrorKind::Repository => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
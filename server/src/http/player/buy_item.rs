@@ -1,6 +1,6 @@
 use axum::{extract::Path, http::StatusCode, Extension, Json};
 use serde_json::{json, Value};
-use shared::player::Player;
+use shared::{inventory::HasInventory, player::Player};
 
 use crate::player;
 
@@ -9,6 +9,9 @@ pub async fn buy_item(
     Extension(mut player): Extension<Player>,
     Path(slot_uuid): Path<uuid::Uuid>,
 ) -> Result<Json<Value>, StatusCode> {
+    if player.inventory().free_slots() == 0 {
+        return Err(StatusCode::CONFLICT);
+    }
     let item = player::buy_item(&mut player, &slot_uuid);
     if item.is_err() {
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
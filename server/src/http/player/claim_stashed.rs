@@ -0,0 +1,34 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    inventory::{HasMutableInventory, InventoryErrorKind},
+    player::Player,
+    unique_entity::UniqueEntity,
+};
+
+use crate::repository::{PlayerRepository, Repository};
+
+#[axum::debug_handler]
+pub async fn claim_stashed(
+    Extension(mut player): Extension<Player>,
+    Json(index): Json<usize>,
+) -> Result<Json<Value>, StatusCode> {
+    let slot_uuid = player.inventory_mut().claim_stashed(index);
+    if let Err(e) = slot_uuid {
+        return match e.kind() {
+            InventoryErrorKind::ItemNotFound => Err(StatusCode::NOT_FOUND),
+            InventoryErrorKind::InventoryFull => Err(StatusCode::CONFLICT),
+            _ => Err(StatusCode::INTERNAL_SERVER_ERROR),
+        };
+    }
+    let slot_uuid = slot_uuid.unwrap();
+    let player_repo = PlayerRepository::build();
+    if player_repo.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let player_repo = player_repo.unwrap();
+    if player_repo.update(player.uuid(), &player).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(slot_uuid)))
+}
@@ -0,0 +1,8 @@
+use axum::Json;
+use serde_json::{json, Value};
+
+use crate::warrior::healing_config;
+
+pub async fn read_healing_config() -> Json<Value> {
+    Json(json!(healing_config()))
+}
@@ -0,0 +1,46 @@
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    equipment::repair::{repair_for_gold, RepairErrorKind},
+    inventory::HasMutableInventory,
+    player::Player,
+    unique_entity::UniqueEntity,
+};
+use uuid::Uuid;
+
+use crate::repository::{PlayerRepository, Repository};
+
+#[axum::debug_handler]
+pub async fn repair_item(
+    Extension(mut player): Extension<Player>,
+    Path(slot_uuid): Path<Uuid>,
+) -> Result<Json<Value>, StatusCode> {
+    let item = player.inventory_mut().items_mut().remove(&slot_uuid);
+    if item.is_none() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    let mut item = item.unwrap();
+    let outcome = item.repairable_mut()
+        .map(|repairable| repair_for_gold(repairable, player.inventory_mut()));
+    player.inventory_mut().items_mut().insert(slot_uuid, item);
+    if outcome.is_none() {
+        return Err(StatusCode::CONFLICT);
+    }
+    let outcome = outcome.unwrap();
+    if let Err(e) = outcome {
+        return match e.kind() {
+            RepairErrorKind::NothingToRepair => Err(StatusCode::CONFLICT),
+            RepairErrorKind::NotEnoughGold => Err(StatusCode::PAYMENT_REQUIRED),
+        };
+    }
+    let outcome = outcome.unwrap();
+    let player_repo = PlayerRepository::build();
+    if player_repo.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let player_repo = player_repo.unwrap();
+    if player_repo.update(player.uuid(), &player).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(outcome)))
+}
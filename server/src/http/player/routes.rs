@@ -8,6 +8,10 @@ use super::{
     warriors::player_warriors_routes,
     buy_item::buy_item,
     sell_item::sell_item,
+    repair_item::repair_item,
+    upgrade_inventory::upgrade_inventory,
+    claim_stashed::claim_stashed,
+    healing_config::read_healing_config,
 };
 
 pub fn player_routes() -> Router {
@@ -15,6 +19,10 @@ pub fn player_routes() -> Router {
         .route("/", get(read_player))
         .route("/buy-item/:slot_uuid", patch(buy_item))
         .route("/sell-item/:slot_uuid", patch(sell_item))
+        .route("/repair-item/:slot_uuid", patch(repair_item))
+        .route("/upgrade-inventory", patch(upgrade_inventory))
+        .route("/claim-stashed", patch(claim_stashed))
+        .route("/healing-config", get(read_healing_config))
         .nest("/tournaments", player_tournaments_routes())
         .nest("/warriors", player_warriors_routes())
         .layer(axum::middleware::from_fn(session_auth))
@@ -1,9 +1,11 @@
 use axum::{http::StatusCode, Extension, Json};
 use serde_json::{json, Value};
 use shared::{
+    health::{Activity, PassiveHealing},
     player::Player,
     tournament::Tournament,
     unique_entity::UniqueEntity,
+    warrior::HasTraining,
 };
 use uuid::Uuid;
 
@@ -21,6 +23,10 @@ pub async fn register_warriors(
     }
     for warrior_uuid in warriors_uuids {
         if let Some(warrior) = manager.read_warrior(&warrior_uuid) {
+            // Warriors in the infirmary or away training are not fit to fight
+            if warrior.activity() == Activity::Infirmary || warrior.training().is_some() {
+                return Err(StatusCode::CONFLICT);
+            }
             let mut warrior = warrior.clone();
             if register_contestant(player.uuid(), &mut tournament, &mut warrior).is_err() {
                 return Err(StatusCode::INTERNAL_SERVER_ERROR)
@@ -0,0 +1,33 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    inventory::{HasMutableInventory, InventoryErrorKind},
+    player::Player,
+    unique_entity::UniqueEntity,
+};
+
+use crate::repository::{PlayerRepository, Repository};
+
+#[axum::debug_handler]
+pub async fn upgrade_inventory(
+    Extension(mut player): Extension<Player>,
+) -> Result<Json<Value>, StatusCode> {
+    let cost = player.inventory_mut().buy_capacity_upgrade();
+    if let Err(e) = cost {
+        return match e.kind() {
+            InventoryErrorKind::NotEnoughGold => Err(StatusCode::PAYMENT_REQUIRED),
+            InventoryErrorKind::MaxCapacity => Err(StatusCode::CONFLICT),
+            _ => Err(StatusCode::INTERNAL_SERVER_ERROR),
+        };
+    }
+    let cost = cost.unwrap();
+    let player_repo = PlayerRepository::build();
+    if player_repo.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let player_repo = player_repo.unwrap();
+    if player_repo.update(player.uuid(), &player).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(cost)))
+}
@@ -0,0 +1,63 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    equipment::loadout::{BulkEquip, EquipError, EquipErrorKind, EquipRequest},
+    inventory::HasMutableInventory,
+    player::Player,
+    unique_entity::UniqueEntity,
+    warrior::Warrior,
+};
+
+use crate::{
+    repository::{PlayerRepository, Repository},
+    warrior::WarriorManager,
+};
+
+#[axum::debug_handler]
+pub async fn equip(
+    Extension(mut player): Extension<Player>,
+    Extension(mut warrior): Extension<Warrior>,
+    Json(request): Json<EquipRequest>,
+) -> Result<Json<Value>, StatusCode> {
+    equip_and_save(&mut player, &mut warrior, &request)
+}
+
+// Swaps every piece at once and returns the displaced items
+pub fn equip_and_save(
+    player: &mut Player,
+    warrior: &mut Warrior,
+    request: &EquipRequest,
+) -> Result<Json<Value>, StatusCode> {
+    let displaced = warrior.equip_from_inventory(player.inventory_mut(), request);
+    if let Err(e) = displaced {
+        return Err(equip_status(&e));
+    }
+    let displaced = displaced.unwrap();
+    let player_repo = PlayerRepository::build();
+    if player_repo.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let player_repo = player_repo.unwrap();
+    if player_repo.update(player.uuid(), player).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = WarriorManager::build();
+    if warrior_manager.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = warrior_manager.unwrap();
+    if warrior_manager.save(warrior).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(displaced)))
+}
+
+pub fn equip_status(error: &EquipError) -> StatusCode {
+    match error.kind() {
+        EquipErrorKind::ItemNotFound => StatusCode::NOT_FOUND,
+        EquipErrorKind::MissingPiece(_) => StatusCode::NOT_FOUND,
+        EquipErrorKind::InventoryFull => StatusCode::CONFLICT,
+        EquipErrorKind::TooManyLoadouts => StatusCode::CONFLICT,
+        _ => StatusCode::BAD_REQUEST,
+    }
+}
@@ -0,0 +1,50 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    inventory::HasMutableInventory,
+    player::Player,
+    unique_entity::UniqueEntity,
+    warrior::{
+        body::{
+            prosthetic::{fit_for_gold, ProstheticErrorKind, ProstheticRequest},
+            HasMutableBody,
+        },
+        Warrior,
+    },
+};
+
+use crate::{
+    repository::{PlayerRepository, Repository},
+    warrior::WarriorManager,
+};
+
+pub async fn fit_prosthetic(
+    Extension(mut player): Extension<Player>,
+    Extension(mut warrior): Extension<Warrior>,
+    Json(request): Json<ProstheticRequest>,
+) -> Result<Json<Value>, StatusCode> {
+    if let Err(e) = fit_for_gold(warrior.body_mut(), &request, player.inventory_mut()) {
+        return match e.kind() {
+            ProstheticErrorKind::NotMissing |
+            ProstheticErrorKind::NothingToAttachTo => Err(StatusCode::CONFLICT),
+            ProstheticErrorKind::NotEnoughGold => Err(StatusCode::PAYMENT_REQUIRED),
+        };
+    }
+    let player_repo = PlayerRepository::build();
+    if player_repo.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let player_repo = player_repo.unwrap();
+    if player_repo.update(player.uuid(), &player).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = WarriorManager::build();
+    if warrior_manager.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = warrior_manager.unwrap();
+    if warrior_manager.save(&warrior).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(warrior)))
+}
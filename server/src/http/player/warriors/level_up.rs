@@ -13,17 +13,33 @@ use crate::repository::{FileRepository, Repository};
 #[axum::debug_handler]
 pub async fn level_up(
     Extension(mut warrior): Extension<Warrior>,
+) -> Result<Json<Value>, StatusCode> {
+    if let Err(_) = warrior.level_up() {
+        return Err(StatusCode::CONFLICT)
+    }
+    save_warrior(&warrior)?;
+    Ok(Json(json!(())))
+}
+
+#[axum::debug_handler]
+pub async fn spend_attribute_point(
+    Extension(mut warrior): Extension<Warrior>,
     Json(stat): Json<StatKind>,
 ) -> Result<Json<Value>, StatusCode> {
-    if let Err(_) = warrior.level_up(&stat) {
+    if warrior.spend_attribute_point(&stat).is_err() {
         return Err(StatusCode::CONFLICT)
     }
+    save_warrior(&warrior)?;
+    Ok(Json(json!(())))
+}
+
+fn save_warrior(warrior: &Warrior) -> Result<(), StatusCode> {
     let repo = match FileRepository::build(PathBuf::from("saves/warriors")) {
         Ok(repo) => repo,
         Err(_) => { return Err(StatusCode::INTERNAL_SERVER_ERROR) },
     };
-    if repo.update(warrior.uuid(), &warrior).is_err() {
+    if repo.update(warrior.uuid(), warrior).is_err() {
         return Err(StatusCode::INTERNAL_SERVER_ERROR)
     }
-    Ok(Json(json!(())))
+    Ok(())
 }
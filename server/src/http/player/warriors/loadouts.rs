@@ -0,0 +1,69 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    equipment::loadout::{BulkEquip, HasLoadouts, HasMutableLoadouts, Loadout},
+    inventory::HasInventory,
+    player::Player,
+    warrior::Warrior,
+};
+
+use crate::warrior::WarriorManager;
+
+use super::equip::{equip_and_save, equip_status};
+
+// Saves the current equipment under the given name
+#[axum::debug_handler]
+pub async fn save_loadout(
+    Extension(mut warrior): Extension<Warrior>,
+    Json(name): Json<String>,
+) -> Result<Json<Value>, StatusCode> {
+    if name.trim().is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let loadout = Loadout::from_equipment(name, &warrior);
+    if let Err(e) = warrior.save_loadout(loadout) {
+        return Err(equip_status(&e));
+    }
+    save_warrior(&warrior)
+}
+
+#[axum::debug_handler]
+pub async fn remove_loadout(
+    Extension(mut warrior): Extension<Warrior>,
+    Json(index): Json<usize>,
+) -> Result<Json<Value>, StatusCode> {
+    if index >= warrior.loadouts().len() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    warrior.loadouts_mut().remove(index);
+    save_warrior(&warrior)
+}
+
+#[axum::debug_handler]
+pub async fn equip_loadout(
+    Extension(mut player): Extension<Player>,
+    Extension(mut warrior): Extension<Warrior>,
+    Json(index): Json<usize>,
+) -> Result<Json<Value>, StatusCode> {
+    let loadout = warrior.loadouts().get(index);
+    if loadout.is_none() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    let request = warrior.resolve_loadout(loadout.unwrap(), player.inventory());
+    if let Err(e) = request {
+        return Err(equip_status(&e));
+    }
+    equip_and_save(&mut player, &mut warrior, &request.unwrap())
+}
+
+fn save_warrior(warrior: &Warrior) -> Result<Json<Value>, StatusCode> {
+    let warrior_manager = WarriorManager::build();
+    if warrior_manager.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = warrior_manager.unwrap();
+    if warrior_manager.save(warrior).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(warrior)))
+}
@@ -0,0 +1,68 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    experience::Experience,
+    inventory::HasMutableInventory,
+    player::Player,
+    unique_entity::UniqueEntity,
+    warrior::{respec_for_gold, HasMutablePerks, Perk, PerkErrorKind, Warrior},
+};
+
+use crate::{
+    repository::{PlayerRepository, Repository},
+    warrior::WarriorManager,
+};
+
+pub async fn learn_perk(
+    Extension(mut warrior): Extension<Warrior>,
+    Json(perk): Json<Perk>,
+) -> Result<Json<Value>, StatusCode> {
+    let level = warrior.level();
+    if let Err(e) = warrior.perks_mut().learn(perk, level) {
+        return match e.kind() {
+            PerkErrorKind::LevelTooLow |
+            PerkErrorKind::MissingPrerequisite |
+            PerkErrorKind::NoSlotLeft => Err(StatusCode::FORBIDDEN),
+            _ => Err(StatusCode::CONFLICT),
+        };
+    }
+    let warrior_manager = WarriorManager::build();
+    if warrior_manager.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = warrior_manager.unwrap();
+    if warrior_manager.save(&warrior).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(warrior)))
+}
+
+pub async fn respec(
+    Extension(mut player): Extension<Player>,
+    Extension(mut warrior): Extension<Warrior>,
+) -> Result<Json<Value>, StatusCode> {
+    let level = warrior.level();
+    if let Err(e) = respec_for_gold(warrior.perks_mut(), level, player.inventory_mut()) {
+        return match e.kind() {
+            PerkErrorKind::NotEnoughGold => Err(StatusCode::PAYMENT_REQUIRED),
+            _ => Err(StatusCode::CONFLICT),
+        };
+    }
+    let player_repo = PlayerRepository::build();
+    if player_repo.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let player_repo = player_repo.unwrap();
+    if player_repo.update(player.uuid(), &player).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = WarriorManager::build();
+    if warrior_manager.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = warrior_manager.unwrap();
+    if warrior_manager.save(&warrior).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(warrior)))
+}
@@ -3,8 +3,9 @@ use std::path::PathBuf;
 use axum::http::StatusCode;
 use axum::{Extension, Json};
 use serde_json::{json, Value};
+use shared::equipment::shield::OptionalMutableShield;
 use shared::equipment::weapon::OptionalMutableWeapon;
-use shared::inventory::{HasMutableInventory, Item, MutableItems};
+use shared::inventory::{HasMutableInventory, Item};
 use shared::player::Player;
 use shared::unique_entity::UniqueEntity;
 use shared::warrior::body::body_part::PROTECTABLE_BODY_PARTS;
@@ -19,10 +20,13 @@ pub async fn remove_warrior(
 ) -> Result<Json<Value>, StatusCode> {
     let protections = warrior.take_protections(PROTECTABLE_BODY_PARTS.iter().collect());
     for protection in protections {
-        player.inventory_mut().add_item(Item::Protection(protection));
+        player.inventory_mut().store_item(Item::Protection(protection));
     }
     if let Some(weapon) = warrior.weapon_mut().take() {
-        player.inventory_mut().add_item(Item::Weapon(weapon));
+        player.inventory_mut().store_item(Item::Weapon(weapon));
+    }
+    if let Some(shield) = warrior.shield_mut().take() {
+        player.inventory_mut().store_item(Item::Shield(shield));
     }
     player.take_warrior(warrior.uuid());
     let warrior_repo = FileRepository::build(PathBuf::from("saves/warriors"));
@@ -0,0 +1,71 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    equipment::{
+        protection::OptionalMutableProtection,
+        repair::{repair_for_gold, EquipmentSlot, RepairErrorKind, Repairable},
+        shield::OptionalMutableShield,
+        weapon::OptionalMutableWeapon,
+    },
+    inventory::HasMutableInventory,
+    player::Player,
+    unique_entity::UniqueEntity,
+    warrior::{body::{body_part::OptionalMutableBodyPart, HasMutableBody}, Warrior},
+};
+
+use crate::{
+    repository::{PlayerRepository, Repository},
+    warrior::WarriorManager,
+};
+
+pub async fn repair_equipment(
+    Extension(mut player): Extension<Player>,
+    Extension(mut warrior): Extension<Warrior>,
+    Json(slot): Json<EquipmentSlot>,
+) -> Result<Json<Value>, StatusCode> {
+    let item = equipped_item(&mut warrior, &slot);
+    if item.is_none() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    let outcome = repair_for_gold(item.unwrap(), player.inventory_mut());
+    if let Err(e) = outcome {
+        return match e.kind() {
+            RepairErrorKind::NothingToRepair => Err(StatusCode::CONFLICT),
+            RepairErrorKind::NotEnoughGold => Err(StatusCode::PAYMENT_REQUIRED),
+        };
+    }
+    let outcome = outcome.unwrap();
+    let player_repo = PlayerRepository::build();
+    if player_repo.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let player_repo = player_repo.unwrap();
+    if player_repo.update(player.uuid(), &player).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = WarriorManager::build();
+    if warrior_manager.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = warrior_manager.unwrap();
+    if warrior_manager.save(&warrior).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(outcome)))
+}
+
+fn equipped_item<'a>(warrior: &'a mut Warrior, slot: &EquipmentSlot) -> Option<&'a mut dyn Repairable> {
+    match slot {
+        EquipmentSlot::Weapon => warrior.weapon_mut()
+            .as_mut()
+            .map(|weapon| weapon as &mut dyn Repairable),
+        EquipmentSlot::Shield => warrior.shield_mut()
+            .as_mut()
+            .map(|shield| shield as &mut dyn Repairable),
+        EquipmentSlot::Protection(body_part_kind) => warrior.body_mut()
+            .body_part_mut(body_part_kind)
+            .as_mut()
+            .and_then(|body_part| body_part.protection_mut().as_mut())
+            .map(|protection| protection as &mut dyn Repairable),
+    }
+}
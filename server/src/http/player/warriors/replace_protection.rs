@@ -24,7 +24,7 @@ pub async fn replace_protection(
     let new_protection = new_protection.unwrap();
     let protection_to_store = warrior.replace_protection(&body_part_kind, new_protection);
     if let Some(protection) = protection_to_store {
-        player.inventory_mut().add_item(Item::Protection(protection));
+        player.inventory_mut().store_item(Item::Protection(protection));
     }
     let player_repo = PlayerRepository::build();
     if player_repo.is_err() { return Err(StatusCode::INTERNAL_SERVER_ERROR); }
@@ -0,0 +1,70 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    equipment::shield::{CanHoldShield, OptionalMutableShield, Shield},
+    inventory::{HasMutableInventory, Item, MutableItems},
+    player::Player,
+    unique_entity::UniqueEntity,
+    warrior::Warrior,
+};
+use uuid::Uuid;
+
+use crate::{
+    repository::{PlayerRepository, Repository},
+    warrior::WarriorManager,
+};
+
+pub async fn replace_shield(
+    Extension(mut player): Extension<Player>,
+    Extension(mut warrior): Extension<Warrior>,
+    Json(inventory_slot): Json<Uuid>,
+) -> Result<Json<Value>, StatusCode> {
+    if !warrior.can_hold_shield() {
+        return Err(StatusCode::CONFLICT);
+    }
+    let new_shield = take_shield_from_player(&mut player, &inventory_slot);
+    if new_shield.is_none() { return Err(StatusCode::NOT_FOUND); }
+    let new_shield = new_shield.unwrap();
+    let old_shield = warrior.replace_shield(new_shield);
+    if let Some(shield) = old_shield {
+        player.inventory_mut().store_item(Item::Shield(shield));
+    }
+    let player_repo = PlayerRepository::build();
+    if player_repo.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let player_repo = player_repo.unwrap();
+    if player_repo.update(player.uuid(), &player).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = WarriorManager::build();
+    if warrior_manager.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = warrior_manager.unwrap();
+    if warrior_manager.save(&warrior).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(())))
+}
+
+fn take_shield_from_player(player: &mut Player, inventory_slot: &Uuid) -> Option<Shield> {
+    let new_shield = player.inventory_mut().remove_item(inventory_slot);
+    if new_shield.is_none() {
+        eprintln!(
+            "[WARN] Item {} not found for player {}",
+            inventory_slot,
+            player.uuid(),
+        );
+        return None;
+    }
+    let new_shield = Shield::try_from(new_shield.unwrap());
+    if let Err(e) = new_shield {
+        eprintln!(
+            "[WARN] Item {} couldn't be converted to a shield:\n{e}",
+            inventory_slot,
+        );
+        return None;
+    }
+    Some(new_shield.unwrap())
+}
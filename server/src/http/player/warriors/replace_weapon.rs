@@ -1,7 +1,10 @@
 use axum::{http::StatusCode, Extension, Json};
 use serde_json::{json, Value};
 use shared::{
-    equipment::weapon::{OptionalMutableWeapon, Weapon},
+    equipment::{
+        shield::{CanHoldShield, OptionalMutableShield},
+        weapon::{OptionalMutableWeapon, Weapon},
+    },
     inventory::{HasMutableInventory, Item, MutableItems},
     player::Player,
     unique_entity::UniqueEntity,
@@ -24,7 +27,12 @@ pub async fn replace_weapon(
     let new_weapon = new_weapon.unwrap();
     let old_weapon = warrior.replace_weapon(new_weapon);
     if let Some(weapon) = old_weapon {
-        player.inventory_mut().add_item(Item::Weapon(weapon));
+        player.inventory_mut().store_item(Item::Weapon(weapon));
+    }
+    if !warrior.can_hold_shield() {
+        if let Some(shield) = warrior.shield_mut().take() {
+            player.inventory_mut().store_item(Item::Shield(shield));
+        }
     }
     let player_repo = PlayerRepository::build();
     if player_repo.is_err() {
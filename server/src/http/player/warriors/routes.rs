@@ -6,21 +6,48 @@ use crate::http::middlewares::get_player_warrior;
 
 use super::{
     gen_random_warrior::gen_random_warrior,
-    level_up::level_up,
+    level_up::{level_up, spend_attribute_point},
     read::read_warrior,
     remove_from_replay::remove_warrior_from_replay,
     remove_warrior::remove_warrior,
+    repair_equipment::repair_equipment,
+    equip::equip,
+    loadouts::{equip_loadout, remove_loadout, save_loadout},
     replace_protection::replace_protection,
+    replace_shield::replace_shield,
     replace_weapon::replace_weapon,
+    set_tactics::set_tactics,
+    use_consumable::use_consumable,
+    treat_injury::treat_injury,
+    fit_prosthetic::fit_prosthetic,
+    set_activity::set_activity,
+    perks::{learn_perk, respec},
+    training::{dismiss_training_report, start_training},
 };
 
 pub fn player_warriors_routes() -> Router {
     let single_warrior_routes = Router::new()
         .route("/", get(read_warrior).delete(remove_warrior))
         .route("/level-up", patch(level_up))
+        .route("/attribute-points", patch(spend_attribute_point))
         .route("/replace-weapon", patch(replace_weapon))
         .route("/replace-protection", patch(replace_protection))
+        .route("/replace-shield", patch(replace_shield))
+        .route("/repair", patch(repair_equipment))
+        .route("/equip", patch(equip))
+        .route("/loadouts", post(save_loadout))
+        .route("/loadouts/equip", patch(equip_loadout))
+        .route("/loadouts/remove", patch(remove_loadout))
         .route("/remove-from-replay", patch(remove_warrior_from_replay))
+        .route("/tactics", patch(set_tactics))
+        .route("/use-consumable", patch(use_consumable))
+        .route("/treat", patch(treat_injury))
+        .route("/fit-prosthetic", patch(fit_prosthetic))
+        .route("/activity", patch(set_activity))
+        .route("/perks", patch(learn_perk))
+        .route("/respec", patch(respec))
+        .route("/training", patch(start_training))
+        .route("/training/dismiss-report", patch(dismiss_training_report))
         .layer(axum::middleware::from_fn(get_player_warrior));
     Router::new()
         .route("/random", post(gen_random_warrior))
@@ -0,0 +1,31 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    health::{Activity, MutablePassiveHealing},
+    tournament::contestant::TournamentContestant,
+    warrior::{HasTraining, Warrior},
+};
+
+use crate::warrior::WarriorManager;
+
+pub async fn set_activity(
+    Extension(mut warrior): Extension<Warrior>,
+    Json(activity): Json<Activity>,
+) -> Result<Json<Value>, StatusCode> {
+    if activity == Activity::Registered {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    if warrior.current_tournament().is_some() || warrior.training().is_some() {
+        return Err(StatusCode::CONFLICT);
+    }
+    warrior.set_activity(activity);
+    let warrior_manager = WarriorManager::build();
+    if warrior_manager.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = warrior_manager.unwrap();
+    if warrior_manager.save(&warrior).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(warrior)))
+}
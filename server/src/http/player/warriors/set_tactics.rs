@@ -0,0 +1,25 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::warrior::{HasMutableTactics, Tactics, Warrior};
+
+use crate::warrior::WarriorManager;
+
+#[axum::debug_handler]
+pub async fn set_tactics(
+    Extension(mut warrior): Extension<Warrior>,
+    Json(tactics): Json<Tactics>,
+) -> Result<Json<Value>, StatusCode> {
+    if !tactics.is_valid() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    *warrior.tactics_mut() = tactics;
+    let warrior_manager = WarriorManager::build();
+    if warrior_manager.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = warrior_manager.unwrap();
+    if warrior_manager.save(&warrior).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(())))
+}
@@ -0,0 +1,64 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    inventory::HasMutableInventory,
+    player::Player,
+    unique_entity::UniqueEntity,
+    warrior::{Trainee, TrainingErrorKind, TrainingProgram, Warrior},
+};
+
+use crate::{
+    repository::{PlayerRepository, Repository},
+    warrior::WarriorManager,
+};
+
+pub async fn start_training(
+    Extension(mut player): Extension<Player>,
+    Extension(mut warrior): Extension<Warrior>,
+    Json(program): Json<TrainingProgram>,
+) -> Result<Json<Value>, StatusCode> {
+    if let Err(e) = warrior.start_training(program, player.inventory_mut()) {
+        return match e.kind() {
+            TrainingErrorKind::InvalidDuration => Err(StatusCode::BAD_REQUEST),
+            TrainingErrorKind::NotEnoughGold => Err(StatusCode::PAYMENT_REQUIRED),
+            TrainingErrorKind::PracticeLimitReached => Err(StatusCode::FORBIDDEN),
+            TrainingErrorKind::InTournament |
+            TrainingErrorKind::AlreadyTraining => Err(StatusCode::CONFLICT),
+        };
+    }
+    let player_repo = PlayerRepository::build();
+    if player_repo.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let player_repo = player_repo.unwrap();
+    if player_repo.update(player.uuid(), &player).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = WarriorManager::build();
+    if warrior_manager.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = warrior_manager.unwrap();
+    if warrior_manager.save(&warrior).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(warrior)))
+}
+
+// The player has been told how the last training went
+pub async fn dismiss_training_report(
+    Extension(mut warrior): Extension<Warrior>,
+) -> Result<Json<Value>, StatusCode> {
+    if warrior.training_report_mut().take().is_none() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    let warrior_manager = WarriorManager::build();
+    if warrior_manager.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = warrior_manager.unwrap();
+    if warrior_manager.save(&warrior).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(())))
+}
@@ -0,0 +1,53 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    inventory::HasMutableInventory,
+    player::Player,
+    unique_entity::UniqueEntity,
+    warrior::{
+        body::{
+            recovery::{treat_for_gold, TreatmentErrorKind, TreatmentRequest},
+            HasMutableBody,
+        },
+        Warrior,
+    },
+};
+
+use crate::{
+    repository::{PlayerRepository, Repository},
+    warrior::WarriorManager,
+};
+
+pub async fn treat_injury(
+    Extension(mut player): Extension<Player>,
+    Extension(mut warrior): Extension<Warrior>,
+    Json(request): Json<TreatmentRequest>,
+) -> Result<Json<Value>, StatusCode> {
+    let recovery = treat_for_gold(warrior.body_mut(), &request, player.inventory_mut());
+    if let Err(e) = recovery {
+        return match e.kind() {
+            TreatmentErrorKind::PartSevered => Err(StatusCode::NOT_FOUND),
+            TreatmentErrorKind::NotInjured => Err(StatusCode::CONFLICT),
+            TreatmentErrorKind::NoEffect => Err(StatusCode::CONFLICT),
+            TreatmentErrorKind::NotEnoughGold => Err(StatusCode::PAYMENT_REQUIRED),
+        };
+    }
+    let recovery = recovery.unwrap();
+    let player_repo = PlayerRepository::build();
+    if player_repo.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let player_repo = player_repo.unwrap();
+    if player_repo.update(player.uuid(), &player).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = WarriorManager::build();
+    if warrior_manager.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = warrior_manager.unwrap();
+    if warrior_manager.save(&warrior).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(recovery)))
+}
@@ -0,0 +1,56 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{
+    consumable::{ConsumableErrorKind, UseConsumable},
+    inventory::{HasInventory, HasMutableInventory, Item, MutableItems},
+    player::Player,
+    unique_entity::UniqueEntity,
+    warrior::Warrior,
+};
+use uuid::Uuid;
+
+use crate::{
+    repository::{PlayerRepository, Repository},
+    warrior::WarriorManager,
+};
+
+pub async fn use_consumable(
+    Extension(mut player): Extension<Player>,
+    Extension(mut warrior): Extension<Warrior>,
+    Json(inventory_slot): Json<Uuid>,
+) -> Result<Json<Value>, StatusCode> {
+    let consumable = match player.inventory().items().get(&inventory_slot) {
+        None => return Err(StatusCode::NOT_FOUND),
+        Some(Item::Consumable(consumable)) => consumable.clone(),
+        Some(_) => return Err(StatusCode::BAD_REQUEST),
+    };
+    if let Err(e) = warrior.use_consumable(&consumable) {
+        return match e.kind() {
+            ConsumableErrorKind::InTournament => Err(StatusCode::FORBIDDEN),
+            ConsumableErrorKind::Dead |
+            ConsumableErrorKind::FullHealth |
+            ConsumableErrorKind::NotBleeding |
+            ConsumableErrorKind::AlreadyUnderTonic |
+            ConsumableErrorKind::NotPoisoned |
+            ConsumableErrorKind::AlreadyEnraged => Err(StatusCode::CONFLICT),
+        };
+    }
+    player.inventory_mut().remove_item(&inventory_slot);
+    let player_repo = PlayerRepository::build();
+    if player_repo.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let player_repo = player_repo.unwrap();
+    if player_repo.update(player.uuid(), &player).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = WarriorManager::build();
+    if warrior_manager.is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    let warrior_manager = warrior_manager.unwrap();
+    if warrior_manager.save(&warrior).is_err() {
+        return Err(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+    Ok(Json(json!(warrior)))
+}
@@ -0,0 +1,11 @@
+use axum::{http::StatusCode, Json};
+use serde_json::{json, Value};
+
+use crate::shop;
+
+pub async fn next_restock() -> Result<Json<Value>, StatusCode> {
+    match shop::next_restock() {
+        Ok(timestamp) => Ok(Json(json!(timestamp))),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
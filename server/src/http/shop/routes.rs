@@ -1,7 +1,9 @@
 use axum::{routing::get, Router};
 
-use super::read::read_shop;
+use super::{next_restock::next_restock, read::read_shop};
 
 pub fn shop_routes() -> Router {
-    Router::new().route("/", get(read_shop))
+    Router::new()
+        .route("/", get(read_shop))
+        .route("/next-restock", get(next_restock))
 }
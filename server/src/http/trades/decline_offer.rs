@@ -0,0 +1,22 @@
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::player::Player;
+use uuid::Uuid;
+
+use crate::trade::TradeManager;
+
+use super::status::trade_status;
+
+pub async fn decline_offer(
+    Extension(player): Extension<Player>,
+    Path(offer_uuid): Path<Uuid>,
+) -> Result<Json<Value>, StatusCode> {
+    let manager = TradeManager::build();
+    if let Err(e) = manager {
+        return Err(trade_status(&e));
+    }
+    match manager.unwrap().decline(&player, &offer_uuid) {
+        Ok(offer) => Ok(Json(json!(offer))),
+        Err(e) => Err(trade_status(&e)),
+    }
+}
@@ -0,0 +1,21 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::{player::Player, trade::NewTradeOffer};
+
+use crate::trade::TradeManager;
+
+use super::status::trade_status;
+
+pub async fn propose_offer(
+    Extension(mut player): Extension<Player>,
+    Json(new_offer): Json<NewTradeOffer>,
+) -> Result<Json<Value>, StatusCode> {
+    let manager = TradeManager::build();
+    if let Err(e) = manager {
+        return Err(trade_status(&e));
+    }
+    match manager.unwrap().propose(&mut player, &new_offer) {
+        Ok(offer) => Ok(Json(json!(offer))),
+        Err(e) => Err(trade_status(&e)),
+    }
+}
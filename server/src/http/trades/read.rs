@@ -0,0 +1,20 @@
+use axum::{http::StatusCode, Extension, Json};
+use serde_json::{json, Value};
+use shared::player::Player;
+
+use crate::trade::TradeManager;
+
+use super::status::trade_status;
+
+pub async fn read_offers(
+    Extension(player): Extension<Player>,
+) -> Result<Json<Value>, StatusCode> {
+    let manager = TradeManager::build();
+    if let Err(e) = manager {
+        return Err(trade_status(&e));
+    }
+    match manager.unwrap().list_offers(&player) {
+        Ok(offers) => Ok(Json(json!(offers))),
+        Err(e) => Err(trade_status(&e)),
+    }
+}
@@ -0,0 +1,20 @@
+use axum::{routing::{delete, get, patch}, Router};
+
+use crate::http::middlewares::session_auth;
+
+use super::{
+    read::read_offers,
+    propose_offer::propose_offer,
+    accept_offer::accept_offer,
+    decline_offer::decline_offer,
+    reclaim_offer::reclaim_offer,
+};
+
+pub fn trades_routes() -> Router {
+    Router::new()
+        .route("/", get(read_offers).post(propose_offer))
+        .route("/:offer_uuid", delete(reclaim_offer))
+        .route("/:offer_uuid/accept", patch(accept_offer))
+        .route("/:offer_uuid/decline", patch(decline_offer))
+        .layer(axum::middleware::from_fn(session_auth))
+}
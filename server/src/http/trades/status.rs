@@ -0,0 +1,23 @@
+use axum::http::StatusCode;
+
+use shared::trade::TradeErrorKind;
+
+use crate::trade::{TradeManagerError, TradeManagerErrorKind};
+
+pub fn trade_status(error: &TradeManagerError) -> StatusCode {
+    match error.kind() {
+        TradeManagerErrorKind::OfferNotFound => StatusCode::NOT_FOUND,
+        TradeManagerErrorKind::PlayerNotFound => StatusCode::NOT_FOUND,
+        TradeManagerErrorKind::ItemNotFound => StatusCode::NOT_FOUND,
+        TradeManagerErrorKind::NotEnoughGold => StatusCode::PAYMENT_REQUIRED,
+        TradeManagerErrorKind::InventoryFull => StatusCode::CONFLICT,
+        TradeManagerErrorKind::Rejected(kind) => match kind {
+            TradeErrorKind::SelfTrade => StatusCode::BAD_REQUEST,
+            TradeErrorKind::EmptyOffer => StatusCode::BAD_REQUEST,
+            TradeErrorKind::NotRecipient => StatusCode::FORBIDDEN,
+            TradeErrorKind::NotSender => StatusCode::FORBIDDEN,
+            _ => StatusCode::CONFLICT,
+        },
+        TradeManagerErrorKind::Repository => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
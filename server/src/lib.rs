@@ -60,13 +60,46 @@ mod http {
     mod shop {
         mod routes;
         mod read;
+        mod next_restock;
         pub use routes::shop_routes;
     }
+    mod crafting {
+        mod routes;
+        mod status;
+        mod read;
+        mod salvage_item;
+        mod craft_item;
+        pub use routes::crafting_routes;
+    }
+    mod marketplace {
+        mod routes;
+        mod status;
+        mod read;
+        mod create_listing;
+        mod buy_listing;
+        mod bid_listing;
+        mod cancel_listing;
+        pub use routes::marketplace_routes;
+    }
+    mod trades {
+        mod routes;
+        mod status;
+        mod read;
+        mod propose_offer;
+        mod accept_offer;
+        mod decline_offer;
+        mod reclaim_offer;
+        pub use routes::trades_routes;
+    }
     mod player {
         mod routes;
         mod read;
         mod buy_item;
         mod sell_item;
+        mod repair_item;
+        mod upgrade_inventory;
+        mod claim_stashed;
+        mod healing_config;
         pub use routes::player_routes;
         mod tournaments {
             mod routes;
@@ -83,6 +116,17 @@ mod http {
             mod replace_protection;
             mod remove_warrior;
             mod gen_random_warrior;
+            mod set_tactics;
+            mod replace_shield;
+            mod repair_equipment;
+            mod equip;
+            mod loadouts;
+            mod use_consumable;
+            mod treat_injury;
+            mod fit_prosthetic;
+            mod set_activity;
+            mod perks;
+            mod training;
             pub use routes::player_warriors_routes;
         }
     }
@@ -111,6 +155,8 @@ mod tournament {
 mod warrior {
     mod manager;
     pub use manager::{WarriorManager, WarriorManagerError};
+    mod healing_config;
+    pub use healing_config::healing_config;
 }
 
 pub mod repository {
@@ -128,21 +174,37 @@ mod shop {
     mod error;
     pub use error::{ShopManagerError, ShopManagerErrorKind};
     mod public;
-    pub use public::read_shop;
+    pub use public::{read_shop, next_restock};
+}
+
+mod marketplace {
+    mod manager;
+    pub use manager::MarketplaceManager;
+    mod error;
+    pub use error::{MarketplaceManagerError, MarketplaceManagerErrorKind};
+}
+
+mod trade {
+    mod manager;
+    pub use manager::TradeManager;
+    mod error;
+    pub use error::{TradeManagerError, TradeManagerErrorKind};
 }
 
 use std::error::Error;
 
 use http::run_server;
+use marketplace::MarketplaceManager;
 use shop::ShopManager;
 use tournament::manager::TournamentManager;
+use warrior::WarriorManager;
 
 pub fn run(config: &Config) -> Result<(), Box<dyn Error>> {
     if config.run_tournaments {
         run_tournaments()?;
     }
     if config.reset_shop {
-        ShopManager::reset_shop()?;
+        ShopManager::build()?.reset_shop()?;
     }
     if config.start_server {
         run_server();
@@ -154,6 +216,11 @@ fn run_tournaments() -> Result<(), Box<dyn Error>> {
     let tournament_manager = TournamentManager::build()?;
     tournament_manager.run_tournaments()?;
     println!("Running tournaments");
+    WarriorManager::build()?.complete_all_trainings()?;
+    if ShopManager::build()?.restock_if_due()? {
+        println!("Shop restocked");
+    }
+    MarketplaceManager::build()?.settle_expired()?;
     Ok(())
 }
 
@@ -0,0 +1,60 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use shared::marketplace::{MarketplaceError, MarketplaceErrorKind};
+
+use crate::repository::RepositoryError;
+
+#[derive(Debug)]
+pub enum MarketplaceManagerErrorKind {
+    ListingNotFound,
+    ItemNotFound,
+    NotEnoughGold,
+    InventoryFull,
+    Rejected(MarketplaceErrorKind),
+    Repository,
+}
+
+#[derive(Debug)]
+pub struct MarketplaceManagerError {
+    kind: MarketplaceManagerErrorKind,
+    message: String,
+}
+
+impl MarketplaceManagerError {
+    pub fn new(kind: MarketplaceManagerErrorKind, context: &str) -> Self {
+        let message = match kind {
+            MarketplaceManagerErrorKind::ListingNotFound => "Listing not found",
+            MarketplaceManagerErrorKind::ItemNotFound => "Item not found",
+            MarketplaceManagerErrorKind::NotEnoughGold => "Not enough gold",
+            MarketplaceManagerErrorKind::InventoryFull => "Inventory is full",
+            MarketplaceManagerErrorKind::Rejected(_) => "Rejected",
+            MarketplaceManagerErrorKind::Repository => "Repository Error",
+        };
+        Self { kind, message: format!("Marketplace Manager Error:\n{message}\nContext:\n{context}") }
+    }
+
+    pub fn kind(&self) -> &MarketplaceManagerErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for MarketplaceManagerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for MarketplaceManagerError {}
+
+impl From<RepositoryError> for MarketplaceManagerError {
+    fn from(value: RepositoryError) -> Self {
+        Self::new(MarketplaceManagerErrorKind::Repository, &value.to_string())
+    }
+}
+
+impl From<MarketplaceError> for MarketplaceManagerError {
+    fn from(value: MarketplaceError) -> Self {
+        Self::new(MarketplaceManagerErrorKind::Rejected(value.kind().clone()), &value.to_string())
+    }
+}
@@ -0,0 +1,181 @@
+use std::path::PathBuf;
+
+use shared::{
+    inventory::{HasInventory, HasMutableInventory, Item, MutableItems},
+    marketplace::{check_price, transaction_fee, Listing, NewListing},
+    player::Player,
+    unique_entity::UniqueEntity,
+};
+use uuid::Uuid;
+
+use crate::repository::{FileRepository, PlayerRepository, Repository};
+
+use super::{MarketplaceManagerError, MarketplaceManagerErrorKind};
+
+pub struct MarketplaceManager<T: Repository<Listing>> {
+    repo: T,
+}
+
+impl MarketplaceManager<FileRepository<Listing>> {
+    pub fn build() -> Result<Self, MarketplaceManagerError> {
+        let repo = FileRepository::build(PathBuf::from("saves/marketplace"))?;
+        Ok(Self { repo })
+    }
+}
+
+impl<T: Repository<Listing>> MarketplaceManager<T> {
+    // Expired listings are settled on read
+    pub fn list_listings(&self) -> Result<Vec<Listing>, MarketplaceManagerError> {
+        self.settle_expired()?;
+        let mut listings = vec![];
+        for uuid in self.repo.list()? {
+            listings.push(self.repo.get_by_uuid(&uuid)?);
+        }
+        Ok(listings)
+    }
+
+    // The item goes to the highest bidder or back to the seller,
+    // the seller is paid the winning bid minus the transaction fee
+    pub fn settle_expired(&self) -> Result<(), MarketplaceManagerError> {
+        let player_repo = PlayerRepository::build()?;
+        for uuid in self.repo.list()? {
+            let listing = self.repo.get_by_uuid(&uuid)?;
+            if !listing.is_expired() {
+                continue;
+            }
+            let mut recipient = player_repo.get_by_uuid(listing.recipient())?;
+            let payout = listing.bid().map(|bid| bid.amount() - transaction_fee(bid.amount()));
+            let seller_uuid = *listing.seller();
+            // Goes to the recipient's stash when their inventory is full
+            recipient.inventory_mut().store_item(listing.into_item());
+            self.repo.delete(&uuid)?;
+            player_repo.update(recipient.uuid(), &recipient)?;
+            if let Some(payout) = payout {
+                let mut seller = player_repo.get_by_uuid(&seller_uuid)?;
+                seller.inventory_mut().add_gold(payout);
+                player_repo.update(seller.uuid(), &seller)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn create_listing(
+        &self,
+        player: &mut Player,
+        new_listing: &NewListing,
+    ) -> Result<Listing, MarketplaceManagerError> {
+        check_price(new_listing.price)?;
+        let item = player.inventory_mut().remove_item(&new_listing.slot_uuid);
+        if item.is_none() {
+            return Err(MarketplaceManagerError::new(
+                MarketplaceManagerErrorKind::ItemNotFound,
+                &format!("Slot {}", new_listing.slot_uuid),
+            ));
+        }
+        let listing = Listing::new(
+            *player.uuid(),
+            String::from(player.display_name()),
+            item.unwrap(),
+            new_listing.kind.clone(),
+            new_listing.price,
+        );
+        self.repo.create(&listing)?;
+        PlayerRepository::build()?.update(player.uuid(), player)?;
+        Ok(listing)
+    }
+
+    pub fn buy(&self, player: &mut Player, listing_uuid: &Uuid) -> Result<Item, MarketplaceManagerError> {
+        let listing = self.get_listing(listing_uuid)?;
+        let price = listing.check_purchase(player.uuid())?;
+        if player.inventory().gold() < price {
+            return Err(MarketplaceManagerError::new(
+                MarketplaceManagerErrorKind::NotEnoughGold,
+                &format!("Price is {price}"),
+            ));
+        }
+        let seller_uuid = *listing.seller();
+        let item = listing.into_item();
+        if player.inventory_mut().add_item(item.clone()).is_err() {
+            return Err(MarketplaceManagerError::new(
+                MarketplaceManagerErrorKind::InventoryFull,
+                &format!("Player {}", player.uuid()),
+            ));
+        }
+        player.inventory_mut().remove_gold(price);
+        self.repo.delete(listing_uuid)?;
+        let player_repo = PlayerRepository::build()?;
+        player_repo.update(player.uuid(), player)?;
+        let mut seller = player_repo.get_by_uuid(&seller_uuid)?;
+        seller.inventory_mut().add_gold(price - transaction_fee(price));
+        player_repo.update(seller.uuid(), &seller)?;
+        Ok(item)
+    }
+
+    // The bid is held in escrow, the outbid bidder gets their gold back
+    pub fn bid(
+        &self,
+        player: &mut Player,
+        listing_uuid: &Uuid,
+        amount: u32,
+    ) -> Result<Listing, MarketplaceManagerError> {
+        let mut listing = self.get_listing(listing_uuid)?;
+        let mut available_gold = player.inventory().gold();
+        if let Some(bid) = listing.bid() {
+            if bid.bidder() == player.uuid() {
+                available_gold += bid.amount();
+            }
+        }
+        if available_gold < amount {
+            return Err(MarketplaceManagerError::new(
+                MarketplaceManagerErrorKind::NotEnoughGold,
+                &format!("Bid is {amount}"),
+            ));
+        }
+        let outbid = listing.place_bid(player.uuid(), amount)?;
+        let player_repo = PlayerRepository::build()?;
+        if let Some(outbid) = outbid {
+            if outbid.bidder() == player.uuid() {
+                player.inventory_mut().add_gold(outbid.amount());
+            } else {
+                let mut bidder = player_repo.get_by_uuid(outbid.bidder())?;
+                bidder.inventory_mut().add_gold(outbid.amount());
+                player_repo.update(bidder.uuid(), &bidder)?;
+            }
+        }
+        player.inventory_mut().remove_gold(amount);
+        self.repo.update(listing_uuid, &listing)?;
+        player_repo.update(player.uuid(), player)?;
+        Ok(listing)
+    }
+
+    pub fn cancel(&self, player: &mut Player, listing_uuid: &Uuid) -> Result<Item, MarketplaceManagerError> {
+        let listing = self.get_listing(listing_uuid)?;
+        if listing.seller() != player.uuid() {
+            return Err(MarketplaceManagerError::new(
+                MarketplaceManagerErrorKind::ListingNotFound,
+                &format!("Listing {listing_uuid} is not sold by {}", player.uuid()),
+            ));
+        }
+        listing.check_cancel()?;
+        let item = listing.into_item();
+        if player.inventory_mut().add_item(item.clone()).is_err() {
+            return Err(MarketplaceManagerError::new(
+                MarketplaceManagerErrorKind::InventoryFull,
+                &format!("Player {}", player.uuid()),
+            ));
+        }
+        self.repo.delete(listing_uuid)?;
+        PlayerRepository::build()?.update(player.uuid(), player)?;
+        Ok(item)
+    }
+
+    fn get_listing(&self, listing_uuid: &Uuid) -> Result<Listing, MarketplaceManagerError> {
+        if !self.repo.list()?.contains(listing_uuid) {
+            return Err(MarketplaceManagerError::new(
+                MarketplaceManagerErrorKind::ListingNotFound,
+                &format!("Listing {listing_uuid}"),
+            ));
+        }
+        Ok(self.repo.get_by_uuid(listing_uuid)?)
+    }
+}
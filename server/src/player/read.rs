@@ -1,12 +1,19 @@
+use shared::inventory::HasMutableInventory;
 use shared::player::Player;
+use shared::unique_entity::UniqueEntity;
 use uuid::Uuid;
 
 use crate::auth::SessionManager;
+use crate::repository::{PlayerRepository, Repository};
 
 use super::PlayerAPIError;
 
 pub fn read_player(session_uuid: &Uuid) -> Result<Player, PlayerAPIError> {
     let manager = SessionManager::build()?;
-    let player = manager.read_player(session_uuid)?;
+    let mut player = manager.read_player(session_uuid)?;
+    // Stashed items are lost once they stayed there too long
+    if !player.inventory_mut().drop_expired_stash().is_empty() {
+        PlayerRepository::build()?.update(player.uuid(), &player)?;
+    }
     Ok(player)
 }
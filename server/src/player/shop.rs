@@ -8,16 +8,26 @@ use crate::{repository::{PlayerRepository, Repository}, shop::ShopManager};
 use super::PlayerAPIError;
 
 pub fn buy_item(player: &mut Player, slot_uuid: &Uuid) -> Result<Option<Item>, PlayerAPIError> {
-    let mut shop = ShopManager::read_shop()?;
-    match shop.inventory_mut().remove_item(slot_uuid) {
+    let shop_manager = ShopManager::build()?;
+    let mut shop = shop_manager.read_shop()?;
+    let price = match shop.price(slot_uuid) {
+        None => return Ok(None),
+        Some(price) => price,
+    };
+    if player.inventory().gold() < price {
+        return Ok(None);
+    }
+    match shop.take_one(slot_uuid) {
         None => Ok(None),
-        Some(item) => if player.inventory().gold() < item.gold_value() {
-            Ok(None)
-        } else {
-            player.inventory_mut().remove_gold(item.gold_value());
-            player.inventory_mut().add_item(item.clone());
+        Some(item) => {
+            // The shop is not saved when the item does not fit
+            if let Err(e) = player.inventory_mut().add_item(item.clone()) {
+                return Err(PlayerAPIError::new(&e.to_string()));
+            }
+            player.inventory_mut().remove_gold(price);
             let repo = PlayerRepository::build()?;
             repo.update(player.uuid(), &player)?;
+            shop_manager.save(&shop)?;
             Ok(Some(item))
         }
     }
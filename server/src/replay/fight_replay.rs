@@ -31,6 +31,10 @@ impl<T: Repository<Warrior>> FightReplayBuilder<T> {
     pub fn replay_uuid(&self) -> &Uuid {
         &self.replay_uuid
     }
+
+    pub fn turn_summaries(&self) -> &Vec<TurnSummary> {
+        &self.turn_summaries
+    }
 }
 
 impl FightReplayBuilder<FileRepository<Warrior>> {
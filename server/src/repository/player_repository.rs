@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
-use shared::inventory::{HasInventory, Inventory, MutableItems};
+use shared::inventory::{HasInventory, Inventory};
 use shared::player::{Player, PlayerBuildError, PlayerBuilder};
 use shared::unique_entity::UniqueEntity;
 use shared::warrior::{Warrior, WarriorCollection};
@@ -26,17 +26,12 @@ impl From<&Player> for PlayerDTOFile {
         for warrior in value.warriors() {
             warrior_ids.push(warrior.uuid().clone())
         }
-        let mut inventory = Inventory::new();
-        inventory.add_gold(value.inventory().gold());
-        for (_, item) in value.inventory().items() {
-            inventory.add_item(item.clone());
-        }
         Self {
             uuid: value.uuid().clone(),
             username: String::from(value.username()),
             display_name: String::from(value.display_name()),
             warrior_ids,
-            inventory,
+            inventory: value.inventory().clone(),
         }
     }
 }
@@ -69,6 +64,7 @@ impl<'a, T: Repository<Warrior>> PlayerBuilder for PlayerBuilderFromRepo<'a, T>
     fn build_warriors(&mut self) -> Result<(), PlayerBuildError> {
         let warrior_manager = WarriorManager::build()?;
         warrior_manager.apply_passive_healing(&self.dto.warrior_ids)?;
+        warrior_manager.complete_trainings(&self.dto.warrior_ids)?;
         for warrior_uuid in &self.dto.warrior_ids {
             let warrior: Warrior = self.warriors_repo.get_by_uuid(&warrior_uuid)?;
             self.warriors.push(warrior);
@@ -102,6 +98,18 @@ impl PlayerRepository<FileRepository<PlayerDTOFile>, FileRepository<Warrior>> {
     }
 }
 
+impl<T: Repository<PlayerDTOFile>, K: Repository<Warrior>> PlayerRepository<T, K> {
+    pub fn find_by_username(&self, username: &str) -> Result<Option<Uuid>, RepositoryError> {
+        for uuid in self.dto_repo.list()? {
+            let dto = self.dto_repo.get_by_uuid(&uuid)?;
+            if dto.username == username {
+                return Ok(Some(uuid));
+            }
+        }
+        Ok(None)
+    }
+}
+
 impl<T: Repository<PlayerDTOFile>, K: Repository<Warrior>> Repository<Player> for PlayerRepository<T, K> {
     fn list(&self) -> Result<Vec<Uuid>, RepositoryError> {
         self.dto_repo.list()
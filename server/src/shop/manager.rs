@@ -1,109 +1,212 @@
-use std::{fs, path::PathBuf};
+use std::fs;
+use std::path::{Path, PathBuf};
 
+use rand::Rng;
 use shared::{
-    equipment::{protection::{Protection, ProtectionKind}, weapon::{Weapon, WeaponKind}},
-    inventory::{Inventory, Item, MutableItems},
+    consumable::Consumable,
+    equipment::{
+        protection::{Protection, ProtectionKind},
+        shield::{Shield, ShieldKind},
+        weapon::{Weapon, WeaponKind},
+    },
+    inventory::{Inventory, Item},
+    loot::random_loot,
     shop::Shop,
+    stats::StatKind,
+    unique_entity::UniqueEntity,
 };
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::repository::{FileRepository, Repository, RepositoryError};
 
 use super::{ShopManagerError, ShopManagerErrorKind};
 
-const SHOP_INVENTORY_DIR: &'static str = "data/shop";
-const SHOP_INVENTORY_NAME: &'static str = "inventory.json";
+const SHOP_LOOT_COUNT: usize = 4;
+// Base templates are restocked with up to this many copies, possibly none
+const MAX_TEMPLATE_STOCK: u8 = 3;
+// Consumables are always available
+const CONSUMABLE_STOCK: u8 = 10;
+// Where the single shop was stored before stocks and restocks existed
+const LEGACY_SHOP_DIR: &str = "data/shop";
+const LEGACY_SHOP_PATH: &str = "data/shop/inventory.json";
+
+#[derive(Deserialize)]
+struct LegacyShop {
+    inventory: Inventory,
+}
 
-pub struct ShopManager {}
+pub struct ShopManager<T: Repository<Shop>> {
+    repo: T,
+}
 
-impl ShopManager {
-    fn path() -> String {
-        SHOP_INVENTORY_DIR.to_string() + "/" + SHOP_INVENTORY_NAME
+impl ShopManager<FileRepository<Shop>> {
+    pub fn build() -> Result<Self, ShopManagerError> {
+        let repo = FileRepository::build(PathBuf::from("data/shops"))?;
+        let manager = Self { repo };
+        manager.migrate_legacy_shop()?;
+        Ok(manager)
     }
 
-    pub fn read_shop() -> Result<Shop, ShopManagerError> {
-        let serialized_shop = fs::read_to_string(Self::path());
-        if let Err(_) = serialized_shop {
-            return Err(ShopManagerError::new(
-                &ShopManagerErrorKind::ReadError,
-                format!("Read from path {} failed", Self::path()),
-            ))
+    // The legacy items are kept as single copies until the next restock
+    fn migrate_legacy_shop(&self) -> Result<(), ShopManagerError> {
+        if !Path::new(LEGACY_SHOP_PATH).exists() {
+            return Ok(());
         }
-        let serialized_shop = serialized_shop.unwrap();
-        let shop = serde_json::from_str(&serialized_shop);
-        if let Err(_) = shop {
-            return Err(ShopManagerError::new(
+        let legacy_shop: Result<LegacyShop, String> = fs::read_to_string(LEGACY_SHOP_PATH)
+            .map_err(|e| e.to_string())
+            .and_then(|serialized| serde_json::from_str(&serialized).map_err(|e| e.to_string()));
+        let legacy_shop = match legacy_shop {
+            Ok(legacy_shop) => legacy_shop,
+            Err(e) => return Err(ShopManagerError::new(
                 &ShopManagerErrorKind::ReadError,
-                format!("Deserialization Failed:\n{}", serialized_shop),
-            ));
+                format!("Read of legacy shop {LEGACY_SHOP_PATH} failed:\n{e}"),
+            )),
+        };
+        if self.repo.list()?.is_empty() {
+            let mut shop = Shop::new(Uuid::new_v4());
+            for item in legacy_shop.inventory.items().values() {
+                shop.add_stock(item.clone(), 1);
+            }
+            self.repo.create(&shop)?;
         }
-        let shop = shop.unwrap();
-        Ok(shop)
-    }
-
-    pub fn reset_shop() -> Result<(), ShopManagerError> {
-        let mut inventory = Inventory::new();
-
-        let weapon = Weapon::new(WeaponKind::Axe);
-        inventory.add_item(Item::Weapon(weapon));
-        let weapon = Weapon::new(WeaponKind::BattleAxe);
-        inventory.add_item(Item::Weapon(weapon));
-        let weapon = Weapon::new(WeaponKind::GreatSword);
-        inventory.add_item(Item::Weapon(weapon));
-        let weapon = Weapon::new(WeaponKind::Hammer);
-        inventory.add_item(Item::Weapon(weapon));
-        let weapon = Weapon::new(WeaponKind::Sword);
-        inventory.add_item(Item::Weapon(weapon));
-        let weapon = Weapon::new(WeaponKind::WarHammer);
-        inventory.add_item(Item::Weapon(weapon));
-
-        let protection = Protection::new(ProtectionKind::Armlets);
-        inventory.add_item(Item::Protection(protection));
-        let protection = Protection::new(ProtectionKind::Boots);
-        inventory.add_item(Item::Protection(protection));
-        let protection = Protection::new(ProtectionKind::Breastplate);
-        inventory.add_item(Item::Protection(protection));
-        let protection = Protection::new(ProtectionKind::ChainMail);
-        inventory.add_item(Item::Protection(protection));
-        let protection = Protection::new(ProtectionKind::Gambeson);
-        inventory.add_item(Item::Protection(protection));
-        let protection = Protection::new(ProtectionKind::Gloves);
-        inventory.add_item(Item::Protection(protection));
-        let protection = Protection::new(ProtectionKind::Greaves);
-        inventory.add_item(Item::Protection(protection));
-        let protection = Protection::new(ProtectionKind::Helm);
-        inventory.add_item(Item::Protection(protection));
-
-        let shop = Shop::new(inventory);
-
-        let dir_exist = PathBuf::from(SHOP_INVENTORY_DIR).as_path().try_exists();
-        if let Err(e) = dir_exist {
+        if fs::remove_dir_all(LEGACY_SHOP_DIR).is_err() {
             return Err(ShopManagerError::new(
                 &ShopManagerErrorKind::ResetError,
-                format!("Can't check dir {SHOP_INVENTORY_DIR} existence:\n{e}")
+                format!("Removal of legacy shop {LEGACY_SHOP_DIR} failed"),
             ))
         }
-        let dir_exist = dir_exist.unwrap();
-        if !dir_exist {
-            if let Err(e) = fs::create_dir_all(SHOP_INVENTORY_DIR) {
-                return Err(ShopManagerError::new(
-                    &ShopManagerErrorKind::ResetError,
-                    format!("Can't create dir {SHOP_INVENTORY_DIR}:\n{e}")
-                ))
-            }
+        Ok(())
+    }
+}
+
+impl<T: Repository<Shop>> ShopManager<T> {
+    // Restocks are run by the scheduler, reads catch up if it is late
+    pub fn read_shop(&self) -> Result<Shop, ShopManagerError> {
+        let shop_uuid = self.repo.list()?.first().copied();
+        match shop_uuid {
+            None => {
+                let shop = restocked_shop(Uuid::new_v4());
+                self.repo.create(&shop)?;
+                Ok(shop)
+            },
+            Some(uuid) => {
+                let shop = self.repo.get_by_uuid(&uuid);
+                if let Err(e) = shop {
+                    return Err(ShopManagerError::new(
+                        &ShopManagerErrorKind::ReadError,
+                        format!("Read of shop {uuid} failed:\n{e}"),
+                    ))
+                }
+                let shop = shop.unwrap();
+                if shop.needs_restock() {
+                    let shop = restocked_shop(uuid);
+                    self.repo.update(&uuid, &shop)?;
+                    Ok(shop)
+                } else {
+                    Ok(shop)
+                }
+            },
         }
+    }
 
-        let serialized_shop = serde_json::to_string(&shop);
-        if let Err(_) = serialized_shop {
-            return Err(ShopManagerError::new(
-                &ShopManagerErrorKind::ResetError,
-                format!("Serialization Failed:\n{:?}", shop),
-            ));
+    // server only, called by the scheduler
+    pub fn restock_if_due(&self) -> Result<bool, ShopManagerError> {
+        let shop_uuid = self.repo.list()?.first().copied();
+        let due = match shop_uuid {
+            None => true,
+            Some(uuid) => self.repo.get_by_uuid(&uuid)?.needs_restock(),
+        };
+        if due {
+            self.read_shop()?;
         }
-        let serialized_shop = serialized_shop.unwrap();
-        if let Err(_) = fs::write(Self::path(), &serialized_shop) {
+        Ok(due)
+    }
+
+    pub fn save(&self, shop: &Shop) -> Result<(), ShopManagerError> {
+        self.repo.update(shop.uuid(), shop)?;
+        Ok(())
+    }
+
+    pub fn reset_shop(&self) -> Result<(), ShopManagerError> {
+        let shop = self.read_shop()?;
+        let shop = restocked_shop(*shop.uuid());
+        if self.repo.update(shop.uuid(), &shop).is_err() {
             return Err(ShopManagerError::new(
                 &ShopManagerErrorKind::ResetError,
-                format!("Write to path {} failed", Self::path()),
+                format!("Write of shop {} failed", shop.uuid()),
             ))
         }
         Ok(())
     }
 }
+
+fn template_stock() -> u8 {
+    rand::thread_rng().gen_range(0..=MAX_TEMPLATE_STOCK)
+}
+
+fn restocked_shop(uuid: Uuid) -> Shop {
+    let mut shop = Shop::new(uuid);
+
+    let weapon_kinds = [
+        WeaponKind::Axe,
+        WeaponKind::BattleAxe,
+        WeaponKind::GreatSword,
+        WeaponKind::Hammer,
+        WeaponKind::Sword,
+        WeaponKind::WarHammer,
+    ];
+    for kind in weapon_kinds {
+        shop.add_stock(Item::Weapon(Weapon::new(kind)), template_stock());
+    }
+
+    let protection_kinds = [
+        ProtectionKind::Armlets,
+        ProtectionKind::Boots,
+        ProtectionKind::Breastplate,
+        ProtectionKind::ChainMail,
+        ProtectionKind::Gambeson,
+        ProtectionKind::Gloves,
+        ProtectionKind::Greaves,
+        ProtectionKind::Helm,
+    ];
+    for kind in protection_kinds {
+        shop.add_stock(Item::Protection(Protection::new(kind)), template_stock());
+    }
+
+    let shield_kinds = [
+        ShieldKind::Buckler,
+        ShieldKind::RoundShield,
+        ShieldKind::TowerShield,
+    ];
+    for kind in shield_kinds {
+        shop.add_stock(Item::Shield(Shield::new(kind)), template_stock());
+    }
+
+    let consumables = [
+        Consumable::HealingPotion,
+        Consumable::Bandage,
+        Consumable::Antidote,
+        Consumable::RageDraught,
+        Consumable::Tonic(StatKind::Attack),
+        Consumable::Tonic(StatKind::Parry),
+        Consumable::Tonic(StatKind::Courage),
+        Consumable::Tonic(StatKind::Dexterity),
+        Consumable::Tonic(StatKind::Strength),
+    ];
+    for consumable in consumables {
+        shop.add_stock(Item::Consumable(consumable), CONSUMABLE_STOCK);
+    }
+
+    for _ in 0..SHOP_LOOT_COUNT {
+        shop.add_stock(random_loot(), 1);
+    }
+
+    shop
+}
+
+impl From<RepositoryError> for ShopManagerError {
+    fn from(value: RepositoryError) -> Self {
+        Self::new(&ShopManagerErrorKind::ReadError, format!("Repository Error:\n{value}"))
+    }
+}
@@ -5,10 +5,15 @@ use shared::shop::Shop;
 use super::{ShopManager, ShopManagerError};
 
 pub fn read_shop() -> Result<Shop, ShopAPIError> {
-    let shop = ShopManager::read_shop()?;
+    let shop = ShopManager::build()?.read_shop()?;
     Ok(shop)
 }
 
+pub fn next_restock() -> Result<i64, ShopAPIError> {
+    let shop = ShopManager::build()?.read_shop()?;
+    Ok(shop.next_restock())
+}
+
 #[derive(Debug)]
 pub struct ShopAPIError {
     message: String,
@@ -1,6 +1,8 @@
 use rand::Rng;
-use shared::experience::GainExperience;
+use shared::experience::{Experience, GainExperience};
 use shared::inventory::Inventory;
+use shared::replay::{FightFeats, TournamentPlacement, XpAward};
+use shared::stamina::HasMutableStamina;
 use shared::tournament::{Fighter, Tournament, TournamentError};
 use shared::unique_entity::UniqueEntity;
 use shared::warrior::Warrior;
@@ -87,6 +89,7 @@ impl AutoTournament for Tournament {
                 self.uuid(),
                 round_index,
             )?;
+            let is_final = remaining_contestants_ids.len() == 2;
             let pairs = self.gen_random_pairs(&mut remaining_contestants_ids);
             for pair in pairs {
                 let mut fight_replay_builder = FightReplayBuilder::build(self.uuid())?;
@@ -95,7 +98,7 @@ impl AutoTournament for Tournament {
                 fight_replay_builder.record_warriors_init_state(&warrior1, &warrior2)?;
                 let mut fighter1 = Fighter::from(&warrior1);
                 let mut fighter2 = Fighter::from(&warrior2);
-                let result = Fight::auto(
+                let mut result = Fight::auto(
                     &mut fight_replay_builder,
                     &mut fighter1,
                     &mut fighter2,
@@ -103,20 +106,50 @@ impl AutoTournament for Tournament {
                 fight_replay_builder.write_turn_summaries()?;
                 let inventory1 = fighter1.consume(&mut warrior1);
                 let inventory2 = fighter2.consume(&mut warrior2);
+                warrior1.stamina_mut().recover_between_rounds();
+                warrior2.stamina_mut().recover_between_rounds();
+                let levels = (warrior1.level(), warrior2.level());
+                let mut award1 = XpAward::for_fight(
+                    warrior1.uuid(),
+                    levels,
+                    &result,
+                    &FightFeats::from_turns(warrior1.uuid(), fight_replay_builder.turn_summaries()),
+                );
+                let mut award2 = XpAward::for_fight(
+                    warrior2.uuid(),
+                    (levels.1, levels.0),
+                    &result,
+                    &FightFeats::from_turns(warrior2.uuid(), fight_replay_builder.turn_summaries()),
+                );
                 if let Some(winner_uuid) = result.winner() {
                     let mut fight_rewards = Inventory::new();
                     fight_rewards.add_gold(self.fight_reward(round_index as usize));
-                    if warrior1.uuid() == winner_uuid {
-                        warrior1.gain_xp(20);
-                        self.add_to_contestant_inventory(warrior1.uuid(), fight_rewards);
+                    let (winner_award, loser_award) = if warrior1.uuid() == winner_uuid {
+                        (&mut award1, &mut award2)
                     } else {
-                        warrior2.gain_xp(20);
-                        self.add_to_contestant_inventory(warrior2.uuid(), fight_rewards);
+                        (&mut award2, &mut award1)
+                    };
+                    if is_final {
+                        if let Some(award) = winner_award {
+                            award.add_placement(TournamentPlacement::Champion);
+                        }
+                        if let Some(award) = loser_award {
+                            award.add_placement(TournamentPlacement::RunnerUp);
+                        }
                     }
+                    log_stashed(winner_uuid, self.add_to_contestant_inventory(winner_uuid, fight_rewards));
                     remaining_contestants_ids.push(winner_uuid.clone())
                 }
-                self.add_to_contestant_inventory(warrior1.uuid(), inventory1);
-                self.add_to_contestant_inventory(warrior2.uuid(), inventory2);
+                if let Some(award) = award1 {
+                    warrior1.gain_xp(award.total());
+                    result.push_xp_award(award);
+                }
+                if let Some(award) = award2 {
+                    warrior2.gain_xp(award.total());
+                    result.push_xp_award(award);
+                }
+                log_stashed(warrior1.uuid(), self.add_to_contestant_inventory(warrior1.uuid(), inventory1));
+                log_stashed(warrior2.uuid(), self.add_to_contestant_inventory(warrior2.uuid(), inventory2));
                 repo.update(warrior1.uuid(), &warrior1)?;
                 repo.update(warrior2.uuid(), &warrior2)?;
                 round_replay_builder.push_summary(result);
@@ -127,3 +160,10 @@ impl AutoTournament for Tournament {
         Ok(())
     }
 }
+
+// Stashed loot follows the rest of the contestant's inventory back to its player
+fn log_stashed(warrior_uuid: &Uuid, stashed: usize) {
+    if stashed > 0 {
+        eprintln!("[INFO] {stashed} item(s) won by warrior {warrior_uuid} went to the stash");
+    }
+}
@@ -3,16 +3,24 @@ use std::fmt::Display;
 
 use shared::assault::assault_order_comparable::AssaultOrderComparable;
 use shared::equipment::weapon::OptionalMutableWeapon;
-use shared::health::{IsDead, IsUnconscious};
+use shared::health::{IsDead, IsUnconscious, MutableHealth};
+use shared::stamina::HasStamina;
 use shared::replay::turn_summary::TurnSummary;
-use shared::replay::FightReplaySummary;
+use shared::replay::{FightEndReason, FightReplaySummary};
 use shared::tournament::Fighter;
 use shared::unique_entity::UniqueEntity;
-use shared::warrior::Warrior;
+use shared::warrior::{severed_limbs, HasTactics, Warrior};
+use uuid::Uuid;
 
 use crate::repository::Repository;
 use crate::replay::{FightReplayBuilder, FightReplayBuilderError};
 
+// An exhausted fighter surrenders once its health falls to max / ratio
+const SURRENDER_HEALTH_RATIO: u8 = 3;
+// Weapon proficiency earned for each attack attempted and for killing the opponent
+const ATTACK_PROFICIENCY_XP: u32 = 1;
+const KILL_PROFICIENCY_XP: u32 = 10;
+
 #[derive(Debug)]
 pub struct Fight {}
 
@@ -31,49 +39,124 @@ impl Fight {
         } else {
             (red_corner, blue_corner)
         };
+        let mut first_assailant_damages_dealt: u32 = 0;
+        let mut second_assailant_damages_dealt: u32 = 0;
+        let first_assailant_initial_severed_limbs = severed_limbs(first_assailant);
+        let second_assailant_initial_severed_limbs = severed_limbs(second_assailant);
 
         while turn < u8::MAX {
+            first_assailant.adapt_stance();
+            second_assailant.adapt_stance();
             let turn_summary = TurnSummary::new(
                 first_assailant,
                 second_assailant,
             );
+            first_assailant_damages_dealt += turn_summary.damages_dealt_by(first_assailant.uuid()) as u32;
+            second_assailant_damages_dealt += turn_summary.damages_dealt_by(second_assailant.uuid()) as u32;
+            let first_assailant_attacks = turn_summary.attacks_made_by(first_assailant.uuid());
+            let second_assailant_attacks = turn_summary.attacks_made_by(second_assailant.uuid());
             replay_builder.push_turn_summary(turn_summary);
             turn += 1;
-            if first_assailant.is_dead()
-                || first_assailant.is_unconscious()
-                || first_assailant.weapon().is_none()
-            {
+            if first_assailant_attacks > 0 {
+                first_assailant.practice(ATTACK_PROFICIENCY_XP * first_assailant_attacks);
+            }
+            if second_assailant_attacks > 0 {
+                second_assailant.practice(ATTACK_PROFICIENCY_XP * second_assailant_attacks);
+            }
+            if let Some(reason) = Self::defeat_reason(first_assailant) {
+                if reason == FightEndReason::Death {
+                    second_assailant.practice(KILL_PROFICIENCY_XP);
+                }
                 let result = FightReplaySummary::new(
                     replay_builder.replay_uuid().clone(),
                     Some(second_assailant.uuid().clone()),
                     blue_corner_uuid,
                     red_corner_uuid,
+                    reason,
                 );
                 return Ok(result);
             }
-            replay_builder.replay_uuid();
-            if second_assailant.is_dead()
-                || second_assailant.is_unconscious()
-                || second_assailant.weapon().is_none()
-            {
+            if let Some(reason) = Self::defeat_reason(second_assailant) {
+                if reason == FightEndReason::Death {
+                    first_assailant.practice(KILL_PROFICIENCY_XP);
+                }
                 let result = FightReplaySummary::new(
                     replay_builder.replay_uuid().clone(),
                     Some(first_assailant.uuid().clone()),
                     blue_corner_uuid,
                     red_corner_uuid,
+                    reason,
+                );
+                return Ok(result);
+            }
+            let surrendering = if Self::surrenders(first_assailant, first_assailant_initial_severed_limbs) {
+                Some(*second_assailant.uuid())
+            } else if Self::surrenders(second_assailant, second_assailant_initial_severed_limbs) {
+                Some(*first_assailant.uuid())
+            } else {
+                None
+            };
+            if surrendering.is_some() {
+                let result = FightReplaySummary::new(
+                    *replay_builder.replay_uuid(),
+                    surrendering,
+                    blue_corner_uuid,
+                    red_corner_uuid,
+                    FightEndReason::Surrender,
                 );
                 return Ok(result);
             }
         }
 
+        let winner = Self::judge_decision(
+            (first_assailant.uuid(), first_assailant_damages_dealt),
+            (second_assailant.uuid(), second_assailant_damages_dealt),
+        );
+        let reason = if winner.is_some() {
+            FightEndReason::JudgeDecision
+        } else {
+            FightEndReason::Draw
+        };
         let result = FightReplaySummary::new(
             replay_builder.replay_uuid().clone(),
-            None,
+            winner,
             blue_corner_uuid,
             red_corner_uuid,
+            reason,
         );
         return Ok(result);
     }
+
+    fn defeat_reason(fighter: &Fighter) -> Option<FightEndReason> {
+        if fighter.is_dead() {
+            Some(FightEndReason::Death)
+        } else if fighter.is_unconscious() {
+            Some(FightEndReason::KnockOut)
+        } else if fighter.weapon().is_none() {
+            Some(FightEndReason::Disarmed)
+        } else {
+            None
+        }
+    }
+
+    fn surrenders(fighter: &Fighter, severed_limbs_at_start: usize) -> bool {
+        if fighter.tactics().wants_to_surrender(fighter, severed_limbs_at_start) {
+            return true;
+        }
+        fighter.stamina().is_exhausted()
+            && fighter.health().current() <= fighter.health().max() / SURRENDER_HEALTH_RATIO
+    }
+
+    // Each side is given with the damages it dealt, the judges pick the one who dealt the most
+    fn judge_decision(first: (&Uuid, u32), second: (&Uuid, u32)) -> Option<Uuid> {
+        if first.1 > second.1 {
+            Some(*first.0)
+        } else if second.1 > first.1 {
+            Some(*second.0)
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -100,3 +183,23 @@ impl From<FightReplayBuilderError> for FightError {
         Self::new(format!("Fight Builder Error: \n{value}"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn judges_pick_who_dealt_most_damages() {
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        assert_eq!(Fight::judge_decision((&first, 12), (&second, 3)), Some(first));
+        assert_eq!(Fight::judge_decision((&first, 0), (&second, 1)), Some(second));
+    }
+
+    #[test]
+    fn judges_declare_draw_on_equal_damages() {
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        assert_eq!(Fight::judge_decision((&first, 7), (&second, 7)), None);
+    }
+}
@@ -104,12 +104,16 @@ impl<T: Repository<Tournament>> TournamentManager<T> {
             warriors_manager.apply_passive_healing(&tournament.contestants_ids())?;
             let bot_player_uuid = self.gen_bot_player(&mut tournament)?;
             tournament.auto()?;
+            warriors_manager.clear_tonics(&tournament.contestants_ids())?;
             for (player_uuid, contestants) in tournament.contestants().clone() {
                 let player_repository = PlayerRepository::build()?;
                 let mut player = player_repository.get_by_uuid(&player_uuid)?;
                 for warrior_uuid in contestants {
                     if let Some(inventory) = tournament.take_contestant_inventory(&warrior_uuid) {
-                        player.inventory_mut().join(inventory);
+                        let stashed = player.inventory_mut().join(inventory);
+                        if stashed > 0 {
+                            eprintln!("[INFO] {stashed} item(s) of player {player_uuid} went to the stash");
+                        }
                     }
                 }
                 player_repository.update(&player_uuid, &player)?;
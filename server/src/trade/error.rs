@@ -0,0 +1,62 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use shared::trade::{TradeError, TradeErrorKind};
+
+use crate::repository::RepositoryError;
+
+#[derive(Debug)]
+pub enum TradeManagerErrorKind {
+    OfferNotFound,
+    PlayerNotFound,
+    ItemNotFound,
+    NotEnoughGold,
+    InventoryFull,
+    Rejected(TradeErrorKind),
+    Repository,
+}
+
+#[derive(Debug)]
+pub struct TradeManagerError {
+    kind: TradeManagerErrorKind,
+    message: String,
+}
+
+impl TradeManagerError {
+    pub fn new(kind: TradeManagerErrorKind, context: &str) -> Self {
+        let message = match kind {
+            TradeManagerErrorKind::OfferNotFound => "Trade offer not found",
+            TradeManagerErrorKind::PlayerNotFound => "Player not found",
+            TradeManagerErrorKind::ItemNotFound => "Item not found",
+            TradeManagerErrorKind::NotEnoughGold => "Not enough gold",
+            TradeManagerErrorKind::InventoryFull => "Inventory is full",
+            TradeManagerErrorKind::Rejected(_) => "Rejected",
+            TradeManagerErrorKind::Repository => "Repository Error",
+        };
+        Self { kind, message: format!("Trade Manager Error:\n{message}\nContext:\n{context}") }
+    }
+
+    pub fn kind(&self) -> &TradeManagerErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for TradeManagerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for TradeManagerError {}
+
+impl From<RepositoryError> for TradeManagerError {
+    fn from(value: RepositoryError) -> Self {
+        Self::new(TradeManagerErrorKind::Repository, &value.to_string())
+    }
+}
+
+impl From<TradeError> for TradeManagerError {
+    fn from(value: TradeError) -> Self {
+        Self::new(TradeManagerErrorKind::Rejected(value.kind().clone()), &value.to_string())
+    }
+}
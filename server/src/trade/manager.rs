@@ -0,0 +1,153 @@
+use std::path::PathBuf;
+
+use shared::{
+    inventory::{HasInventory, HasMutableInventory, Inventory, MutableItems},
+    player::Player,
+    trade::{NewTradeOffer, TradeOffer},
+    unique_entity::UniqueEntity,
+};
+use uuid::Uuid;
+
+use crate::repository::{FileRepository, PlayerRepository, Repository};
+
+use super::{TradeManagerError, TradeManagerErrorKind};
+
+pub struct TradeManager<T: Repository<TradeOffer>> {
+    repo: T,
+}
+
+impl TradeManager<FileRepository<TradeOffer>> {
+    pub fn build() -> Result<Self, TradeManagerError> {
+        let repo = FileRepository::build(PathBuf::from("saves/trades"))?;
+        Ok(Self { repo })
+    }
+}
+
+impl<T: Repository<TradeOffer>> TradeManager<T> {
+    // Pending and declined offers along with the history of accepted ones
+    pub fn list_offers(&self, player: &Player) -> Result<Vec<TradeOffer>, TradeManagerError> {
+        let mut offers = vec![];
+        for uuid in self.repo.list()? {
+            let offer = self.repo.get_by_uuid(&uuid)?;
+            if offer.involves(player.uuid()) {
+                offers.push(offer);
+            }
+        }
+        offers.sort_by_key(|offer| offer.created_at());
+        Ok(offers)
+    }
+
+    pub fn propose(
+        &self,
+        player: &mut Player,
+        new_offer: &NewTradeOffer,
+    ) -> Result<TradeOffer, TradeManagerError> {
+        let player_repo = PlayerRepository::build()?;
+        let recipient_uuid = player_repo.find_by_username(&new_offer.recipient_username)?;
+        if recipient_uuid.is_none() {
+            return Err(TradeManagerError::new(
+                TradeManagerErrorKind::PlayerNotFound,
+                &format!("Username {}", new_offer.recipient_username),
+            ));
+        }
+        let recipient = player_repo.get_by_uuid(&recipient_uuid.unwrap())?;
+        if player.inventory().gold() < new_offer.gold {
+            return Err(TradeManagerError::new(
+                TradeManagerErrorKind::NotEnoughGold,
+                &format!("Offered {} gold", new_offer.gold),
+            ));
+        }
+        let mut offered = Inventory::new();
+        for slot_uuid in &new_offer.slot_uuids {
+            match player.inventory_mut().remove_item(slot_uuid) {
+                None => return Err(TradeManagerError::new(
+                    TradeManagerErrorKind::ItemNotFound,
+                    &format!("Slot {slot_uuid}"),
+                )),
+                Some(item) => { offered.items_mut().insert(*slot_uuid, item); },
+            }
+        }
+        offered.add_gold(new_offer.gold);
+        let offer = TradeOffer::new(
+            (*player.uuid(), String::from(player.display_name())),
+            (*recipient.uuid(), String::from(recipient.display_name())),
+            offered,
+            new_offer.requested_gold,
+        )?;
+        player.inventory_mut().remove_gold(new_offer.gold);
+        self.repo.create(&offer)?;
+        player_repo.update(player.uuid(), player)?;
+        Ok(offer)
+    }
+
+    // Every check is done before either inventory is written
+    pub fn accept(&self, player: &mut Player, offer_uuid: &Uuid) -> Result<TradeOffer, TradeManagerError> {
+        let mut offer = self.get_offer(offer_uuid)?;
+        offer.check_answer(player.uuid())?;
+        if player.inventory().free_slots() < offer.offered().items().len() {
+            return Err(TradeManagerError::new(
+                TradeManagerErrorKind::InventoryFull,
+                &format!("Player {}", player.uuid()),
+            ));
+        }
+        if player.inventory().gold() < offer.requested_gold() {
+            return Err(TradeManagerError::new(
+                TradeManagerErrorKind::NotEnoughGold,
+                &format!("Requested {} gold", offer.requested_gold()),
+            ));
+        }
+        let player_repo = PlayerRepository::build()?;
+        let mut sender = player_repo.get_by_uuid(offer.sender())?;
+        let received = offer.accept(player.uuid())?;
+        let stashed = player.inventory_mut().join(received);
+        log_stashed(player.uuid(), stashed);
+        player.inventory_mut().remove_gold(offer.requested_gold());
+        sender.inventory_mut().add_gold(offer.requested_gold());
+        self.repo.update(offer_uuid, &offer)?;
+        player_repo.update(player.uuid(), player)?;
+        player_repo.update(sender.uuid(), &sender)?;
+        Ok(offer)
+    }
+
+    pub fn decline(&self, player: &Player, offer_uuid: &Uuid) -> Result<TradeOffer, TradeManagerError> {
+        let mut offer = self.get_offer(offer_uuid)?;
+        offer.decline(player.uuid())?;
+        self.repo.update(offer_uuid, &offer)?;
+        Ok(offer)
+    }
+
+    // Gives the escrow back to the sender
+    pub fn reclaim(&self, player: &mut Player, offer_uuid: &Uuid) -> Result<(), TradeManagerError> {
+        let offer = self.get_offer(offer_uuid)?;
+        offer.check_reclaim(player.uuid())?;
+        if player.inventory().free_slots() < offer.offered().items().len() {
+            return Err(TradeManagerError::new(
+                TradeManagerErrorKind::InventoryFull,
+                &format!("Player {}", player.uuid()),
+            ));
+        }
+        let escrow = offer.reclaim(player.uuid())?;
+        let stashed = player.inventory_mut().join(escrow);
+        log_stashed(player.uuid(), stashed);
+        self.repo.delete(offer_uuid)?;
+        PlayerRepository::build()?.update(player.uuid(), player)?;
+        Ok(())
+    }
+
+    fn get_offer(&self, offer_uuid: &Uuid) -> Result<TradeOffer, TradeManagerError> {
+        if !self.repo.list()?.contains(offer_uuid) {
+            return Err(TradeManagerError::new(
+                TradeManagerErrorKind::OfferNotFound,
+                &format!("Offer {offer_uuid}"),
+            ));
+        }
+        Ok(self.repo.get_by_uuid(offer_uuid)?)
+    }
+}
+
+// Free slots are checked beforehand, anything stashed means the check missed something
+fn log_stashed(player_uuid: &Uuid, stashed: usize) {
+    if stashed > 0 {
+        eprintln!("[WARN] {stashed} traded item(s) of player {player_uuid} went to the stash");
+    }
+}
@@ -0,0 +1,22 @@
+use std::str::FromStr;
+
+use shared::health::HealingConfig;
+
+fn env_or<T: FromStr>(name: &str, default: T) -> T {
+    match std::env::var(name) {
+        Ok(value) => value.parse().unwrap_or(default),
+        Err(_) => default,
+    }
+}
+
+// Each server can tune healing through the environment
+pub fn healing_config() -> HealingConfig {
+    let default = HealingConfig::default();
+    HealingConfig {
+        interval: env_or("PASSIVE_HEAL_INTERVAL", default.interval),
+        resting_ratio: env_or("PASSIVE_HEAL_RESTING_RATIO", default.resting_ratio),
+        training_ratio: env_or("PASSIVE_HEAL_TRAINING_RATIO", default.training_ratio),
+        infirmary_ratio: env_or("PASSIVE_HEAL_INFIRMARY_RATIO", default.infirmary_ratio),
+        registered_ratio: env_or("PASSIVE_HEAL_REGISTERED_RATIO", default.registered_ratio),
+    }
+}
@@ -1,10 +1,17 @@
 use std::{error::Error, fmt::Display, path::PathBuf};
 
-use shared::{health::MutablePassiveHealing, unique_entity::UniqueEntity, warrior::Warrior};
+use shared::{
+    consumable::HasMutableTonic,
+    health::MutablePassiveHealing,
+    unique_entity::UniqueEntity,
+    warrior::{Trainee, Warrior},
+};
 use uuid::Uuid;
 
 use crate::repository::{FileRepository, Repository, RepositoryError};
 
+use super::healing_config;
+
 pub struct WarriorManager {
     repo: FileRepository<Warrior>,
 }
@@ -16,14 +23,41 @@ impl WarriorManager {
     }
 
     pub fn apply_passive_healing(&self, warrior_uuids: &[Uuid]) -> Result<(), WarriorManagerError> {
+        let config = healing_config();
         for uuid in warrior_uuids {
             let mut warrior = self.repo.get_by_uuid(uuid)?;
-            warrior.passive_heal();
+            warrior.passive_heal(&config);
             self.repo.update(warrior.uuid(), &warrior)?;
         }
         Ok(())
     }
 
+    pub fn complete_trainings(&self, warrior_uuids: &[Uuid]) -> Result<(), WarriorManagerError> {
+        for uuid in warrior_uuids {
+            let mut warrior = self.repo.get_by_uuid(uuid)?;
+            if warrior.complete_training() {
+                self.repo.update(warrior.uuid(), &warrior)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Resolves every training session that ended, whoever the warrior belongs to
+    pub fn complete_all_trainings(&self) -> Result<(), WarriorManagerError> {
+        self.complete_trainings(&self.repo.list()?)
+    }
+
+    // Tonics only last for one tournament
+    pub fn clear_tonics(&self, warrior_uuids: &[Uuid]) -> Result<(), WarriorManagerError> {
+        for uuid in warrior_uuids {
+            let mut warrior = self.repo.get_by_uuid(uuid)?;
+            if warrior.tonic_mut().take().is_some() {
+                self.repo.update(warrior.uuid(), &warrior)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn save(&self, warrior: &Warrior) -> Result<(), WarriorManagerError> {
         self.repo.update(warrior.uuid(), warrior)?;
         Ok(())
@@ -15,7 +15,7 @@ fn update_player_inventory() -> Result<(), Box<dyn Error>> {
     let weapon = Weapon::random();
     
     assert!(player.inventory().items().len() < 1);
-    player.inventory_mut().add_item(Item::Weapon(weapon));
+    player.inventory_mut().add_item(Item::Weapon(weapon)).unwrap();
     repo.update(player.uuid(), &player)?;
 
     let player = repo.get_by_uuid(player.uuid())?;
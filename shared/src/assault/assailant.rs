@@ -2,10 +2,13 @@ use crate::health::IsDead;
 use crate::health::IsUnconscious;
 use crate::inventory::HasMutableInventory;
 use crate::knock_out::KnockOut;
+use crate::stamina::HasMutableStamina;
+use crate::status_effect::HasMutableStatusEffects;
 use crate::temporary_handicap::OptionalMutableAssaultMisses;
 use crate::temporary_handicap::OptionalMutableParryMisses;
 use crate::unique_entity::UniqueEntity;
 use crate::warrior::body::HasMutableBody;
+use crate::warrior::HasStance;
 
 use super::assault_order_comparable::AssaultOrderComparable;
 use super::attack_attempt::AttackAttempt;
@@ -16,7 +19,6 @@ use super::critical_hit::DealCriticalHit;
 use super::critical_hit::ResolveCriticalHit;
 use super::critical_parry::DealCriticalParry;
 use super::critical_parry::ResolveCriticalParry;
-use super::duration_damages::TakeDurationDamages;
 use super::end_turn_consequences::EndTurnConsequencesBuilder;
 use super::common_traits::ReduceDamages;
 use super::common_traits::DealDamages;
@@ -40,7 +42,8 @@ pub trait Assailant:
     DealDamages +
     ReduceDamages +
     TakeDamage +
-    TakeDurationDamages +
+    HasMutableStatusEffects +
+    HasMutableStamina +
     IsDead +
     IsUnconscious +
     OptionalMutableAssaultMisses +
@@ -54,5 +57,6 @@ pub trait Assailant:
     KnockOut +
     EndTurnConsequencesBuilder +
     AssaultOrderComparable +
-    HasMutableInventory
+    HasMutableInventory +
+    HasStance
 {}
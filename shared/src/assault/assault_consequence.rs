@@ -2,14 +2,14 @@ use serde::{Deserialize, Serialize};
 
 use crate::equipment::protection::OptionalMutableProtection;
 use crate::equipment::rupture::{Rupture, RUPTURE_MAX};
-use crate::inventory::{Item, MutableItems};
+use crate::inventory::Item;
 use crate::warrior::body::body_part::{BodyPartKind, OptionalMutableBodyPart};
 use crate::warrior::body::injury::{Injuries, Injury};
+use crate::status_effect::StatusEffect;
 use crate::temporary_handicap::TemporaryHandicap;
 
 use super::assailant::Assailant;
 use super::critical_hit::CriticalHit;
-use super::duration_damages::DurationDamages;
 
 pub trait AssaultConsequencesBuilder {
     fn to_consequences(&self, assailant: &dyn Assailant, victim: & dyn Assailant) -> AssaultConsequences;
@@ -27,7 +27,8 @@ pub struct IndividualConsequences {
     raw_damages: u8,
     armor_damages: Option<ArmorDamages>,
     injury: Option<Injury>,
-    duration_damages: Option<DurationDamages>,
+    #[serde(default)]
+    status_effect: Option<StatusEffect>,
     knock_out: bool,
     assault_misses: Option<TemporaryHandicap>,
     parry_misses: Option<TemporaryHandicap>,
@@ -35,6 +36,8 @@ pub struct IndividualConsequences {
     weapon_damages: Option<u8>,
     counter_critical_hit: Option<CriticalHit>,
     self_critical_hit: Option<CriticalHit>,
+    #[serde(default)]
+    shield_damages: Option<u8>,
 }
 
 impl IndividualConsequences {
@@ -43,7 +46,7 @@ impl IndividualConsequences {
         raw_damages: u8,
         armor_damages: Option<ArmorDamages>,
         injury: Option<Injury>,
-        duration_damages: Option<DurationDamages>,
+        status_effect: Option<StatusEffect>,
         knock_out: bool,
         assault_misses: Option<TemporaryHandicap>,
         parry_misses: Option<TemporaryHandicap>,
@@ -57,7 +60,7 @@ impl IndividualConsequences {
             raw_damages,
             armor_damages,
             injury,
-            duration_damages,
+            status_effect,
             knock_out,
             assault_misses,
             parry_misses,
@@ -65,6 +68,7 @@ impl IndividualConsequences {
             weapon_damages,
             counter_critical_hit,
             self_critical_hit,
+            shield_damages: None,
         }
     }
 
@@ -74,7 +78,7 @@ impl IndividualConsequences {
             raw_damages: 0,
             armor_damages: None,
             injury: None,
-            duration_damages: None,
+            status_effect: None,
             knock_out: false,
             assault_misses: None,
             parry_misses: None,
@@ -82,6 +86,7 @@ impl IndividualConsequences {
             weapon_damages: None,
             counter_critical_hit: None,
             self_critical_hit: None,
+            shield_damages: None,
         }
     }
 
@@ -91,7 +96,7 @@ impl IndividualConsequences {
             raw_damages: 0,
             armor_damages: None,
             injury: None,
-            duration_damages: None,
+            status_effect: None,
             knock_out: false,
             assault_misses: None,
             parry_misses: None,
@@ -99,6 +104,7 @@ impl IndividualConsequences {
             weapon_damages: None,
             counter_critical_hit: None,
             self_critical_hit: None,
+            shield_damages: None,
         }
     }
 
@@ -108,7 +114,7 @@ impl IndividualConsequences {
             raw_damages,
             armor_damages: None,
             injury: None,
-            duration_damages: None,
+            status_effect: None,
             knock_out: false,
             assault_misses: None,
             parry_misses: None,
@@ -116,6 +122,7 @@ impl IndividualConsequences {
             weapon_damages: None,
             counter_critical_hit: None,
             self_critical_hit: None,
+            shield_damages: None,
         }
     }
 
@@ -125,7 +132,7 @@ impl IndividualConsequences {
             raw_damages,
             armor_damages: Some(armor_damages),
             injury: None,
-            duration_damages: None,
+            status_effect: None,
             knock_out: false,
             assault_misses: None,
             parry_misses: None,
@@ -133,6 +140,7 @@ impl IndividualConsequences {
             weapon_damages: None,
             counter_critical_hit: None,
             self_critical_hit: None,
+            shield_damages: None,
         }
     }
 
@@ -145,7 +153,7 @@ impl IndividualConsequences {
             raw_damages,
             armor_damages: None,
             injury: Some(injury),
-            duration_damages: None,
+            status_effect: None,
             knock_out: false,
             assault_misses: None,
             parry_misses: None,
@@ -153,6 +161,7 @@ impl IndividualConsequences {
             weapon_damages: None,
             counter_critical_hit: None,
             self_critical_hit: None,
+            shield_damages: None,
         }
     }
 
@@ -166,7 +175,7 @@ impl IndividualConsequences {
             raw_damages,
             armor_damages: Some(armor_damages),
             injury: Some(injury),
-            duration_damages: None,
+            status_effect: None,
             knock_out: false,
             assault_misses: None,
             parry_misses: None,
@@ -174,16 +183,17 @@ impl IndividualConsequences {
             weapon_damages: None,
             counter_critical_hit: None,
             self_critical_hit: None,
+            shield_damages: None,
         }
     }
 
-    pub fn damage_on_duration(raw_damages: u8, duration_damages: DurationDamages) -> Self {
+    pub fn inflict_status_effect(raw_damages: u8, status_effect: StatusEffect) -> Self {
         Self {
             damages: 0,
             raw_damages,
             armor_damages: None,
             injury: None,
-            duration_damages: Some(duration_damages),
+            status_effect: Some(status_effect),
             knock_out: false,
             assault_misses: None,
             parry_misses: None,
@@ -191,6 +201,7 @@ impl IndividualConsequences {
             weapon_damages: None,
             counter_critical_hit: None,
             self_critical_hit: None,
+            shield_damages: None,
         }
     }
 
@@ -200,7 +211,7 @@ impl IndividualConsequences {
             raw_damages,
             armor_damages: None,
             injury: None,
-            duration_damages: None,
+            status_effect: None,
             knock_out: true,
             assault_misses: None,
             parry_misses: None,
@@ -208,6 +219,7 @@ impl IndividualConsequences {
             weapon_damages: None,
             counter_critical_hit: None,
             self_critical_hit: None,
+            shield_damages: None,
         }
     }
 
@@ -217,7 +229,7 @@ impl IndividualConsequences {
             raw_damages: 0,
             armor_damages: None,
             injury: None,
-            duration_damages: None,
+            status_effect: None,
             knock_out: false,
             assault_misses: Some(misses),
             parry_misses: None,
@@ -225,6 +237,7 @@ impl IndividualConsequences {
             weapon_damages: None,
             counter_critical_hit: None,
             self_critical_hit: None,
+            shield_damages: None,
         }
     }
 
@@ -234,7 +247,7 @@ impl IndividualConsequences {
             raw_damages: 0,
             armor_damages: None,
             injury: None,
-            duration_damages: None,
+            status_effect: None,
             knock_out: false,
             assault_misses: Some(misses.clone()),
             parry_misses: Some(misses),
@@ -242,6 +255,7 @@ impl IndividualConsequences {
             weapon_damages: None,
             counter_critical_hit: None,
             self_critical_hit: None,
+            shield_damages: None,
         }
     }
 
@@ -251,7 +265,7 @@ impl IndividualConsequences {
             raw_damages: 0,
             armor_damages: None,
             injury: None,
-            duration_damages: None,
+            status_effect: None,
             knock_out: false,
             assault_misses: None,
             parry_misses: None,
@@ -259,6 +273,7 @@ impl IndividualConsequences {
             weapon_damages: None,
             counter_critical_hit: None,
             self_critical_hit: None,
+            shield_damages: None,
         }
     }
 
@@ -268,7 +283,7 @@ impl IndividualConsequences {
             raw_damages: 0,
             armor_damages: None,
             injury: None,
-            duration_damages: None,
+            status_effect: None,
             knock_out: false,
             assault_misses: None,
             parry_misses: None,
@@ -276,9 +291,61 @@ impl IndividualConsequences {
             weapon_damages: Some(rupture_damages),
             counter_critical_hit: None,
             self_critical_hit: None,
+            shield_damages: None,
         }
     }
 
+    pub fn damage_shield(rupture_damages: u8) -> Self {
+        Self::no_consequences().with_shield_damages(rupture_damages)
+    }
+
+    pub fn damages_and_damage_armor(damages: u8, armor_damages: ArmorDamages) -> Self {
+        Self {
+            damages,
+            raw_damages: 0,
+            armor_damages: Some(armor_damages),
+            injury: None,
+            status_effect: None,
+            knock_out: false,
+            assault_misses: None,
+            parry_misses: None,
+            drop_weapon: false,
+            weapon_damages: None,
+            counter_critical_hit: None,
+            self_critical_hit: None,
+            shield_damages: None,
+        }
+    }
+
+    pub fn damages_and_miss_assaults(damages: u8, misses: TemporaryHandicap) -> Self {
+        Self {
+            damages,
+            raw_damages: 0,
+            armor_damages: None,
+            injury: None,
+            status_effect: None,
+            knock_out: false,
+            assault_misses: Some(misses),
+            parry_misses: None,
+            drop_weapon: false,
+            weapon_damages: None,
+            counter_critical_hit: None,
+            self_critical_hit: None,
+            shield_damages: None,
+        }
+    }
+
+    // Adds a lingering effect on top of the other consequences
+    pub fn with_status_effect(mut self, status_effect: StatusEffect) -> Self {
+        self.status_effect = Some(status_effect);
+        self
+    }
+
+    pub fn with_shield_damages(mut self, rupture_damages: u8) -> Self {
+        self.shield_damages = Some(rupture_damages);
+        self
+    }
+
     fn apply(&self, victim: &mut dyn Assailant) {
         victim.take_damage(self.damages);
         victim.take_damage(self.raw_damages);
@@ -290,7 +357,7 @@ impl IndividualConsequences {
                 Injury::RightArmSevered |
                 Injury::RightHandSevered => {
                     if let Some(weapon) = victim.weapon_mut().take() {
-                        victim.inventory_mut().add_item(Item::Weapon(weapon));
+                        victim.inventory_mut().store_item(Item::Weapon(weapon));
                     }
                 },
                 Injury::LeftArmSevered |
@@ -298,19 +365,25 @@ impl IndividualConsequences {
                     if let Some(weapon) = victim.weapon() {
                         if weapon.is_two_handed() {
                             let lost_weapon = victim.weapon_mut().take().unwrap();
-                            victim.inventory_mut().add_item(Item::Weapon(lost_weapon));
+                            victim.inventory_mut().store_item(Item::Weapon(lost_weapon));
                         }
                     }
+                    if let Some(shield) = victim.shield_mut().take() {
+                        victim.inventory_mut().store_item(Item::Shield(shield));
+                    }
                 }
                 _ => {},
             }
             let severed_parts = victim.body_mut().add_injury(injury.clone());
             for mut part in severed_parts.into_iter() {
                 if let Some(protection) = part.protection_mut().take() {
-                    victim.inventory_mut().add_item(Item::Protection(protection));
+                    victim.inventory_mut().store_item(Item::Protection(protection));
                 }
             }
         }
+        if let Some(status_effect) = &self.status_effect {
+            victim.status_effects_mut().inflict(status_effect.clone());
+        }
         if self.knock_out {
             victim.knock_out();
         }
@@ -323,7 +396,7 @@ impl IndividualConsequences {
         }
         if self.drop_weapon {
             if let Some(weapon) = victim.weapon_mut().take() {
-                victim.inventory_mut().add_item(Item::Weapon(weapon));
+                victim.inventory_mut().store_item(Item::Weapon(weapon));
             }
         }
         if let Some(rupture_damages) = &self.weapon_damages {
@@ -336,19 +409,32 @@ impl IndividualConsequences {
                 }
             }
         }
+        if let Some(rupture_damages) = &self.shield_damages {
+            if let Some(shield) = victim.shield_mut() {
+                shield.damage_rupture(*rupture_damages);
+                if let Some(rup) = shield.rupture() {
+                    if *rup >= RUPTURE_MAX {
+                        victim.shield_mut().take();
+                    }
+                }
+            }
+        }
     }
 
     pub fn weapon_damages(&self) -> &Option<u8> {
         &self.weapon_damages
     }
+    pub fn shield_damages(&self) -> &Option<u8> {
+        &self.shield_damages
+    }
     pub fn injury(&self) -> &Option<Injury> {
         &self.injury
     }
     pub fn armor_damages(&self) -> &Option<ArmorDamages> {
         &self.armor_damages
     }
-    pub fn duration_damages(&self) -> &Option<DurationDamages> {
-        &self.duration_damages
+    pub fn status_effect(&self) -> &Option<StatusEffect> {
+        &self.status_effect
     }
     pub fn damages(&self) -> u8 {
         self.damages
@@ -453,7 +539,7 @@ mod tests {
         assert_eq!(actual.damages, 0);
         assert_eq!(actual.armor_damages.is_none(), true);
         assert_eq!(actual.injury, None);
-        assert_eq!(actual.duration_damages.is_none(), true);
+        assert_eq!(actual.status_effect.is_none(), true);
         assert_eq!(actual.knock_out, false);
         assert_eq!(actual.assault_misses.is_some(), true);
         assert_eq!(actual.assault_misses.unwrap().count(), 2);
@@ -472,7 +558,7 @@ mod tests {
         assert_eq!(actual.damages, 0);
         assert_eq!(actual.armor_damages.is_none(), true);
         assert_eq!(actual.injury, None);
-        assert_eq!(actual.duration_damages.is_none(), true);
+        assert_eq!(actual.status_effect.is_none(), true);
         assert_eq!(actual.knock_out, false);
         assert_eq!(actual.assault_misses.is_some(), true);
         assert_eq!(actual.parry_misses.is_some(), true);
@@ -488,7 +574,7 @@ mod tests {
         assert_eq!(actual.damages, 0);
         assert_eq!(actual.armor_damages.is_none(), true);
         assert_eq!(actual.injury, None);
-        assert_eq!(actual.duration_damages.is_none(), true);
+        assert_eq!(actual.status_effect.is_none(), true);
         assert_eq!(actual.knock_out, false);
         assert_eq!(actual.assault_misses.is_none(), true);
         assert_eq!(actual.parry_misses.is_none(), true);
@@ -504,7 +590,7 @@ mod tests {
         assert_eq!(actual.damages, 0);
         assert_eq!(actual.armor_damages.is_none(), true);
         assert_eq!(actual.injury, None);
-        assert_eq!(actual.duration_damages.is_none(), true);
+        assert_eq!(actual.status_effect.is_none(), true);
         assert_eq!(actual.knock_out, false);
         assert_eq!(actual.assault_misses.is_none(), true);
         assert_eq!(actual.parry_misses.is_none(), true);
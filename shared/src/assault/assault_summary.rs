@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::random::Random;
+use crate::warrior::body::body_part::BodyPartKind;
 
 use super::assailant::Assailant;
 use super::assault_consequence::{AssaultConsequences, AssaultConsequencesBuilder};
@@ -62,6 +63,17 @@ impl AssaultSummary {
         &self.attack_success
     }
 
+    // Where the blow landed, if it was neither parried nor turned into a parry clumsiness
+    pub fn hit_location(&self) -> Option<&BodyPartKind> {
+        if self.parry_clumsiness.is_some()
+            || self.parry_success.is_some()
+            || self.parry_critical.is_some()
+        {
+            return None;
+        }
+        self.attack_success.as_ref()?.hit_location().as_ref()
+    }
+
     pub fn parry_clumsiness(&self) -> &Option<ParryClumsiness> {
         &self.parry_clumsiness
     }
@@ -82,6 +94,19 @@ impl AssaultSummary {
         &self.consequences
     }
 
+    // Damages each side inflicted on the other, fumbles hurting oneself are left out
+    pub fn damages_dealt_by(&self, uuid: &Uuid) -> u8 {
+        if uuid == &self.assailant_uuid && self.parry_clumsiness.is_none() {
+            let for_victim = self.consequences.for_victim();
+            for_victim.damages().saturating_add(for_victim.raw_damages())
+        } else if uuid == &self.victim_uuid && self.parry_critical.is_some() {
+            let for_assailant = self.consequences.for_assailant();
+            for_assailant.damages().saturating_add(for_assailant.raw_damages())
+        } else {
+            0
+        }
+    }
+
     pub fn new(assailant: &dyn Assailant, victim: &dyn Assailant) -> Self {
         let not_possible = assailant.can_attack(victim);
         let mut attack_clumsiness = None;
@@ -105,7 +130,7 @@ impl AssaultSummary {
                     attack_missed.as_ref().unwrap().to_consequences(assailant, victim)
                 },
                 AttackAttemptResult::Success => {
-                    attack_success = Some(AttackSuccess::new());
+                    attack_success = Some(AttackSuccess::new(victim.body().roll_hit_location()));
                     parry_not_possible = victim.can_parry();
                     if let Some(_) = &parry_not_possible {
                         attack_success.as_ref().unwrap().to_consequences(assailant, victim)
@@ -12,8 +12,15 @@ pub trait AttackThreshold {
 }
 
 pub trait AttackAttempt: AttackThreshold {
+    fn attack_clumsiness_threshold(&self) -> u8 {
+        20
+    }
+
     fn attack_attempt(&self) -> AttackAttemptResult {
-        match Dice::D20.test_roll(self.attack_threshold()) {
+        match Dice::D20.test_roll_with_critical_failure(
+            self.attack_threshold(),
+            self.attack_clumsiness_threshold(),
+        ) {
             TestRollResult::CriticalSuccess => AttackAttemptResult::CriticalSuccess,
             TestRollResult::Success => AttackAttemptResult::Success,
             TestRollResult::Failure => AttackAttemptResult::Failure,
@@ -1,28 +1,69 @@
 use serde::{Deserialize, Serialize};
 
+use crate::equipment::protection::OptionalMutableProtection;
+use crate::equipment::rupture::{Rupture, RuptureTestResult};
+use crate::warrior::body::body_part::{BodyPartKind, OptionalBodyPart};
+use crate::warrior::body::HasBody;
+
 use super::assailant::Assailant;
-use super::assault_consequence::{AssaultConsequences, AssaultConsequencesBuilder, IndividualConsequences};
+use super::assault_consequence::{ArmorDamages, AssaultConsequences, AssaultConsequencesBuilder, IndividualConsequences};
 use super::common_traits::ReduceDamages;
 
 #[derive(Debug, Serialize, Deserialize)]
-pub struct AttackSuccess {}
+pub struct AttackSuccess {
+    #[serde(default)]
+    hit_location: Option<BodyPartKind>,
+}
 
 impl AttackSuccess {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(hit_location: BodyPartKind) -> Self {
+        Self { hit_location: Some(hit_location) }
+    }
+
+    pub fn hit_location(&self) -> &Option<BodyPartKind> {
+        &self.hit_location
     }
 }
 
 impl AssaultConsequencesBuilder for AttackSuccess {
     fn to_consequences(&self, assailant: & dyn Assailant, victim: & dyn Assailant) -> AssaultConsequences {
         let for_assailant = IndividualConsequences::no_consequences();
-        let for_victim = victim.resolve_hit(assailant.deal_damages());
+        let for_victim = match &self.hit_location {
+            Some(location) => victim.resolve_hit_at(assailant.deal_damages(), location),
+            None => victim.resolve_hit(assailant.deal_damages()),
+        };
         AssaultConsequences::new(for_assailant, for_victim)
     }
 }
 
-pub trait ResolveAttackSuccess: ReduceDamages {
+pub trait ResolveAttackSuccess: ReduceDamages + HasBody {
+    // Damages absorbed by the skin itself, wherever the blow lands
+    fn natural_armor(&self) -> u8 {
+        0
+    }
+
     fn resolve_hit(&self, damages: u8) -> IndividualConsequences {
-        IndividualConsequences::only_damages(self.reduce_damages(damages))
+        self.resolve_hit_at(damages, &self.body().roll_hit_location())
+    }
+
+    // The protection on the struck body part absorbs the blow and may wear out
+    fn resolve_hit_at(&self, damages: u8, location: &BodyPartKind) -> IndividualConsequences {
+        let damages = self.body()
+            .reduce_damages_at(damages, location)
+            .saturating_sub(self.natural_armor());
+        let protection = self.body()
+            .body_part(location)
+            .as_ref()
+            .and_then(|body_part| body_part.protection().as_ref());
+        match protection {
+            Some(protection) => match protection.rupture_test() {
+                RuptureTestResult::Success => IndividualConsequences::only_damages(damages),
+                RuptureTestResult::Fail => IndividualConsequences::damages_and_damage_armor(
+                    damages,
+                    ArmorDamages::new(1, location.clone()),
+                ),
+            },
+            None => IndividualConsequences::only_damages(damages),
+        }
     }
 }
@@ -0,0 +1,258 @@
+use crate::dice::Dice;
+use crate::equipment::weapon::Weapon;
+
+use super::CriticalHit;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CriticalHitTable {
+    Sharp,
+    Blunt,
+    TwoHandedSharp,
+    TwoHandedBlunt,
+}
+
+impl CriticalHitTable {
+    pub fn roll(&self) -> CriticalHit {
+        self.critical_hit(Dice::D20.roll())
+    }
+
+    pub fn critical_hit(&self, face: u8) -> CriticalHit {
+        match self {
+            Self::Sharp => sharp_critical(face),
+            Self::Blunt => blunt_critical(face),
+            Self::TwoHandedSharp => two_handed_sharp_critical(face),
+            Self::TwoHandedBlunt => two_handed_blunt_critical(face),
+        }
+    }
+}
+
+impl From<&Weapon> for CriticalHitTable {
+    fn from(weapon: &Weapon) -> Self {
+        match (weapon.is_sharp(), weapon.is_two_handed()) {
+            (true, false) => Self::Sharp,
+            (false, false) => Self::Blunt,
+            (true, true) => Self::TwoHandedSharp,
+            (false, true) => Self::TwoHandedBlunt,
+        }
+    }
+}
+
+fn sharp_critical(face: u8) -> CriticalHit {
+    match face {
+        1..=3 => CriticalHit::DeepIncision,
+        4..=6 => CriticalHit::ReallyDeepIncision,
+        7 => CriticalHit::ImpressiveWoundAndArmorDamage,
+        8 => CriticalHit::PreciseHitAndArmorDamage,
+        9 => CriticalHit::AccurateHeavyBlowAndArmorDamage,
+        10 => CriticalHit::GougedEye,
+        11 | 12 => CriticalHit::SeveredHand,
+        13 => CriticalHit::SeveredFoot,
+        14 => CriticalHit::SeveredArm,
+        15 => CriticalHit::SeveredLeg,
+        16 | 17 => CriticalHit::WoundedGenitals,
+        18 | 19 => CriticalHit::VitalOrganDamage,
+        20 => CriticalHit::HeartInjury,
+        other => panic!("D20 roll resulted in {other}"),
+    }
+}
+
+fn two_handed_sharp_critical(face: u8) -> CriticalHit {
+    match face {
+        1 | 2 => CriticalHit::ReallyDeepIncision,
+        3 => CriticalHit::ImpressiveWoundAndArmorDamage,
+        4 => CriticalHit::AccurateHeavyBlowAndArmorDamage,
+        5 | 6 => CriticalHit::PartOfTheArmorIsDestroyed,
+        7 => CriticalHit::GougedEye,
+        8 | 9 => CriticalHit::SeveredHand,
+        10 | 11 => CriticalHit::SeveredFoot,
+        12 | 13 => CriticalHit::SeveredArm,
+        14 | 15 => CriticalHit::SeveredLeg,
+        16 => CriticalHit::WoundedGenitals,
+        17 | 18 => CriticalHit::VitalOrganDamage,
+        19 => CriticalHit::HeartInjury,
+        20 => CriticalHit::SeriousHeadWound,
+        other => panic!("D20 roll resulted in {other}"),
+    }
+}
+
+fn blunt_critical(face: u8) -> CriticalHit {
+    match face {
+        1 | 2 => CriticalHit::ImpressiveBruise,
+        3 | 4 => CriticalHit::ImpressiveBruiseAndLimbDislocation,
+        5 | 6 => CriticalHit::RibFacture,
+        7 => CriticalHit::PreciseHitAndArmorDamage,
+        8 => CriticalHit::AccurateHeavyBlowAndArmorDamage,
+        9 | 10 => CriticalHit::KneeDislocation,
+        11 | 12 => CriticalHit::BrokenHand,
+        13 => CriticalHit::SmashedFoot,
+        14 => CriticalHit::BrokenArm,
+        15 => CriticalHit::BrokenLeg,
+        16 => CriticalHit::CrushedGenitals,
+        17 | 18 => CriticalHit::KnockedOut,
+        19 => CriticalHit::OpenSkullFacture,
+        20 => CriticalHit::VitalOrganCrushed,
+        other => panic!("D20 roll resulted in {other}"),
+    }
+}
+
+fn two_handed_blunt_critical(face: u8) -> CriticalHit {
+    match face {
+        1 | 2 => CriticalHit::ImpressiveBruiseAndLimbDislocation,
+        3 | 4 => CriticalHit::RibFacture,
+        5 => CriticalHit::AccurateHeavyBlowAndArmorDamage,
+        6 => CriticalHit::PartOfTheArmorIsDestroyed,
+        7 => CriticalHit::KneeDislocation,
+        8 => CriticalHit::BrokenHand,
+        9 | 10 => CriticalHit::SmashedFoot,
+        11 | 12 => CriticalHit::BrokenArm,
+        13 | 14 => CriticalHit::BrokenLeg,
+        15 => CriticalHit::CrushedGenitals,
+        16 | 17 => CriticalHit::KnockedOut,
+        18 | 19 => CriticalHit::OpenSkullFacture,
+        20 => CriticalHit::VitalOrganCrushed,
+        other => panic!("D20 roll resulted in {other}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::equipment::weapon::WeaponKind;
+
+    use super::*;
+
+    const TABLES: [CriticalHitTable; 4] = [
+        CriticalHitTable::Sharp,
+        CriticalHitTable::Blunt,
+        CriticalHitTable::TwoHandedSharp,
+        CriticalHitTable::TwoHandedBlunt,
+    ];
+
+    fn is_bleeding(critical_hit: &CriticalHit) -> bool {
+        matches!(
+            critical_hit,
+            CriticalHit::DeepIncision
+                | CriticalHit::ReallyDeepIncision
+                | CriticalHit::ImpressiveWoundAndArmorDamage
+                | CriticalHit::WoundedGenitals
+                | CriticalHit::VitalOrganDamage
+                | CriticalHit::HeartInjury
+        )
+    }
+
+    fn is_severing(critical_hit: &CriticalHit) -> bool {
+        matches!(
+            critical_hit,
+            CriticalHit::SeveredHand
+                | CriticalHit::SeveredFoot
+                | CriticalHit::SeveredArm
+                | CriticalHit::SeveredLeg
+                | CriticalHit::GougedEye
+        )
+    }
+
+    fn is_fracture(critical_hit: &CriticalHit) -> bool {
+        matches!(
+            critical_hit,
+            CriticalHit::ImpressiveBruiseAndLimbDislocation
+                | CriticalHit::RibFacture
+                | CriticalHit::KneeDislocation
+                | CriticalHit::BrokenHand
+                | CriticalHit::SmashedFoot
+                | CriticalHit::BrokenArm
+                | CriticalHit::BrokenLeg
+                | CriticalHit::OpenSkullFacture
+        )
+    }
+
+    fn is_knock_out(critical_hit: &CriticalHit) -> bool {
+        matches!(critical_hit, CriticalHit::KnockedOut)
+    }
+
+    fn count(table: &CriticalHitTable, predicate: fn(&CriticalHit) -> bool) -> usize {
+        (1..=20).filter(|face| predicate(&table.critical_hit(*face))).count()
+    }
+
+    #[test]
+    fn every_face_has_an_outcome() {
+        for table in TABLES {
+            for face in 1..=20 {
+                table.critical_hit(face);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn face_out_of_range_panics() {
+        CriticalHitTable::Sharp.critical_hit(21);
+    }
+
+    #[test]
+    fn sharp_tables_favor_bleeding_and_severing() {
+        for table in [CriticalHitTable::Sharp, CriticalHitTable::TwoHandedSharp] {
+            assert_eq!(count(&table, is_fracture), 0);
+            assert_eq!(count(&table, is_knock_out), 0);
+            assert!(count(&table, is_bleeding) + count(&table, is_severing) > 10);
+        }
+        assert!(count(&CriticalHitTable::Sharp, is_bleeding) >= 10);
+    }
+
+    #[test]
+    fn blunt_tables_favor_fractures_and_knock_outs() {
+        for table in [CriticalHitTable::Blunt, CriticalHitTable::TwoHandedBlunt] {
+            assert_eq!(count(&table, is_bleeding), 0);
+            assert_eq!(count(&table, is_severing), 0);
+            assert!(count(&table, is_knock_out) >= 2);
+            assert!(count(&table, is_fracture) + count(&table, is_knock_out) > 10);
+        }
+    }
+
+    #[test]
+    fn two_handed_tables_are_heavier() {
+        assert!(
+            count(&CriticalHitTable::TwoHandedSharp, is_severing)
+                > count(&CriticalHitTable::Sharp, is_severing)
+        );
+        assert!(
+            count(&CriticalHitTable::TwoHandedBlunt, is_fracture)
+                > count(&CriticalHitTable::Blunt, is_fracture)
+        );
+    }
+
+    #[test]
+    fn rolls_follow_table_distribution() {
+        let rolls = 2000;
+        let sharp_severing = (0..rolls)
+            .filter(|_| is_severing(&CriticalHitTable::Sharp.roll()))
+            .count();
+        let blunt_severing = (0..rolls)
+            .filter(|_| is_severing(&CriticalHitTable::Blunt.roll()))
+            .count();
+        let blunt_knock_out = (0..rolls)
+            .filter(|_| is_knock_out(&CriticalHitTable::Blunt.roll()))
+            .count();
+        // expected 6/20 and 2/20 of the rolls, with a generous margin
+        assert!(sharp_severing > rolls * 4 / 20 && sharp_severing < rolls * 8 / 20);
+        assert_eq!(blunt_severing, 0);
+        assert!(blunt_knock_out > rolls / 20 && blunt_knock_out < rolls * 3 / 20);
+    }
+
+    #[test]
+    fn table_from_weapon() {
+        assert_eq!(CriticalHitTable::from(&Weapon::new(WeaponKind::Sword)), CriticalHitTable::Sharp);
+        assert_eq!(CriticalHitTable::from(&Weapon::new(WeaponKind::Axe)), CriticalHitTable::Sharp);
+        assert_eq!(CriticalHitTable::from(&Weapon::new(WeaponKind::Hammer)), CriticalHitTable::Blunt);
+        assert_eq!(
+            CriticalHitTable::from(&Weapon::new(WeaponKind::GreatSword)),
+            CriticalHitTable::TwoHandedSharp,
+        );
+        assert_eq!(
+            CriticalHitTable::from(&Weapon::new(WeaponKind::BattleAxe)),
+            CriticalHitTable::TwoHandedSharp,
+        );
+        assert_eq!(
+            CriticalHitTable::from(&Weapon::new(WeaponKind::WarHammer)),
+            CriticalHitTable::TwoHandedBlunt,
+        );
+    }
+}
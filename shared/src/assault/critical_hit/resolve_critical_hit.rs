@@ -3,11 +3,11 @@ use std::u8;
 use rand::Rng;
 
 use crate::assault::assault_consequence::{ArmorDamages, IndividualConsequences};
-use crate::assault::duration_damages::DurationDamages;
 use crate::assault::common_traits::{DealDamages, ResolveGougeRandomEye};
 use crate::equipment::protection::OptionalMutableProtection;
 use crate::equipment::rupture::{Rupture, RuptureTestResult, RUPTURE_MAX};
 use crate::random::Random;
+use crate::status_effect::{StatusEffect, StatusEffectKind};
 use crate::warrior::body::body_part::{BodyPartKind, BodySide, OptionalBodyPart, PROTECTABLE_BODY_PARTS};
 use crate::warrior::body::injury::Injury;
 use crate::warrior::body::HasMutableBody;
@@ -21,8 +21,10 @@ pub trait ResolveCriticalHit:
 {
     fn resolve_critical_hit(&self, damages: u8, critical_hit: &CriticalHit) -> IndividualConsequences {
         match critical_hit {
-            CriticalHit::DeepIncision => self.resolve_raw_damages(damages + 1),
-            CriticalHit::ReallyDeepIncision => self.resolve_raw_damages(damages + 2),
+            CriticalHit::DeepIncision => self.resolve_raw_damages(damages + 1)
+                .with_status_effect(StatusEffect::new(StatusEffectKind::Bleeding)),
+            CriticalHit::ReallyDeepIncision => self.resolve_raw_damages(damages + 2)
+                .with_status_effect(StatusEffect::with_stacks(StatusEffectKind::Bleeding, 2)),
             CriticalHit::ImpressiveWoundAndArmorDamage => self.resolve_damage_random_armor_piece(
                 damages + 3,
                 1,
@@ -39,18 +41,20 @@ pub trait ResolveCriticalHit:
                 damages,
                 u8::MAX,
             ),
-            CriticalHit::GougedEye => self.resolve_gouge_random_eye(damages),
+            CriticalHit::GougedEye => self.resolve_blinding_gouge(damages),
             CriticalHit::SeveredHand => self.resolve_sever_random_hand(damages),
             CriticalHit::SeveredFoot => self.resolve_sever_random_foot(damages),
             CriticalHit::SeveredArm => self.resolve_sever_random_arm(damages),
             CriticalHit::SeveredLeg => self.resolve_sever_random_leg(damages),
             CriticalHit::WoundedGenitals => self.resolve_wound_genitals(damages),
-            CriticalHit::VitalOrganDamage => self.resolve_duration_damage(damages + 9),
+            CriticalHit::VitalOrganDamage => self.resolve_poisoning(damages + 9),
             CriticalHit::HeartInjury => self.resolve_lethal_injury(damages, &BodyPartKind::Torso),
             CriticalHit::SeriousHeadWound => self.resolve_lethal_injury(damages, &BodyPartKind::Head),
-            CriticalHit::ImpressiveBruise => self.resolve_raw_damages(damages + 1),
+            CriticalHit::ImpressiveBruise => self.resolve_raw_damages(damages + 1)
+                .with_status_effect(StatusEffect::new(StatusEffectKind::Stunned)),
             CriticalHit::ImpressiveBruiseAndLimbDislocation => self.resolve_raw_damages(damages + 2),
-            CriticalHit::RibFacture => self.resolve_raw_damages(damages + 2),
+            CriticalHit::RibFacture => self.resolve_raw_damages(damages + 2)
+                .with_status_effect(StatusEffect::new(StatusEffectKind::Exhausted)),
             CriticalHit::KneeDislocation => self.resolve_dislocate_random_knee(damages),
             CriticalHit::BrokenHand => self.resolve_break_random_hand(damages),
             CriticalHit::SmashedFoot => self.resolve_smash_random_foot(damages),
@@ -280,9 +284,9 @@ pub trait ResolveCriticalHit:
     fn resolve_wound_genitals(&self, damages: u8) -> IndividualConsequences {
         match self.body().body_part(&BodyPartKind::Genitals) {
             None => IndividualConsequences::no_consequences(),
-            Some(_) => IndividualConsequences::damage_on_duration(
+            Some(_) => IndividualConsequences::inflict_status_effect(
                 damages + 5,
-                DurationDamages::new(),
+                StatusEffect::new(StatusEffectKind::Bleeding),
             ),
         }
     }
@@ -301,8 +305,18 @@ pub trait ResolveCriticalHit:
     fn resolve_raw_damages(&self, damages: u8) -> IndividualConsequences {
         IndividualConsequences::only_raw_damages(damages)
     }
-    fn resolve_duration_damage(&self, damages: u8) -> IndividualConsequences {
-        IndividualConsequences::damage_on_duration(damages, DurationDamages::new())
+    fn resolve_poisoning(&self, damages: u8) -> IndividualConsequences {
+        IndividualConsequences::inflict_status_effect(
+            damages,
+            StatusEffect::with_stacks(StatusEffectKind::Poisoned, 2),
+        )
+    }
+    fn resolve_blinding_gouge(&self, damages: u8) -> IndividualConsequences {
+        let consequences = self.resolve_gouge_random_eye(damages);
+        match consequences.injury() {
+            None => consequences,
+            Some(_) => consequences.with_status_effect(StatusEffect::new(StatusEffectKind::Blinded)),
+        }
     }
     fn resolve_knock_out(&self, damages: u8) -> IndividualConsequences {
         IndividualConsequences::knock_out(damages)
@@ -5,7 +5,7 @@ use crate::assault::assault_consequence::{AssaultConsequences, IndividualConsequ
 use crate::dice::Dice;
 use crate::random::Random;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub enum CriticalParry {
     RegularParry,
     AssailantRepelled,
@@ -16,6 +16,26 @@ pub enum CriticalParry {
     AssailantHit,
     AssailantCriticalHit,
     AssailantSelfCriticalHit,
+    ShieldBash,
+}
+
+impl CriticalParry {
+    // Used when the parry author holds a shield
+    pub fn random_with_shield() -> Self {
+        match Dice::D20.roll() {
+            1..=3 => CriticalParry::RegularParry,
+            4 | 5 => CriticalParry::AssailantRepelled,
+            6..=8 => CriticalParry::ShieldBash,
+            9 => CriticalParry::AssailantTrips,
+            10 | 11 => CriticalParry::AssailantFalls,
+            12..=13 => CriticalParry::AssailantDropsWeapon,
+            14 | 15 => CriticalParry::AssailantBreaksWeapon,
+            16..=18 => CriticalParry::AssailantHit,
+            19 => CriticalParry::AssailantCriticalHit,
+            20 => CriticalParry::AssailantSelfCriticalHit,
+            other => panic!("D20 roll resulted in {other}"),
+        }
+    }
 }
 
 impl Random for CriticalParry {
@@ -44,3 +64,15 @@ impl AssaultConsequencesBuilder for CriticalParry {
 }
 
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shield_bash_only_happens_with_shield() {
+        let rolls = 1000;
+        assert!((0..rolls).any(|_| CriticalParry::random_with_shield() == CriticalParry::ShieldBash));
+        assert!(!(0..rolls).any(|_| CriticalParry::random() == CriticalParry::ShieldBash));
+    }
+}
@@ -1,9 +1,14 @@
+use crate::equipment::shield::CanHoldShield;
 use crate::random::Random;
 
 use super::CriticalParry;
 
-pub trait DealCriticalParry {
+pub trait DealCriticalParry: CanHoldShield {
     fn deal_critical_parry(&self) -> CriticalParry {
-        CriticalParry::random()
+        if self.usable_shield().is_some() {
+            CriticalParry::random_with_shield()
+        } else {
+            CriticalParry::random()
+        }
     }
 }
@@ -7,6 +7,8 @@ use crate::assault::critical_hit::DealCriticalHit;
 use crate::assault::critical_hit::{ResolveCriticalHit, ResolveCriticalHitSelf};
 use crate::assault::common_traits::DealDamages;
 use crate::assault::common_traits::ReduceDamages;
+use crate::dice::Dice;
+use crate::status_effect::{StatusEffect, StatusEffectKind};
 use crate::temporary_handicap::TemporaryHandicap;
 use crate::temporary_handicap::TemporaryHandicapReason;
 
@@ -42,8 +44,15 @@ pub trait ResolveCriticalParry:
             CriticalParry::AssailantHit => self.resolve_counter_hit(parry_author),
             CriticalParry::AssailantCriticalHit => self.resolve_counter_critical_hit(parry_author),
             CriticalParry::AssailantSelfCriticalHit => self.resolve_critical_hit_self(),
+            CriticalParry::ShieldBash => self.resolve_shield_bash(),
         }
     }
+    fn resolve_shield_bash(&self) -> IndividualConsequences {
+        IndividualConsequences::damages_and_miss_assaults(
+            self.reduce_damages(Dice::D6.roll()),
+            TemporaryHandicap::new(1, TemporaryHandicapReason::LostBalance),
+        ).with_status_effect(StatusEffect::new(StatusEffectKind::Stunned))
+    }
     fn resolve_unstoppable_attack(&self, misses: TemporaryHandicap) -> IndividualConsequences {
         IndividualConsequences::unstoppable_assaults(misses)
     }
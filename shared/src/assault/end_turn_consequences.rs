@@ -1,35 +1,51 @@
 use serde::{Deserialize, Serialize};
 
 use crate::assault::assailant::Assailant;
-use crate::assault::duration_damages::TakeDurationDamages;
+use crate::equipment::weapon::OptionalMutableWeapon;
+use crate::stamina::{turn_cost, HasMutableStamina};
+use crate::status_effect::{HasMutableStatusEffects, StatusEffectKind};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct EndTurnConsequences {
     duration_damages: u8,
+    #[serde(default)]
+    expired_effects: Vec<StatusEffectKind>,
+    #[serde(default)]
+    stamina_spent: u8,
 }
 
-// client only
 impl EndTurnConsequences {
     pub fn duration_damages(&self) -> u8 {
         self.duration_damages
     }
 
+    pub fn expired_effects(&self) -> &Vec<StatusEffectKind> {
+        &self.expired_effects
+    }
+
+    pub fn stamina_spent(&self) -> u8 {
+        self.stamina_spent
+    }
+
+    // Both the server and the client replay tick the effects the same way
     pub fn apply(&self, victim: &mut dyn Assailant) {
         victim.take_damage(self.duration_damages);
+        victim.status_effects_mut().tick();
+        victim.stamina_mut().spend(self.stamina_spent);
     }
 }
 
 // server only
 pub trait EndTurnConsequencesBuilder:
-    TakeDurationDamages
+    HasMutableStatusEffects +
+    HasMutableStamina +
+    OptionalMutableWeapon
 {
     fn end_turn(&mut self) -> EndTurnConsequences {
-        let mut duration_damages = 0;
-        if let Some(damages) = self.take_duration_damages() {
-            duration_damages = damages;
-        }
         EndTurnConsequences {
-            duration_damages,
+            duration_damages: self.status_effects().tick_damages(),
+            expired_effects: self.status_effects().expiring(),
+            stamina_spent: turn_cost(self.weapon()),
         }
     }
 }
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use crate::equipment::rupture::{Rupture, RuptureTestResult};
+use crate::equipment::shield::CanHoldShield;
+
 use super::assailant::Assailant;
 use super::assault_consequence::{AssaultConsequences, AssaultConsequencesBuilder, IndividualConsequences};
 
@@ -12,17 +15,23 @@ impl ParrySuccess {
     }
 }
 
-pub trait ResolveParrySuccess {
-    fn resolve_parry(&self) -> IndividualConsequences {
-        IndividualConsequences::no_consequences()
+pub trait ResolveParrySuccess: CanHoldShield {
+    fn resolve_parry(&self, blocked_damages: u8) -> IndividualConsequences {
+        match self.usable_shield() {
+            Some(shield) if blocked_damages > shield.absorption() => match shield.rupture_test() {
+                RuptureTestResult::Fail => IndividualConsequences::damage_shield(1),
+                RuptureTestResult::Success => IndividualConsequences::no_consequences(),
+            },
+            _ => IndividualConsequences::no_consequences(),
+        }
     }
 }
 
 impl AssaultConsequencesBuilder for ParrySuccess {
-    fn to_consequences(&self, _: & dyn Assailant, _: & dyn Assailant) -> AssaultConsequences {
+    fn to_consequences(&self, assailant: & dyn Assailant, victim: & dyn Assailant) -> AssaultConsequences {
         AssaultConsequences::new(
             IndividualConsequences::no_consequences(),
-            IndividualConsequences::no_consequences(),
+            victim.resolve_parry(assailant.deal_damages()),
         )
     }
 }
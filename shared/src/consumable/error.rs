@@ -0,0 +1,46 @@
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConsumableErrorKind {
+    InTournament,
+    Dead,
+    FullHealth,
+    NotBleeding,
+    AlreadyUnderTonic,
+    NotPoisoned,
+    AlreadyEnraged,
+}
+
+#[derive(Debug)]
+pub struct ConsumableError {
+    kind: ConsumableErrorKind,
+    message: String,
+}
+
+impl ConsumableError {
+    pub fn new(kind: ConsumableErrorKind) -> Self {
+        let message = match kind {
+            ConsumableErrorKind::InTournament => "Warrior is registered in a tournament".to_string(),
+            ConsumableErrorKind::Dead => "Warrior is dead".to_string(),
+            ConsumableErrorKind::FullHealth => "Warrior is not hurt".to_string(),
+            ConsumableErrorKind::NotBleeding => "Warrior is not bleeding".to_string(),
+            ConsumableErrorKind::AlreadyUnderTonic => "Warrior already drank a tonic".to_string(),
+            ConsumableErrorKind::NotPoisoned => "Warrior is not poisoned".to_string(),
+            ConsumableErrorKind::AlreadyEnraged => "Warrior is already enraged".to_string(),
+        };
+        Self { kind, message }
+    }
+
+    pub fn kind(&self) -> &ConsumableErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ConsumableError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ConsumableError {}
@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+
+use crate::health::{IsDead, MutableHealth};
+use crate::stats::{StatKind, StatModifier};
+use crate::status_effect::{HasMutableStatusEffects, StatusEffect, StatusEffectKind};
+use crate::tournament::contestant::TournamentContestant;
+
+use super::{ConsumableError, ConsumableErrorKind};
+
+pub const HEALING_POTION_HP: u8 = 10;
+pub const TONIC_BONUS: i8 = 2;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum Consumable {
+    HealingPotion,
+    // Stops one bleeding wound
+    Bandage,
+    Tonic(StatKind),
+    Antidote,
+    // Enrages the warrior for the first turns of the next fight
+    RageDraught,
+}
+
+// Stat boost lasting until the end of the next tournament
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Tonic {
+    stat: StatKind,
+    bonus: i8,
+}
+
+impl Tonic {
+    pub fn new(stat: StatKind) -> Self {
+        Self { stat, bonus: TONIC_BONUS }
+    }
+
+    pub fn stat(&self) -> &StatKind {
+        &self.stat
+    }
+
+    pub fn bonus(&self) -> i8 {
+        self.bonus
+    }
+}
+
+impl StatModifier for Tonic {
+    fn value(&self, stat: &StatKind) -> i8 {
+        if &self.stat == stat { self.bonus } else { 0 }
+    }
+}
+
+pub trait HasTonic {
+    fn tonic(&self) -> &Option<Tonic>;
+}
+
+pub trait HasMutableTonic: HasTonic {
+    fn tonic_mut(&mut self) -> &mut Option<Tonic>;
+}
+
+pub trait UseConsumable: MutableHealth + IsDead + HasMutableStatusEffects + HasMutableTonic + TournamentContestant {
+    fn check_consumable(&self, consumable: &Consumable) -> Result<(), ConsumableError> {
+        if self.current_tournament().is_some() {
+            return Err(ConsumableError::new(ConsumableErrorKind::InTournament));
+        }
+        if self.is_dead() {
+            return Err(ConsumableError::new(ConsumableErrorKind::Dead));
+        }
+        match consumable {
+            Consumable::HealingPotion => if self.health().current() >= self.health().max() {
+                return Err(ConsumableError::new(ConsumableErrorKind::FullHealth));
+            },
+            Consumable::Bandage => if !self.status_effects().has(&StatusEffectKind::Bleeding) {
+                return Err(ConsumableError::new(ConsumableErrorKind::NotBleeding));
+            },
+            Consumable::Tonic(_) => if self.tonic().is_some() {
+                return Err(ConsumableError::new(ConsumableErrorKind::AlreadyUnderTonic));
+            },
+            Consumable::Antidote => if !self.status_effects().has(&StatusEffectKind::Poisoned) {
+                return Err(ConsumableError::new(ConsumableErrorKind::NotPoisoned));
+            },
+            Consumable::RageDraught => if self.status_effects().has(&StatusEffectKind::Enraged) {
+                return Err(ConsumableError::new(ConsumableErrorKind::AlreadyEnraged));
+            },
+        }
+        Ok(())
+    }
+
+    // server only
+    fn use_consumable(&mut self, consumable: &Consumable) -> Result<(), ConsumableError> {
+        self.check_consumable(consumable)?;
+        match consumable {
+            Consumable::HealingPotion => {
+                let current = self.health().current();
+                self.health_mut().set(current.saturating_add(HEALING_POTION_HP));
+            },
+            Consumable::Bandage => self.status_effects_mut().relieve(&StatusEffectKind::Bleeding),
+            Consumable::Tonic(stat) => { *self.tonic_mut() = Some(Tonic::new(stat.clone())); },
+            Consumable::Antidote => self.status_effects_mut().remove(&StatusEffectKind::Poisoned),
+            Consumable::RageDraught => self.status_effects_mut().add(StatusEffect::new(StatusEffectKind::Enraged)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::Random;
+    use crate::status_effect::HasStatusEffects;
+    use crate::warrior::Warrior;
+
+    use super::*;
+
+    #[test]
+    fn healing_potion_restores_health_up_to_max() {
+        let mut warrior = Warrior::random();
+        assert_eq!(
+            warrior.use_consumable(&Consumable::HealingPotion).unwrap_err().kind(),
+            &ConsumableErrorKind::FullHealth,
+        );
+        let max = warrior.health().max();
+        warrior.health_mut().set(max - 3);
+        warrior.use_consumable(&Consumable::HealingPotion).unwrap();
+        assert_eq!(warrior.health().current(), max);
+    }
+
+    #[test]
+    fn bandage_stops_one_bleeding() {
+        let mut warrior = Warrior::random();
+        assert_eq!(
+            warrior.use_consumable(&Consumable::Bandage).unwrap_err().kind(),
+            &ConsumableErrorKind::NotBleeding,
+        );
+        warrior.status_effects_mut().add(StatusEffect::with_stacks(StatusEffectKind::Bleeding, 2));
+        warrior.use_consumable(&Consumable::Bandage).unwrap();
+        assert_eq!(warrior.status_effects().list()[0].stacks(), 1);
+    }
+
+    #[test]
+    fn only_one_tonic_at_a_time() {
+        let mut warrior = Warrior::random();
+        warrior.use_consumable(&Consumable::Tonic(StatKind::Strength)).unwrap();
+        assert_eq!(warrior.tonic().as_ref().unwrap().value(&StatKind::Strength), TONIC_BONUS);
+        assert_eq!(warrior.tonic().as_ref().unwrap().value(&StatKind::Attack), 0);
+        assert_eq!(
+            warrior.use_consumable(&Consumable::Tonic(StatKind::Attack)).unwrap_err().kind(),
+            &ConsumableErrorKind::AlreadyUnderTonic,
+        );
+    }
+
+    #[test]
+    fn antidote_cures_poison_and_draught_enrages() {
+        let mut warrior = Warrior::random();
+        assert_eq!(
+            warrior.use_consumable(&Consumable::Antidote).unwrap_err().kind(),
+            &ConsumableErrorKind::NotPoisoned,
+        );
+        warrior.status_effects_mut().add(StatusEffect::with_stacks(StatusEffectKind::Poisoned, 3));
+        warrior.use_consumable(&Consumable::Antidote).unwrap();
+        assert!(warrior.status_effects().list().is_empty());
+        warrior.use_consumable(&Consumable::RageDraught).unwrap();
+        assert_eq!(
+            warrior.use_consumable(&Consumable::RageDraught).unwrap_err().kind(),
+            &ConsumableErrorKind::AlreadyEnraged,
+        );
+    }
+
+    #[test]
+    fn cannot_be_used_during_a_tournament() {
+        let mut warrior = Warrior::random();
+        warrior.status_effects_mut().add(StatusEffect::new(StatusEffectKind::Bleeding));
+        warrior.set_current_tournament(Some(uuid::Uuid::new_v4()));
+        assert_eq!(
+            warrior.use_consumable(&Consumable::Bandage).unwrap_err().kind(),
+            &ConsumableErrorKind::InTournament,
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CraftingErrorKind {
+    ItemNotFound,
+    NotSalvageable,
+    RecipeNotFound,
+    MissingMaterials,
+    NotEnoughGold,
+    InventoryFull,
+}
+
+#[derive(Debug)]
+pub struct CraftingError {
+    kind: CraftingErrorKind,
+    message: String,
+}
+
+impl CraftingError {
+    pub fn new(kind: CraftingErrorKind) -> Self {
+        let message = match kind {
+            CraftingErrorKind::ItemNotFound => "Item not found".to_string(),
+            CraftingErrorKind::NotSalvageable => "Item yields no material".to_string(),
+            CraftingErrorKind::RecipeNotFound => "Recipe not found".to_string(),
+            CraftingErrorKind::MissingMaterials => "Not enough materials".to_string(),
+            CraftingErrorKind::NotEnoughGold => "Not enough gold".to_string(),
+            CraftingErrorKind::InventoryFull => "Inventory is full".to_string(),
+        };
+        Self { kind, message }
+    }
+
+    pub fn kind(&self) -> &CraftingErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for CraftingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for CraftingError {}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
+pub enum Material {
+    Metal,
+    Leather,
+    Cloth,
+    Wood,
+}
+
+pub const MATERIALS: [&Material; 4] = [
+    &Material::Metal,
+    &Material::Leather,
+    &Material::Cloth,
+    &Material::Wood,
+];
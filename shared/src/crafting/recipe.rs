@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::equipment::protection::{Protection, ProtectionKind};
+use crate::equipment::weapon::{Weapon, WeaponKind};
+use crate::inventory::{Inventory, Item, MutableItems};
+use crate::loot::{Affix, Lootable, Rarity};
+
+use super::{CraftingError, CraftingErrorKind, Material};
+
+const RECIPES: &str = include_str!("recipes.json");
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum CraftedKind {
+    Weapon(WeaponKind),
+    Protection(ProtectionKind),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Recipe {
+    name: String,
+    result: CraftedKind,
+    materials: Vec<(Material, u32)>,
+    gold: u32,
+    // Stats of the crafted item on top of its template
+    affixes: Vec<Affix>,
+}
+
+impl Recipe {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn result(&self) -> &CraftedKind {
+        &self.result
+    }
+
+    pub fn materials(&self) -> &Vec<(Material, u32)> {
+        &self.materials
+    }
+
+    pub fn gold(&self) -> u32 {
+        self.gold
+    }
+
+    pub fn affixes(&self) -> &Vec<Affix> {
+        &self.affixes
+    }
+
+    pub fn can_afford(&self, inventory: &Inventory) -> bool {
+        inventory.gold() >= self.gold && inventory.has_materials(&self.materials)
+    }
+
+    fn finish<T: Lootable>(&self, mut item: T) -> T {
+        for affix in &self.affixes {
+            item.apply_affix(affix.clone());
+        }
+        item.rename(self.name.clone());
+        item.set_rarity(match self.affixes.len() {
+            0 => Rarity::Common,
+            1 => Rarity::Uncommon,
+            2 => Rarity::Rare,
+            _ => Rarity::Epic,
+        });
+        item
+    }
+
+    pub fn item(&self) -> Item {
+        match &self.result {
+            CraftedKind::Weapon(kind) => Item::Weapon(self.finish(Weapon::new(kind.clone()))),
+            CraftedKind::Protection(kind) => Item::Protection(self.finish(Protection::new(kind.clone()))),
+        }
+    }
+}
+
+pub fn recipes() -> Vec<Recipe> {
+    serde_json::from_str(RECIPES).expect("Invalid recipes.json")
+}
+
+// server only
+pub fn craft_item(inventory: &mut Inventory, recipe_name: &str) -> Result<Uuid, CraftingError> {
+    let recipe = match recipes().into_iter().find(|recipe| recipe.name == recipe_name) {
+        None => return Err(CraftingError::new(CraftingErrorKind::RecipeNotFound)),
+        Some(recipe) => recipe,
+    };
+    if inventory.gold() < recipe.gold {
+        return Err(CraftingError::new(CraftingErrorKind::NotEnoughGold));
+    }
+    if !inventory.has_materials(&recipe.materials) {
+        return Err(CraftingError::new(CraftingErrorKind::MissingMaterials));
+    }
+    let slot_uuid = match inventory.add_item(recipe.item()) {
+        Err(_) => return Err(CraftingError::new(CraftingErrorKind::InventoryFull)),
+        Ok(slot_uuid) => slot_uuid,
+    };
+    inventory.remove_gold(recipe.gold);
+    inventory.remove_materials(&recipe.materials);
+    Ok(slot_uuid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recipes_are_valid() {
+        let recipes = recipes();
+        assert!(!recipes.is_empty());
+        for recipe in recipes {
+            let allowed = match recipe.item() {
+                Item::Weapon(weapon) => weapon.allowed_affixes(),
+                Item::Protection(protection) => protection.allowed_affixes(),
+                _ => panic!("{} does not craft equipment", recipe.name()),
+            };
+            for affix in recipe.affixes() {
+                assert!(allowed.contains(affix), "{} has a forbidden affix", recipe.name());
+            }
+        }
+    }
+
+    #[test]
+    fn crafting_consumes_gold_and_materials() {
+        let mut inventory = Inventory::new();
+        assert_eq!(
+            craft_item(&mut inventory, "Nothing").unwrap_err().kind(),
+            &CraftingErrorKind::RecipeNotFound,
+        );
+        inventory.add_gold(40);
+        assert_eq!(
+            craft_item(&mut inventory, "Iron Helm").unwrap_err().kind(),
+            &CraftingErrorKind::NotEnoughGold,
+        );
+        inventory.add_gold(20);
+        inventory.add_materials(&[(Material::Metal, 5)]);
+        let slot = craft_item(&mut inventory, "Iron Helm").unwrap();
+        assert_eq!(inventory.gold(), 0);
+        assert_eq!(inventory.material(&Material::Metal), 1);
+        let helm = inventory.items().get(&slot).unwrap();
+        assert_eq!(helm.repairable().unwrap().name(), "Iron Helm");
+        assert_eq!(helm.repairable().unwrap().rarity(), &Rarity::Uncommon);
+    }
+
+    #[test]
+    fn missing_materials_are_reported() {
+        let mut inventory = Inventory::new();
+        inventory.add_gold(100);
+        inventory.add_materials(&[(Material::Leather, 3)]);
+        assert_eq!(
+            craft_item(&mut inventory, "Scout Boots").unwrap_err().kind(),
+            &CraftingErrorKind::MissingMaterials,
+        );
+        assert_eq!(inventory.gold(), 100);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::equipment::protection::{Protection, ProtectionKind};
+use crate::equipment::shield::{Shield, ShieldKind};
+use crate::equipment::weapon::{Weapon, WeaponKind};
+use crate::inventory::{Inventory, Item, MutableItems};
+use crate::loot::Lootable;
+
+use super::{CraftingError, CraftingErrorKind, Material};
+
+pub trait Salvage {
+    fn salvage(&self) -> Vec<(Material, u32)>;
+}
+
+// Each affix gives back one more unit of the main material
+fn with_affixes<T: Lootable>(item: &T, mut materials: Vec<(Material, u32)>) -> Vec<(Material, u32)> {
+    if let Some((_, amount)) = materials.first_mut() {
+        *amount += item.affixes().len() as u32;
+    }
+    materials
+}
+
+impl Salvage for Weapon {
+    fn salvage(&self) -> Vec<(Material, u32)> {
+        let materials = match self.kind() {
+            WeaponKind::Sword => vec![(Material::Metal, 3), (Material::Leather, 1)],
+            WeaponKind::GreatSword => vec![(Material::Metal, 5), (Material::Leather, 1)],
+            WeaponKind::Axe => vec![(Material::Metal, 2), (Material::Wood, 2)],
+            WeaponKind::BattleAxe => vec![(Material::Metal, 3), (Material::Wood, 3)],
+            WeaponKind::Hammer => vec![(Material::Metal, 2), (Material::Wood, 2)],
+            WeaponKind::WarHammer => vec![(Material::Metal, 4), (Material::Wood, 3)],
+        };
+        with_affixes(self, materials)
+    }
+}
+
+impl Salvage for Protection {
+    fn salvage(&self) -> Vec<(Material, u32)> {
+        let materials = match self.kind() {
+            ProtectionKind::Armlets => vec![(Material::Leather, 2)],
+            ProtectionKind::Boots => vec![(Material::Leather, 3)],
+            ProtectionKind::Breastplate => vec![(Material::Metal, 5), (Material::Leather, 1)],
+            ProtectionKind::ChainMail => vec![(Material::Metal, 4), (Material::Cloth, 1)],
+            ProtectionKind::Gambeson => vec![(Material::Cloth, 5)],
+            ProtectionKind::Gloves => vec![(Material::Leather, 2)],
+            ProtectionKind::Greaves => vec![(Material::Metal, 2), (Material::Leather, 1)],
+            ProtectionKind::Helm => vec![(Material::Metal, 3)],
+        };
+        with_affixes(self, materials)
+    }
+}
+
+impl Salvage for Shield {
+    fn salvage(&self) -> Vec<(Material, u32)> {
+        let materials = match self.kind() {
+            ShieldKind::Buckler => vec![(Material::Wood, 2), (Material::Metal, 1)],
+            ShieldKind::RoundShield => vec![(Material::Wood, 4), (Material::Metal, 1)],
+            ShieldKind::TowerShield => vec![(Material::Wood, 6), (Material::Metal, 2)],
+        };
+        with_affixes(self, materials)
+    }
+}
+
+impl Salvage for Item {
+    fn salvage(&self) -> Vec<(Material, u32)> {
+        match self {
+            Item::Weapon(weapon) => weapon.salvage(),
+            Item::Protection(protection) => protection.salvage(),
+            Item::Shield(shield) => shield.salvage(),
+            Item::Consumable(_) => vec![],
+        }
+    }
+}
+
+// server only
+// The item is destroyed and its materials go to the inventory
+pub fn salvage_item(
+    inventory: &mut Inventory,
+    slot_uuid: &uuid::Uuid,
+) -> Result<Vec<(Material, u32)>, CraftingError> {
+    let materials = match inventory.items().get(slot_uuid) {
+        None => return Err(CraftingError::new(CraftingErrorKind::ItemNotFound)),
+        Some(item) => item.salvage(),
+    };
+    if materials.is_empty() {
+        return Err(CraftingError::new(CraftingErrorKind::NotSalvageable));
+    }
+    inventory.remove_item(slot_uuid);
+    inventory.add_materials(&materials);
+    Ok(materials)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::consumable::Consumable;
+    use crate::loot::{generate_loot, Rarity};
+
+    use super::*;
+
+    #[test]
+    fn affixes_give_more_materials() {
+        let common = Weapon::new(WeaponKind::Sword);
+        let rare = generate_loot(Weapon::new(WeaponKind::Sword), Rarity::Rare);
+        assert_eq!(common.salvage()[0], (Material::Metal, 3));
+        assert_eq!(rare.salvage()[0], (Material::Metal, 5));
+    }
+
+    #[test]
+    fn salvaged_item_becomes_materials() {
+        let mut inventory = Inventory::new();
+        let slot = inventory.add_item(Item::Protection(Protection::new(ProtectionKind::Gambeson))).unwrap();
+        assert_eq!(salvage_item(&mut inventory, &slot).unwrap(), vec![(Material::Cloth, 5)]);
+        assert!(inventory.items().is_empty());
+        assert_eq!(inventory.material(&Material::Cloth), 5);
+        assert_eq!(
+            salvage_item(&mut inventory, &slot).unwrap_err().kind(),
+            &CraftingErrorKind::ItemNotFound,
+        );
+    }
+
+    #[test]
+    fn consumables_cannot_be_salvaged() {
+        let mut inventory = Inventory::new();
+        let slot = inventory.add_item(Item::Consumable(Consumable::Bandage)).unwrap();
+        assert_eq!(
+            salvage_item(&mut inventory, &slot).unwrap_err().kind(),
+            &CraftingErrorKind::NotSalvageable,
+        );
+        assert_eq!(inventory.items().len(), 1);
+    }
+}
@@ -11,10 +11,19 @@ pub enum Dice {
 
 impl Dice {
     pub fn test_roll(&self, success_threshold: u8) -> TestRollResult {
+        self.test_roll_with_critical_failure(success_threshold, 20)
+    }
+
+    // Faces at or above critical_failure_threshold are critical failures
+    pub fn test_roll_with_critical_failure(
+        &self,
+        success_threshold: u8,
+        critical_failure_threshold: u8,
+    ) -> TestRollResult {
         let face = self.roll();
         if face == 1 {
             TestRollResult::CriticalSuccess
-        } else if face == 20 {
+        } else if face >= critical_failure_threshold {
             TestRollResult::CriticalFailure
         } else if face > success_threshold {
             TestRollResult::Failure
@@ -85,4 +94,21 @@ mod tests {
         assert_roll_distribution(Dice::D6);
         assert_roll_distribution(Dice::D20);
     }
+
+    #[test]
+    fn lower_critical_failure_threshold_adds_critical_failures() {
+        let mut critical_failures = 0;
+        let mut i = 0;
+        while i < 1000 {
+            // success threshold 20 makes any non critical result a success
+            match Dice::D20.test_roll_with_critical_failure(20, 16) {
+                TestRollResult::CriticalFailure => critical_failures += 1,
+                TestRollResult::Failure => panic!("No plain failure expected with threshold 20"),
+                _ => {},
+            }
+            i += 1;
+        }
+        // expected 250 out of 1000
+        assert!(critical_failures > 150 && critical_failures < 350);
+    }
 }
@@ -0,0 +1,392 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::inventory::{Inventory, Item, MutableItems};
+use crate::name::Name;
+use crate::warrior::body::body_part::{BodyPartKind, OptionalBodyPart, OptionalMutableBodyPart, PROTECTABLE_BODY_PARTS};
+use crate::warrior::body::{HasBody, HasMutableBody};
+
+use super::protection::{CanWearProtection, OptionalMutableProtection, Protection};
+use super::shield::{CanHoldShield, OptionalMutableShield, Shield};
+use super::weapon::{OptionalMutableWeapon, Weapon};
+
+pub const MAX_LOADOUTS: usize = 5;
+
+// Pieces are remembered by name since a piece gets a new inventory slot whenever it is stored back
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Loadout {
+    name: String,
+    weapon: Option<String>,
+    shield: Option<String>,
+    protections: Vec<(BodyPartKind, String)>,
+}
+
+impl Loadout {
+    pub fn from_equipment<T: OptionalMutableWeapon + OptionalMutableShield + HasBody>(
+        name: String,
+        warrior: &T,
+    ) -> Self {
+        let mut protections = vec![];
+        for body_part_kind in PROTECTABLE_BODY_PARTS {
+            let protection = warrior.body().body_part(&body_part_kind)
+                .as_ref()
+                .and_then(|body_part| body_part.protection().as_ref());
+            if let Some(protection) = protection {
+                protections.push((body_part_kind, String::from(protection.name())));
+            }
+        }
+        Self {
+            name,
+            weapon: warrior.weapon().as_ref().map(|weapon| String::from(weapon.name())),
+            shield: warrior.shield().as_ref().map(|shield| String::from(shield.name())),
+            protections,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn weapon(&self) -> Option<&str> {
+        self.weapon.as_deref()
+    }
+
+    pub fn shield(&self) -> Option<&str> {
+        self.shield.as_deref()
+    }
+
+    pub fn protections(&self) -> &Vec<(BodyPartKind, String)> {
+        &self.protections
+    }
+}
+
+// Inventory slots to equip in a single swap
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct EquipRequest {
+    pub weapon: Option<Uuid>,
+    pub shield: Option<Uuid>,
+    pub protections: Vec<(BodyPartKind, Uuid)>,
+}
+
+impl EquipRequest {
+    pub fn is_empty(&self) -> bool {
+        self.weapon.is_none() && self.shield.is_none() && self.protections.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EquipErrorKind {
+    ItemNotFound,
+    WrongItemKind,
+    SlotUsedTwice,
+    CannotWear(BodyPartKind),
+    CannotHoldShield,
+    InventoryFull,
+    MissingPiece(String),
+    TooManyLoadouts,
+}
+
+#[derive(Debug)]
+pub struct EquipError {
+    kind: EquipErrorKind,
+    message: String,
+}
+
+impl EquipError {
+    fn new(kind: EquipErrorKind) -> Self {
+        let message = match &kind {
+            EquipErrorKind::ItemNotFound => "Item not found in inventory".to_string(),
+            EquipErrorKind::WrongItemKind => "Item does not fit this slot".to_string(),
+            EquipErrorKind::SlotUsedTwice => "Slot used twice".to_string(),
+            EquipErrorKind::CannotWear(kind) => format!("Protection cannot be worn on {kind:?}"),
+            EquipErrorKind::CannotHoldShield => "Shield cannot be held".to_string(),
+            EquipErrorKind::InventoryFull => "No room left for displaced items".to_string(),
+            EquipErrorKind::MissingPiece(name) => format!("{name} is not in the inventory"),
+            EquipErrorKind::TooManyLoadouts => format!("A warrior has at most {MAX_LOADOUTS} loadouts"),
+        };
+        Self { kind, message }
+    }
+
+    pub fn kind(&self) -> &EquipErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for EquipError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for EquipError {}
+
+pub trait HasLoadouts {
+    fn loadouts(&self) -> &Vec<Loadout>;
+}
+
+pub trait HasMutableLoadouts: HasLoadouts {
+    fn loadouts_mut(&mut self) -> &mut Vec<Loadout>;
+
+    // A loadout with the same name is overwritten
+    fn save_loadout(&mut self, loadout: Loadout) -> Result<(), EquipError> {
+        let loadouts = self.loadouts_mut();
+        match loadouts.iter().position(|saved| saved.name == loadout.name) {
+            Some(index) => loadouts[index] = loadout,
+            None if loadouts.len() >= MAX_LOADOUTS => {
+                return Err(EquipError::new(EquipErrorKind::TooManyLoadouts));
+            },
+            None => loadouts.push(loadout),
+        }
+        Ok(())
+    }
+}
+
+pub trait BulkEquip: CanHoldShield + HasMutableBody + Clone {
+    // Nothing changes unless every piece can be equipped, displaced items are returned
+    fn equip_from_inventory(
+        &mut self,
+        inventory: &mut Inventory,
+        request: &EquipRequest,
+    ) -> Result<Vec<Item>, EquipError> {
+        let mut warrior = self.clone();
+        let mut new_inventory = inventory.clone();
+        let mut displaced = vec![];
+
+        if let Some(slot) = &request.weapon {
+            let weapon = Weapon::try_from(take_item(&mut new_inventory, slot)?)
+                .map_err(|_| EquipError::new(EquipErrorKind::WrongItemKind))?;
+            if let Some(weapon) = warrior.replace_weapon(weapon) {
+                displaced.push(Item::Weapon(weapon));
+            }
+        }
+
+        for (index, (body_part_kind, slot)) in request.protections.iter().enumerate() {
+            if request.protections[..index].iter().any(|(kind, _)| kind == body_part_kind) {
+                return Err(EquipError::new(EquipErrorKind::SlotUsedTwice));
+            }
+            let protection = Protection::try_from(take_item(&mut new_inventory, slot)?)
+                .map_err(|_| EquipError::new(EquipErrorKind::WrongItemKind))?;
+            if !warrior.can_wear_protection(&protection) {
+                return Err(EquipError::new(EquipErrorKind::CannotWear(body_part_kind.clone())));
+            }
+            let body_part = warrior.body_mut().body_part_mut(body_part_kind);
+            match body_part {
+                Some(body_part) if body_part.can_wear_protection(&protection) => {
+                    if let Some(protection) = body_part.protection_mut().replace(protection) {
+                        displaced.push(Item::Protection(protection));
+                    }
+                },
+                _ => return Err(EquipError::new(EquipErrorKind::CannotWear(body_part_kind.clone()))),
+            }
+        }
+
+        if let Some(slot) = &request.shield {
+            let shield = Shield::try_from(take_item(&mut new_inventory, slot)?)
+                .map_err(|_| EquipError::new(EquipErrorKind::WrongItemKind))?;
+            if !warrior.can_hold_shield() {
+                return Err(EquipError::new(EquipErrorKind::CannotHoldShield));
+            }
+            if let Some(shield) = warrior.shield_mut().replace(shield) {
+                displaced.push(Item::Shield(shield));
+            }
+        } else if !warrior.can_hold_shield() {
+            if let Some(shield) = warrior.shield_mut().take() {
+                displaced.push(Item::Shield(shield));
+            }
+        }
+
+        for item in &displaced {
+            if new_inventory.add_item(item.clone()).is_err() {
+                return Err(EquipError::new(EquipErrorKind::InventoryFull));
+            }
+        }
+        *self = warrior;
+        *inventory = new_inventory;
+        Ok(displaced)
+    }
+
+    // Pieces already equipped are left in place
+    fn resolve_loadout(&self, loadout: &Loadout, inventory: &Inventory) -> Result<EquipRequest, EquipError> {
+        let mut picked: Vec<Uuid> = vec![];
+        let mut request = EquipRequest::default();
+
+        if let Some(name) = loadout.weapon() {
+            if self.weapon().as_ref().is_none_or(|weapon| weapon.name() != name) {
+                let slot = find_item(inventory, &picked, name, |item| matches!(item, Item::Weapon(_)))?;
+                picked.push(slot);
+                request.weapon = Some(slot);
+            }
+        }
+
+        for (body_part_kind, name) in loadout.protections() {
+            let equipped = self.body().body_part(body_part_kind)
+                .as_ref()
+                .and_then(|body_part| body_part.protection().as_ref());
+            if equipped.is_none_or(|protection| protection.name() != name) {
+                let slot = find_item(inventory, &picked, name, |item| matches!(item, Item::Protection(_)))?;
+                picked.push(slot);
+                request.protections.push((body_part_kind.clone(), slot));
+            }
+        }
+
+        if let Some(name) = loadout.shield() {
+            if self.shield().as_ref().is_none_or(|shield| shield.name() != name) {
+                let slot = find_item(inventory, &picked, name, |item| matches!(item, Item::Shield(_)))?;
+                request.shield = Some(slot);
+            }
+        }
+
+        Ok(request)
+    }
+}
+
+impl<T: CanHoldShield + HasMutableBody + Clone> BulkEquip for T {}
+
+fn take_item(inventory: &mut Inventory, slot: &Uuid) -> Result<Item, EquipError> {
+    match inventory.remove_item(slot) {
+        Some(item) => Ok(item),
+        None => Err(EquipError::new(EquipErrorKind::ItemNotFound)),
+    }
+}
+
+fn find_item<F: Fn(&Item) -> bool>(
+    inventory: &Inventory,
+    picked: &[Uuid],
+    name: &str,
+    is_kind: F,
+) -> Result<Uuid, EquipError> {
+    inventory.items()
+        .iter()
+        .find(|(slot, item)| {
+            !picked.contains(slot) && is_kind(item) && item.repairable().is_some_and(|item| item.name() == name)
+        })
+        .map(|(slot, _)| *slot)
+        .ok_or_else(|| EquipError::new(EquipErrorKind::MissingPiece(String::from(name))))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::equipment::protection::ProtectionKind;
+    use crate::equipment::shield::ShieldKind;
+    use crate::equipment::weapon::WeaponKind;
+    use crate::random::Random;
+    use crate::warrior::body::body_part::BodySide;
+    use crate::warrior::Warrior;
+
+    use super::*;
+
+    fn slot_of(inventory: &Inventory, name: &str) -> Uuid {
+        *inventory.items()
+            .iter()
+            .find(|(_, item)| item.repairable().is_some_and(|item| item.name() == name))
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn equips_every_piece_and_returns_displaced_items() {
+        let mut warrior = Warrior::random();
+        warrior.replace_weapon(Weapon::new(WeaponKind::Sword));
+        let mut inventory = Inventory::new();
+        inventory.add_item(Item::Weapon(Weapon::new(WeaponKind::Axe))).unwrap();
+        inventory.add_item(Item::Protection(Protection::new(ProtectionKind::Helm))).unwrap();
+        inventory.add_item(Item::Shield(Shield::new(ShieldKind::Buckler))).unwrap();
+        let request = EquipRequest {
+            weapon: Some(slot_of(&inventory, Weapon::new(WeaponKind::Axe).name())),
+            shield: Some(slot_of(&inventory, Shield::new(ShieldKind::Buckler).name())),
+            protections: vec![(
+                BodyPartKind::Head,
+                slot_of(&inventory, Protection::new(ProtectionKind::Helm).name()),
+            )],
+        };
+        let displaced = warrior.equip_from_inventory(&mut inventory, &request).unwrap();
+        assert_eq!(displaced.len(), 1);
+        assert!(matches!(&displaced[0], Item::Weapon(weapon) if weapon.name() == Weapon::new(WeaponKind::Sword).name()));
+        assert_eq!(inventory.items().len(), 1);
+        assert!(warrior.shield().is_some());
+        assert!(warrior.body().body_part(&BodyPartKind::Head).as_ref().unwrap().protection().is_some());
+    }
+
+    #[test]
+    fn nothing_changes_when_a_piece_does_not_fit() {
+        let mut warrior = Warrior::random();
+        warrior.replace_weapon(Weapon::new(WeaponKind::Sword));
+        let mut inventory = Inventory::new();
+        inventory.add_item(Item::Weapon(Weapon::new(WeaponKind::Axe))).unwrap();
+        inventory.add_item(Item::Protection(Protection::new(ProtectionKind::Boots))).unwrap();
+        let request = EquipRequest {
+            weapon: Some(slot_of(&inventory, Weapon::new(WeaponKind::Axe).name())),
+            shield: None,
+            protections: vec![(
+                BodyPartKind::Arm(BodySide::Left),
+                slot_of(&inventory, Protection::new(ProtectionKind::Boots).name()),
+            )],
+        };
+        let error = warrior.equip_from_inventory(&mut inventory, &request).unwrap_err();
+        assert_eq!(error.kind(), &EquipErrorKind::CannotWear(BodyPartKind::Arm(BodySide::Left)));
+        assert_eq!(inventory.items().len(), 2);
+        assert!(warrior.weapon().as_ref().is_some_and(|weapon| weapon.name() == Weapon::new(WeaponKind::Sword).name()));
+    }
+
+    #[test]
+    fn two_handed_weapon_rejects_shield() {
+        let mut warrior = Warrior::random();
+        let mut inventory = Inventory::new();
+        inventory.add_item(Item::Weapon(Weapon::new(WeaponKind::GreatSword))).unwrap();
+        inventory.add_item(Item::Shield(Shield::new(ShieldKind::Buckler))).unwrap();
+        let request = EquipRequest {
+            weapon: Some(slot_of(&inventory, Weapon::new(WeaponKind::GreatSword).name())),
+            shield: Some(slot_of(&inventory, Shield::new(ShieldKind::Buckler).name())),
+            protections: vec![],
+        };
+        assert_eq!(
+            warrior.equip_from_inventory(&mut inventory, &request).unwrap_err().kind(),
+            &EquipErrorKind::CannotHoldShield,
+        );
+    }
+
+    #[test]
+    fn loadout_is_resolved_by_name() {
+        let mut warrior = Warrior::random();
+        warrior.replace_weapon(Weapon::new(WeaponKind::Sword));
+        warrior.body_mut().body_part_mut(&BodyPartKind::Head)
+            .as_mut()
+            .unwrap()
+            .replace_protection(Protection::new(ProtectionKind::Helm));
+        let loadout = Loadout::from_equipment(String::from("Duel"), &warrior);
+        assert_eq!(loadout.protections().len(), 1);
+
+        let mut inventory = Inventory::new();
+        let request = warrior.resolve_loadout(&loadout, &inventory).unwrap();
+        assert!(request.is_empty());
+
+        warrior.weapon_mut().take();
+        assert_eq!(
+            warrior.resolve_loadout(&loadout, &inventory).unwrap_err().kind(),
+            &EquipErrorKind::MissingPiece(String::from(Weapon::new(WeaponKind::Sword).name())),
+        );
+        inventory.add_item(Item::Weapon(Weapon::new(WeaponKind::Sword))).unwrap();
+        let request = warrior.resolve_loadout(&loadout, &inventory).unwrap();
+        assert_eq!(request.weapon, Some(slot_of(&inventory, Weapon::new(WeaponKind::Sword).name())));
+        assert!(request.protections.is_empty());
+    }
+
+    #[test]
+    fn loadouts_are_limited_and_overwritten_by_name() {
+        let mut warrior = Warrior::random();
+        for index in 0..MAX_LOADOUTS {
+            warrior.save_loadout(Loadout::from_equipment(format!("{index}"), &warrior.clone())).unwrap();
+        }
+        warrior.save_loadout(Loadout::from_equipment(String::from("0"), &warrior.clone())).unwrap();
+        assert_eq!(
+            warrior.save_loadout(Loadout::from_equipment(String::from("extra"), &warrior.clone()))
+                .unwrap_err()
+                .kind(),
+            &EquipErrorKind::TooManyLoadouts,
+        );
+        assert_eq!(warrior.loadouts().len(), MAX_LOADOUTS);
+    }
+}
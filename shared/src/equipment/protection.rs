@@ -1,7 +1,12 @@
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 use crate::{assault::common_traits::ReduceDamages, name::Name, stats::{StatKind, StatModifier}};
 
+use crate::loot::{Affix, Lootable, Rarity};
+use crate::random::Random;
+
+use super::repair::Repairable;
 use super::rupture::Rupture;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -16,6 +21,22 @@ pub enum ProtectionKind {
     Helm,
 }
 
+impl Random for ProtectionKind {
+    fn random() -> Self {
+        match rand::thread_rng().gen_range(1..=8) {
+            1 => ProtectionKind::Armlets,
+            2 => ProtectionKind::Boots,
+            3 => ProtectionKind::Breastplate,
+            4 => ProtectionKind::ChainMail,
+            5 => ProtectionKind::Gambeson,
+            6 => ProtectionKind::Gloves,
+            7 => ProtectionKind::Greaves,
+            8 => ProtectionKind::Helm,
+            other => panic!("{other} not in range"),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Protection {
     kind: ProtectionKind,
@@ -24,6 +45,10 @@ pub struct Protection {
     dexterity_mod: i8,
     courage_mod: i8,
     name: String,
+    #[serde(default)]
+    rarity: Rarity,
+    #[serde(default)]
+    affixes: Vec<Affix>,
 }
 
 impl Protection {
@@ -44,6 +69,8 @@ impl Protection {
                 dexterity_mod: -2,
                 courage_mod: 0,
                 name: "Heavy coarse metal armlet".to_string(),
+                rarity: Rarity::Common,
+                affixes: vec![],
             },
             ProtectionKind::Boots => Self {
                 kind,
@@ -52,6 +79,8 @@ impl Protection {
                 dexterity_mod: 0,
                 courage_mod: 0,
                 name: "Shabby leather boots".to_string(),
+                rarity: Rarity::Common,
+                affixes: vec![],
             },
             ProtectionKind::Breastplate => Self {
                 kind,
@@ -60,6 +89,8 @@ impl Protection {
                 dexterity_mod: 0,
                 courage_mod: 0,
                 name: "Basic leather breastplate".to_string(),
+                rarity: Rarity::Common,
+                affixes: vec![],
             },
             ProtectionKind::ChainMail => Self {
                 kind,
@@ -68,6 +99,8 @@ impl Protection {
                 dexterity_mod: -1,
                 courage_mod: 0,
                 name: "Rusty chain mail".to_string(),
+                rarity: Rarity::Common,
+                affixes: vec![],
             },
             ProtectionKind::Gambeson => Self {
                 kind,
@@ -76,6 +109,8 @@ impl Protection {
                 dexterity_mod: 0,
                 courage_mod: 0,
                 name: "Basic gambeson".to_string(),
+                rarity: Rarity::Common,
+                affixes: vec![],
             },
             ProtectionKind::Gloves => Self {
                 kind,
@@ -84,6 +119,8 @@ impl Protection {
                 dexterity_mod: 0,
                 courage_mod: 0,
                 name: "Leather Gloves".to_string(),
+                rarity: Rarity::Common,
+                affixes: vec![],
             },
             ProtectionKind::Greaves => Self {
                 kind,
@@ -92,6 +129,8 @@ impl Protection {
                 dexterity_mod: -2,
                 courage_mod: 0,
                 name: "Heavy coarse greaves".to_string(),
+                rarity: Rarity::Common,
+                affixes: vec![],
             },
             ProtectionKind::Helm => Self {
                 kind,
@@ -100,6 +139,8 @@ impl Protection {
                 dexterity_mod: 0,
                 courage_mod: 0,
                 name: "Shabby leather helmet".to_string(),
+                rarity: Rarity::Common,
+                affixes: vec![],
             }
         }
     }
@@ -149,3 +190,60 @@ impl StatModifier for Protection {
         }
     }
 }
+
+impl Random for Protection {
+    fn random() -> Self {
+        Self::new(ProtectionKind::random())
+    }
+}
+
+impl Lootable for Protection {
+    fn rarity(&self) -> &Rarity {
+        &self.rarity
+    }
+
+    fn set_rarity(&mut self, rarity: Rarity) {
+        self.rarity = rarity;
+    }
+
+    fn affixes(&self) -> &Vec<Affix> {
+        &self.affixes
+    }
+
+    fn allowed_affixes(&self) -> Vec<Affix> {
+        vec![Affix::Padded, Affix::Nimble, Affix::Tempered]
+    }
+
+    fn apply_affix(&mut self, affix: Affix) {
+        match affix {
+            Affix::Padded => self.amount += 1,
+            Affix::Nimble => self.dexterity_mod += 1,
+            Affix::Tempered => self.temper(),
+            Affix::Keen | Affix::Accurate | Affix::Guarding => {},
+        }
+        self.affixes.push(affix);
+    }
+
+    fn base_name(&self) -> &str {
+        match self.kind {
+            ProtectionKind::Armlets => "Armlets",
+            ProtectionKind::Boots => "Boots",
+            ProtectionKind::Breastplate => "Breastplate",
+            ProtectionKind::ChainMail => "Chain Mail",
+            ProtectionKind::Gambeson => "Gambeson",
+            ProtectionKind::Gloves => "Gloves",
+            ProtectionKind::Greaves => "Greaves",
+            ProtectionKind::Helm => "Helm",
+        }
+    }
+
+    fn rename(&mut self, name: String) {
+        self.name = name;
+    }
+}
+
+impl Repairable for Protection {
+    fn template_rupture(&self) -> Option<u8> {
+        *Protection::new(self.kind.clone()).rupture()
+    }
+}
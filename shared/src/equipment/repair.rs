@@ -0,0 +1,198 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::{GoldValue, Inventory};
+use crate::loot::{Affix, Lootable};
+use crate::warrior::body::body_part::BodyPartKind;
+
+// One repair in MASTERWORK_CHANCE ends up sturdier than the item was when new
+const MASTERWORK_CHANCE: u32 = 10;
+const REPAIR_MIN_COST: u32 = 5;
+// Each point of rupture damage costs a fifth of the item value
+const REPAIR_COST_DIVISOR: u32 = 5;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum RepairOutcome {
+    Repaired,
+    Masterwork,
+}
+
+// Equipped gear a warrior can hand to the blacksmith
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum EquipmentSlot {
+    Weapon,
+    Shield,
+    Protection(BodyPartKind),
+}
+
+#[derive(Debug)]
+pub enum RepairErrorKind {
+    NothingToRepair,
+    NotEnoughGold,
+}
+
+#[derive(Debug)]
+pub struct RepairError {
+    kind: RepairErrorKind,
+    message: String,
+}
+
+impl RepairError {
+    fn new(kind: RepairErrorKind) -> Self {
+        let message = match kind {
+            RepairErrorKind::NothingToRepair => "Item is not damaged".to_string(),
+            RepairErrorKind::NotEnoughGold => "Not enough gold to pay the repair".to_string(),
+        };
+        Self { kind, message }
+    }
+
+    pub fn kind(&self) -> &RepairErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for RepairError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for RepairError {}
+
+pub trait Repairable: Lootable + GoldValue {
+    // Rupture of a brand new item of the same kind
+    fn template_rupture(&self) -> Option<u8>;
+
+    fn base_rupture(&self) -> Option<u8> {
+        let tempers = self.affixes()
+            .iter()
+            .filter(|affix| **affix == Affix::Tempered)
+            .count();
+        self.template_rupture().map(|mut rupture| {
+            for _ in 0..tempers {
+                if rupture > 1 {
+                    rupture -= 1;
+                }
+            }
+            rupture
+        })
+    }
+
+    fn damage_level(&self) -> u8 {
+        match (self.rupture(), self.base_rupture()) {
+            (Some(rupture), Some(base)) => rupture.saturating_sub(base),
+            _ => 0,
+        }
+    }
+
+    fn needs_repair(&self) -> bool {
+        self.damage_level() > 0
+    }
+
+    fn repair_cost(&self) -> u32 {
+        if !self.needs_repair() {
+            return 0;
+        }
+        let cost = self.gold_value() * self.damage_level() as u32 / REPAIR_COST_DIVISOR;
+        cost.max(REPAIR_MIN_COST)
+    }
+
+    // A masterwork repair is recorded as an extra Tempered affix
+    fn repair(&mut self) -> RepairOutcome {
+        let base_rupture = self.base_rupture();
+        self.set_rupture(base_rupture);
+        let can_be_tempered = base_rupture.is_some_and(|rupture| rupture > 1);
+        if can_be_tempered && rand::thread_rng().gen_range(0..MASTERWORK_CHANCE) == 0 {
+            self.apply_affix(Affix::Tempered);
+            RepairOutcome::Masterwork
+        } else {
+            RepairOutcome::Repaired
+        }
+    }
+}
+
+// server only
+pub fn repair_for_gold(
+    item: &mut dyn Repairable,
+    inventory: &mut Inventory,
+) -> Result<RepairOutcome, RepairError> {
+    if !item.needs_repair() {
+        return Err(RepairError::new(RepairErrorKind::NothingToRepair));
+    }
+    let cost = item.repair_cost();
+    if inventory.gold() < cost {
+        return Err(RepairError::new(RepairErrorKind::NotEnoughGold));
+    }
+    inventory.remove_gold(cost);
+    Ok(item.repair())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::equipment::protection::{Protection, ProtectionKind};
+    use crate::equipment::rupture::{Rupture, RUPTURE_MAX};
+    use crate::equipment::weapon::{Weapon, WeaponKind};
+
+    use super::*;
+
+    #[test]
+    fn new_items_need_no_repair() {
+        let weapon = Weapon::new(WeaponKind::Sword);
+        assert!(!weapon.needs_repair());
+        assert_eq!(weapon.repair_cost(), 0);
+    }
+
+    #[test]
+    fn repair_cost_grows_with_damages() {
+        let mut weapon = Weapon::new(WeaponKind::Axe);
+        weapon.damage_rupture(1);
+        let light_cost = weapon.repair_cost();
+        weapon.damage_rupture(1);
+        assert_eq!(weapon.damage_level(), 2);
+        assert!(weapon.repair_cost() > light_cost);
+        assert!(light_cost >= REPAIR_MIN_COST);
+    }
+
+    #[test]
+    fn repair_restores_base_rupture() {
+        let mut protection = Protection::new(ProtectionKind::Breastplate);
+        let base_rupture = *protection.rupture();
+        protection.damage_rupture(RUPTURE_MAX);
+        match protection.repair() {
+            RepairOutcome::Repaired => assert_eq!(*protection.rupture(), base_rupture),
+            RepairOutcome::Masterwork => {
+                assert_eq!(*protection.rupture(), base_rupture.map(|rup| rup - 1));
+                assert!(!protection.needs_repair());
+            },
+        }
+    }
+
+    #[test]
+    fn tempered_affix_lowers_base_rupture() {
+        let mut weapon = Weapon::new(WeaponKind::Sword);
+        let template_rupture = weapon.template_rupture().unwrap();
+        weapon.apply_affix(Affix::Tempered);
+        assert_eq!(weapon.base_rupture(), Some(template_rupture - 1));
+        assert!(!weapon.needs_repair());
+    }
+
+    #[test]
+    fn repair_for_gold_charges_the_inventory() {
+        let mut weapon = Weapon::new(WeaponKind::Hammer);
+        let mut inventory = Inventory::new();
+        assert!(repair_for_gold(&mut weapon, &mut inventory).is_err());
+
+        weapon.damage_rupture(1);
+        let cost = weapon.repair_cost();
+        let result = repair_for_gold(&mut weapon, &mut inventory);
+        assert!(matches!(result.unwrap_err().kind(), RepairErrorKind::NotEnoughGold));
+
+        inventory.add_gold(cost + 1);
+        assert!(repair_for_gold(&mut weapon, &mut inventory).is_ok());
+        assert_eq!(inventory.gold(), 1);
+        assert!(!weapon.needs_repair());
+    }
+}
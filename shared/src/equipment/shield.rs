@@ -0,0 +1,216 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::equipment::weapon::OptionalMutableWeapon;
+use crate::loot::{Affix, Lootable, Rarity};
+use crate::name::Name;
+use crate::random::Random;
+use crate::stats::{StatKind, StatModifier};
+use crate::warrior::body::injury::{Injuries, Injury};
+use crate::warrior::body::HasBody;
+
+use super::repair::Repairable;
+use super::rupture::Rupture;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum ShieldKind {
+    Buckler,
+    RoundShield,
+    TowerShield,
+}
+
+impl Random for ShieldKind {
+    fn random() -> Self {
+        match rand::thread_rng().gen_range(1..=3) {
+            1 => ShieldKind::Buckler,
+            2 => ShieldKind::RoundShield,
+            3 => ShieldKind::TowerShield,
+            other => panic!("{other} not in range"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Shield {
+    kind: ShieldKind,
+    // Blocked blows dealing more damages than this strain the shield
+    absorption: u8,
+    rupture: Option<u8>,
+    attack_mod: i8,
+    parry_mod: i8,
+    dexterity_mod: i8,
+    name: String,
+    #[serde(default)]
+    rarity: Rarity,
+    #[serde(default)]
+    affixes: Vec<Affix>,
+}
+
+impl Shield {
+    pub fn kind(&self) -> &ShieldKind {
+        &self.kind
+    }
+
+    pub fn absorption(&self) -> u8 {
+        self.absorption
+    }
+
+    pub fn new(kind: ShieldKind) -> Self {
+        match kind {
+            ShieldKind::Buckler => Self {
+                kind,
+                absorption: 4,
+                rupture: Some(3),
+                attack_mod: 0,
+                parry_mod: 1,
+                dexterity_mod: 0,
+                name: "Dented iron buckler".to_string(),
+                rarity: Rarity::Common,
+                affixes: vec![],
+            },
+            ShieldKind::RoundShield => Self {
+                kind,
+                absorption: 5,
+                rupture: Some(2),
+                attack_mod: 0,
+                parry_mod: 2,
+                dexterity_mod: -1,
+                name: "Wooden round shield".to_string(),
+                rarity: Rarity::Common,
+                affixes: vec![],
+            },
+            ShieldKind::TowerShield => Self {
+                kind,
+                absorption: 6,
+                rupture: Some(2),
+                attack_mod: -1,
+                parry_mod: 3,
+                dexterity_mod: -2,
+                name: "Heavy tower shield".to_string(),
+                rarity: Rarity::Common,
+                affixes: vec![],
+            },
+        }
+    }
+}
+
+pub trait OptionalMutableShield {
+    fn shield(&self) -> &Option<Shield>;
+    fn shield_mut(&mut self) -> &mut Option<Shield>;
+    fn replace_shield(&mut self, shield: Shield) -> Option<Shield> {
+        self.shield_mut().replace(shield)
+    }
+}
+
+// The shield is held in the left hand, the weapon in the right one
+pub trait CanHoldShield: OptionalMutableWeapon + OptionalMutableShield + HasBody {
+    fn can_hold_shield(&self) -> bool {
+        if self.weapon().as_ref().is_some_and(|weapon| weapon.is_two_handed()) {
+            return false;
+        }
+        !self.body().injuries().iter().any(|injury| matches!(
+            injury,
+            Injury::LeftArmSevered
+                | Injury::LeftHandSevered
+                | Injury::LeftArmBroken
+                | Injury::LeftHandBroken
+        ))
+    }
+
+    fn usable_shield(&self) -> Option<&Shield> {
+        if self.can_hold_shield() {
+            self.shield().as_ref()
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: OptionalMutableWeapon + OptionalMutableShield + HasBody> CanHoldShield for T {}
+
+impl Rupture for Shield {
+    fn rupture(&self) -> &Option<u8> {
+        &self.rupture
+    }
+
+    fn set_rupture(&mut self, rup: Option<u8>) {
+        self.rupture = rup;
+    }
+}
+
+impl Name for Shield {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl StatModifier for Shield {
+    fn value(&self, stat: &StatKind) -> i8 {
+        match stat {
+            StatKind::Attack => self.attack_mod,
+            StatKind::Parry => self.parry_mod,
+            StatKind::Dexterity => self.dexterity_mod,
+            _ => 0,
+        }
+    }
+}
+
+impl Random for Shield {
+    fn random() -> Self {
+        Self::new(ShieldKind::random())
+    }
+}
+
+impl Lootable for Shield {
+    fn rarity(&self) -> &Rarity {
+        &self.rarity
+    }
+
+    fn set_rarity(&mut self, rarity: Rarity) {
+        self.rarity = rarity;
+    }
+
+    fn affixes(&self) -> &Vec<Affix> {
+        &self.affixes
+    }
+
+    fn allowed_affixes(&self) -> Vec<Affix> {
+        vec![
+            Affix::Accurate,
+            Affix::Guarding,
+            Affix::Nimble,
+            Affix::Tempered,
+            Affix::Padded,
+        ]
+    }
+
+    fn apply_affix(&mut self, affix: Affix) {
+        match affix {
+            Affix::Accurate => self.attack_mod += 1,
+            Affix::Guarding => self.parry_mod += 1,
+            Affix::Nimble => self.dexterity_mod += 1,
+            Affix::Tempered => self.temper(),
+            Affix::Padded => self.absorption += 1,
+            Affix::Keen => {},
+        }
+        self.affixes.push(affix);
+    }
+
+    fn base_name(&self) -> &str {
+        match self.kind {
+            ShieldKind::Buckler => "Buckler",
+            ShieldKind::RoundShield => "Round Shield",
+            ShieldKind::TowerShield => "Tower Shield",
+        }
+    }
+
+    fn rename(&mut self, name: String) {
+        self.name = name;
+    }
+}
+
+impl Repairable for Shield {
+    fn template_rupture(&self) -> Option<u8> {
+        *Shield::new(self.kind.clone()).rupture()
+    }
+}
@@ -3,13 +3,15 @@ use serde::{Deserialize, Serialize};
 
 use crate::assault::common_traits::DealDamages;
 use crate::dice::Dice;
+use crate::loot::{Affix, Lootable, Rarity};
 use crate::name::Name;
 use crate::random::Random;
 use crate::stats::{StatKind, StatModifier};
 
+use super::repair::Repairable;
 use super::rupture::Rupture;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum WeaponKind {
     Sword,
     GreatSword,
@@ -44,6 +46,12 @@ pub struct Weapon {
     attack_mod: i8,
     parry_mod: i8,
     courage_mod: i8,
+    #[serde(default)]
+    dexterity_mod: i8,
+    #[serde(default)]
+    rarity: Rarity,
+    #[serde(default)]
+    affixes: Vec<Affix>,
 }
 
 impl Weapon {
@@ -59,6 +67,9 @@ impl Weapon {
                 parry_mod: -1,
                 courage_mod: -1,
                 rupture: Some(4),
+                dexterity_mod: 0,
+                rarity: Rarity::Common,
+                affixes: vec![],
             },
             WeaponKind::Axe => Self {
                 name: String::from("Rusty Axe"),
@@ -70,6 +81,9 @@ impl Weapon {
                 parry_mod: -2,
                 courage_mod: 0,
                 rupture: Some(3),
+                dexterity_mod: 0,
+                rarity: Rarity::Common,
+                affixes: vec![],
             },
             WeaponKind::BattleAxe => Self {
                 name: String::from("Coarse Battle Axe"),
@@ -81,6 +95,9 @@ impl Weapon {
                 parry_mod: -4,
                 courage_mod: 0,
                 rupture: Some(3),
+                dexterity_mod: 0,
+                rarity: Rarity::Common,
+                affixes: vec![],
             },
             WeaponKind::GreatSword => Self {
                 name: String::from("Basic Great Sword"),
@@ -92,6 +109,9 @@ impl Weapon {
                 parry_mod: -4,
                 courage_mod: 0,
                 rupture: Some(4),
+                dexterity_mod: 0,
+                rarity: Rarity::Common,
+                affixes: vec![],
             },
             WeaponKind::Hammer => Self {
                 name: String::from("Shitty Hammer"),
@@ -103,6 +123,9 @@ impl Weapon {
                 parry_mod: -2,
                 courage_mod: 0,
                 rupture: Some(4),
+                dexterity_mod: 0,
+                rarity: Rarity::Common,
+                affixes: vec![],
             },
             WeaponKind::WarHammer => Self {
                 name: String::from("Coarse War Hammer"),
@@ -114,10 +137,17 @@ impl Weapon {
                 parry_mod: -4,
                 courage_mod: 0,
                 rupture: Some(4),
+                dexterity_mod: 0,
+                rarity: Rarity::Common,
+                affixes: vec![],
             },
         }
     }
 
+    pub fn kind(&self) -> &WeaponKind {
+        &self.kind
+    }
+
     pub fn is_sharp(&self) -> bool {
         self.is_sharp
     }
@@ -167,7 +197,7 @@ impl StatModifier for Weapon {
             &StatKind::Attack => self.attack_mod,
             &StatKind::Parry => self.parry_mod,
             &StatKind::Courage => self.courage_mod,
-            &StatKind::Dexterity => 0,
+            &StatKind::Dexterity => self.dexterity_mod,
             &StatKind::Strength => 0,
         }
     }
@@ -178,3 +208,60 @@ impl Name for Weapon {
         &self.name
     }
 }
+
+impl Lootable for Weapon {
+    fn rarity(&self) -> &Rarity {
+        &self.rarity
+    }
+
+    fn set_rarity(&mut self, rarity: Rarity) {
+        self.rarity = rarity;
+    }
+
+    fn affixes(&self) -> &Vec<Affix> {
+        &self.affixes
+    }
+
+    fn allowed_affixes(&self) -> Vec<Affix> {
+        vec![
+            Affix::Keen,
+            Affix::Accurate,
+            Affix::Guarding,
+            Affix::Nimble,
+            Affix::Tempered,
+        ]
+    }
+
+    fn apply_affix(&mut self, affix: Affix) {
+        match affix {
+            Affix::Keen => self.add_dmg += 1,
+            Affix::Accurate => self.attack_mod += 1,
+            Affix::Guarding => self.parry_mod += 1,
+            Affix::Nimble => self.dexterity_mod += 1,
+            Affix::Tempered => self.temper(),
+            Affix::Padded => {},
+        }
+        self.affixes.push(affix);
+    }
+
+    fn base_name(&self) -> &str {
+        match self.kind {
+            WeaponKind::Sword => "Sword",
+            WeaponKind::GreatSword => "Great Sword",
+            WeaponKind::Axe => "Axe",
+            WeaponKind::BattleAxe => "Battle Axe",
+            WeaponKind::Hammer => "Hammer",
+            WeaponKind::WarHammer => "War Hammer",
+        }
+    }
+
+    fn rename(&mut self, name: String) {
+        self.name = name;
+    }
+}
+
+impl Repairable for Weapon {
+    fn template_rupture(&self) -> Option<u8> {
+        *Weapon::new(self.kind.clone()).rupture()
+    }
+}
@@ -2,6 +2,9 @@ use std::{error::Error, fmt::Display};
 
 use crate::stats::StatKind;
 
+// Spent freely on any natural stat, one point per stat increment
+pub const ATTRIBUTE_POINTS_PER_LEVEL: u8 = 2;
+
 pub trait Experience {
     fn xp(&self) -> u64;
     fn level(&self) -> u8;
@@ -12,7 +15,9 @@ pub trait GainExperience: Experience {
     fn can_level_up(&self) -> bool {
         self.xp() > level_xp_threshold(self.level() + 1)
     }
-    fn level_up(&mut self, stat: &StatKind) -> Result<(), ExperienceError>;
+    fn level_up(&mut self) -> Result<(), ExperienceError>;
+    fn attribute_points(&self) -> u8;
+    fn spend_attribute_point(&mut self, stat: &StatKind) -> Result<(), ExperienceError>;
 }
 
 fn level_xp_threshold(level_to_reach: u8) -> u64 {
@@ -35,7 +40,8 @@ fn level_xp_threshold(level_to_reach: u8) -> u64 {
 
 #[derive(Debug)]
 pub enum ExperienceErrorKind {
-    InvalidStatIncrement(u8, StatKind),
+    NotEnoughXp(u8),
+    NoAttributePoint,
 }
 
 #[derive(Debug)]
@@ -45,9 +51,12 @@ pub struct ExperienceError {
 
 impl ExperienceError {
     pub fn new(kind: &ExperienceErrorKind) -> Self {
-        match &kind {
-            &ExperienceErrorKind::InvalidStatIncrement(lvl, stat) => Self {
-                message: format!("Invalid Stat Increment ({:?}, {:?})", lvl, stat),
+        match kind {
+            ExperienceErrorKind::NotEnoughXp(lvl) => Self {
+                message: format!("Not enough experience to reach level {:?}", lvl),
+            },
+            ExperienceErrorKind::NoAttributePoint => Self {
+                message: String::from("No attribute point left to spend"),
             },
         }
     }
@@ -63,6 +72,10 @@ impl Error for ExperienceError {}
 
 #[cfg(test)]
 mod tests {
+    use crate::random::Random;
+    use crate::stats::Stats;
+    use crate::warrior::Warrior;
+
     use super::*;
 
     #[test]
@@ -86,4 +99,20 @@ mod tests {
             assert_eq!(expected_xp, level_xp_threshold(level))
         }
     }
+
+    #[test]
+    fn level_up_grants_attribute_points_to_spend_on_any_stat() {
+        let mut warrior = Warrior::random();
+        assert!(warrior.level_up().is_err());
+        warrior.gain_xp(level_xp_threshold(2) + 1);
+        warrior.level_up().unwrap();
+        assert_eq!(warrior.level(), 2);
+        assert_eq!(warrior.attribute_points(), ATTRIBUTE_POINTS_PER_LEVEL);
+        let attack = warrior.stats().nat_stat(&StatKind::Attack).value();
+        for _ in 0..ATTRIBUTE_POINTS_PER_LEVEL {
+            warrior.spend_attribute_point(&StatKind::Attack).unwrap();
+        }
+        assert_eq!(warrior.stats().nat_stat(&StatKind::Attack).value(), attack + ATTRIBUTE_POINTS_PER_LEVEL);
+        assert!(warrior.spend_attribute_point(&StatKind::Courage).is_err());
+    }
 }
@@ -1,30 +1,190 @@
 use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
 
 use super::MutableHealth;
 
-const PASSIVE_HEAL_INTERVAL: u64 = 3600;
-const PASSIVE_HEAL_RATIO: u64 = 2;
+const SECONDS_PER_HOUR: i64 = 3600;
 
-pub trait PassiveHealing {
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub enum Activity {
+    #[default]
+    Resting,
+    Training,
+    Infirmary,
+    // Waiting for a tournament to start
+    Registered,
+}
+
+// Activities a player can pick, registration happens on its own
+pub const ACTIVITIES: [Activity; 3] = [
+    Activity::Resting,
+    Activity::Training,
+    Activity::Infirmary,
+];
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HealingConfig {
+    // Seconds between two passive heals
+    pub interval: u64,
+    // HP healed each interval, depending on the activity
+    pub resting_ratio: u8,
+    pub training_ratio: u8,
+    pub infirmary_ratio: u8,
+    pub registered_ratio: u8,
+}
+
+impl Default for HealingConfig {
+    fn default() -> Self {
+        Self {
+            interval: 3600,
+            resting_ratio: 2,
+            training_ratio: 1,
+            infirmary_ratio: 4,
+            registered_ratio: 1,
+        }
+    }
+}
+
+impl HealingConfig {
+    pub fn ratio(&self, activity: &Activity) -> u8 {
+        match activity {
+            Activity::Resting => self.resting_ratio,
+            Activity::Training => self.training_ratio,
+            Activity::Infirmary => self.infirmary_ratio,
+            Activity::Registered => self.registered_ratio,
+        }
+    }
+}
+
+pub trait PassiveHealing: MutableHealth {
     fn last_passive_heal(&self) -> DateTime<Utc>;
+    fn activity(&self) -> Activity {
+        Activity::Resting
+    }
+    // Extra HP healed each interval, whatever the activity
+    fn healing_bonus(&self) -> u8 {
+        0
+    }
+    fn healing_ratio(&self, config: &HealingConfig) -> u8 {
+        config.ratio(&self.activity()).saturating_add(self.healing_bonus())
+    }
+    // Seconds left before health is full, None if it never will be
+    fn time_to_full_health(&self, config: &HealingConfig) -> Option<u64> {
+        let missing = (self.health().max() - self.health().current()) as u64;
+        if missing == 0 {
+            return Some(0);
+        }
+        let ratio = self.healing_ratio(config) as u64;
+        if ratio == 0 || config.interval == 0 {
+            return None;
+        }
+        let intervals_needed = missing.div_ceil(ratio);
+        let rest_duration = (Utc::now() - self.last_passive_heal()).num_seconds().max(0) as u64;
+        Some((intervals_needed * config.interval).saturating_sub(rest_duration))
+    }
 }
 
-pub trait MutablePassiveHealing:
-    PassiveHealing +
-    MutableHealth
-{
+pub trait MutablePassiveHealing: PassiveHealing {
     fn set_last_passive_heal(&mut self, last_passive_heal: DateTime<Utc>);
-    fn passive_heal(&mut self) {
-        let rest_duration = (Utc::now() - self.last_passive_heal()).num_seconds() as u64;
-        let intervals_passed = rest_duration / PASSIVE_HEAL_INTERVAL;
-        let heal_amount = intervals_passed * PASSIVE_HEAL_RATIO;
-        let current_health = self.health().current();
-        if heal_amount > u8::MAX as u64 {
-            self.health_mut().set(u8::MAX)
-        } else {
-            self.health_mut().set(current_health + heal_amount as u8)
+    fn set_activity(&mut self, activity: Activity);
+    // Broken parts recover by the hour
+    fn recover_injuries(&mut self, _hours: u32) {}
+    // Knocked out warriors come back to their senses after a full interval
+    fn wake_up(&mut self) {}
+    // Tired warriors get their breath back after a full interval
+    fn catch_breath(&mut self) {}
+    fn passive_heal(&mut self, config: &HealingConfig) {
+        if config.interval == 0 {
+            return;
+        }
+        let now = Utc::now();
+        let last_passive_heal = self.last_passive_heal();
+        let rest_duration = (now - last_passive_heal).num_seconds().max(0) as u64;
+        let intervals_passed = rest_duration / config.interval;
+        if intervals_passed == 0 {
+            return;
         }
-        let updated_rest_duration = TimeDelta::seconds((rest_duration % PASSIVE_HEAL_INTERVAL) as i64);
-        self.set_last_passive_heal(Utc::now() - updated_rest_duration)
+        let heal_amount = intervals_passed.saturating_mul(self.healing_ratio(config) as u64);
+        let current_health = self.health().current();
+        self.health_mut().set(current_health.saturating_add(heal_amount.min(u8::MAX as u64) as u8));
+        let updated_rest_duration = TimeDelta::seconds((rest_duration % config.interval) as i64);
+        let next_passive_heal = now - updated_rest_duration;
+        // Counting hour marks rather than intervals carries leftover minutes over to the next heal
+        let hours_passed = next_passive_heal.timestamp().div_euclid(SECONDS_PER_HOUR)
+            - last_passive_heal.timestamp().div_euclid(SECONDS_PER_HOUR);
+        self.recover_injuries(hours_passed.clamp(0, u32::MAX as i64) as u32);
+        self.wake_up();
+        self.catch_breath();
+        self.set_last_passive_heal(next_passive_heal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::health::IsUnconscious;
+    use crate::knock_out::KnockOut;
+    use crate::random::Random;
+    use crate::warrior::body::body_part::{BodyPartKind, BodySide, OptionalBodyPart, OptionalMutableBodyPart};
+    use crate::warrior::body::recovery::recovery_hours;
+    use crate::warrior::body::{HasBody, HasMutableBody};
+    use crate::warrior::Warrior;
+
+    use super::*;
+
+    fn hurt_warrior(hours_of_rest: i64) -> Warrior {
+        let mut warrior = Warrior::random();
+        warrior.health_mut().set(10);
+        warrior.set_last_passive_heal(Utc::now() - TimeDelta::hours(hours_of_rest));
+        warrior
+    }
+
+    #[test]
+    fn activity_changes_healing_rate() {
+        let config = HealingConfig::default();
+        let mut resting = hurt_warrior(2);
+        resting.passive_heal(&config);
+        assert_eq!(resting.health().current(), 10 + 2 * config.resting_ratio);
+
+        let mut in_infirmary = hurt_warrior(2);
+        in_infirmary.set_activity(Activity::Infirmary);
+        in_infirmary.passive_heal(&config);
+        assert_eq!(in_infirmary.health().current(), 10 + 2 * config.infirmary_ratio);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn unconscious_warrior_wakes_up_after_an_interval() {
+        let config = HealingConfig::default();
+        let mut warrior = Warrior::random();
+        warrior.knock_out();
+        warrior.passive_heal(&config);
+        assert!(warrior.is_unconscious());
+        warrior.set_last_passive_heal(Utc::now() - TimeDelta::seconds(config.interval as i64));
+        warrior.passive_heal(&config);
+        assert!(!warrior.is_unconscious());
+    }
+
+    #[test]
+    fn injuries_recover_by_the_hour_whatever_the_interval() {
+        let config = HealingConfig { interval: 7200, ..HealingConfig::default() };
+        let mut warrior = hurt_warrior(4);
+        let leg = BodyPartKind::Leg(BodySide::Left);
+        warrior.body_mut().break_part(&leg);
+        warrior.passive_heal(&config);
+        let recovery = warrior.body().body_part(&leg).as_ref().unwrap().recovery();
+        assert_eq!(recovery, recovery_hours(&leg) - 4);
+    }
+
+    #[test]
+    fn eta_accounts_for_ratio() {
+        let config = HealingConfig::default();
+        let mut warrior = hurt_warrior(0);
+        let missing = (warrior.health().max() - 10) as u64;
+        let intervals = missing.div_ceil(config.resting_ratio as u64);
+        let eta = warrior.time_to_full_health(&config).unwrap();
+        assert!(eta <= intervals * config.interval && eta + 5 > intervals * config.interval);
+        warrior.set_activity(Activity::Infirmary);
+        assert!(warrior.time_to_full_health(&config).unwrap() < eta);
+        let never = HealingConfig { infirmary_ratio: 0, ..config };
+        assert_eq!(warrior.time_to_full_health(&never), None);
+    }
+}
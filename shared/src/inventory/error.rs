@@ -3,33 +3,45 @@ use std::fmt::{Debug, Display};
 
 #[derive(Debug)]
 pub struct InventoryError<T: Debug> {
+    kind: InventoryErrorKind,
     message: String,
     context: T,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum InventoryErrorKind {
     NotAWeapon,
     NotAProtection,
+    NotAShield,
+    NotAConsumable,
     ItemNotFound,
+    InventoryFull,
+    MaxCapacity,
+    NotEnoughGold,
 }
 
 impl<T: Debug> InventoryError<T> {
     pub fn new(kind: &InventoryErrorKind, context: T) -> Self {
-        match kind {
-            InventoryErrorKind::NotAProtection => Self {
-                message: "Not a protection".to_string(),
-                context,
-            },
-            InventoryErrorKind::NotAWeapon => Self {
-                message: "Not a weapon".to_string(),
-                context,
-            },
-            InventoryErrorKind::ItemNotFound => Self {
-                message: "Item not found".to_string(),
-                context,
-            }
-        }
+        let message = match kind {
+            InventoryErrorKind::NotAProtection => "Not a protection",
+            InventoryErrorKind::NotAWeapon => "Not a weapon",
+            InventoryErrorKind::NotAShield => "Not a shield",
+            InventoryErrorKind::NotAConsumable => "Not a consumable",
+            InventoryErrorKind::ItemNotFound => "Item not found",
+            InventoryErrorKind::InventoryFull => "Inventory is full",
+            InventoryErrorKind::MaxCapacity => "Inventory cannot be upgraded anymore",
+            InventoryErrorKind::NotEnoughGold => "Not enough gold",
+        };
+        Self { kind: kind.clone(), message: message.to_string(), context }
+    }
+
+    pub fn kind(&self) -> &InventoryErrorKind {
+        &self.kind
+    }
+
+    // Gives back the rejected value
+    pub fn into_context(self) -> T {
+        self.context
     }
 }
 
@@ -1,8 +1,11 @@
 use crate::{
+    consumable::Consumable,
     equipment::{
-        protection::{Protection, ProtectionKind}, rupture::{Rupture, RUPTURE_MAX}, weapon::Weapon
+        protection::{Protection, ProtectionKind}, rupture::{Rupture, RUPTURE_MAX}, shield::{Shield, ShieldKind}, weapon::Weapon
     },
+    loot::{Lootable, Rarity},
     stats::{StatKind, StatModifier},
+    warrior::body::prosthetic::Prosthetic,
 };
 
 use super::Item;
@@ -33,6 +36,7 @@ impl GoldValue for Protection {
         }
 
         value = modify_gold_value(self, value);
+        value = rarity_gold_value(self.rarity(), value);
 
         value
     }
@@ -52,20 +56,65 @@ impl GoldValue for Weapon {
         }
 
         value = modify_gold_value(self, value);
+        value = rarity_gold_value(self.rarity(), value);
 
         value
     }
 }
 
+impl GoldValue for Shield {
+    fn gold_value(&self) -> u32 {
+        let mut value = match self.kind() {
+            ShieldKind::Buckler => 40,
+            ShieldKind::RoundShield => 80,
+            ShieldKind::TowerShield => 120,
+        };
+        value += equipment_rupture_value(self.rupture());
+
+        value = modify_gold_value(self, value);
+        value = rarity_gold_value(self.rarity(), value);
+
+        value
+    }
+}
+
+impl GoldValue for Consumable {
+    fn gold_value(&self) -> u32 {
+        match self {
+            Self::HealingPotion => 40,
+            Self::Bandage => 25,
+            Self::Tonic(_) => 60,
+            Self::Antidote => 35,
+            Self::RageDraught => 50,
+        }
+    }
+}
+
+impl GoldValue for Prosthetic {
+    fn gold_value(&self) -> u32 {
+        match self {
+            Self::PegLeg => 150,
+            Self::HookHand => 120,
+            Self::GlassEye => 60,
+        }
+    }
+}
+
 impl GoldValue for Item {
     fn gold_value(&self) -> u32 {
         match self {
             Self::Protection(protection) => protection.gold_value(),
             Self::Weapon(weapon) => weapon.gold_value(),
+            Self::Shield(shield) => shield.gold_value(),
+            Self::Consumable(consumable) => consumable.gold_value(),
         }
     }
 }
 
+fn rarity_gold_value(rarity: &Rarity, value: u32) -> u32 {
+    value.saturating_mul(rarity.gold_value_percent()) / 100
+}
+
 fn equipment_rupture_value(rupture: &Option<u8>) -> u32 {
     match rupture {
         None => 500,
@@ -113,6 +162,16 @@ impl StatsValueThresholds for Weapon {
     }
 }
 
+impl StatsValueThresholds for Shield {
+    fn base_value_thresholds(&self) -> (i8, i8) {
+        match self.kind() {
+            ShieldKind::Buckler => (0, 1),
+            ShieldKind::RoundShield => (0, 2),
+            ShieldKind::TowerShield => (-1, 3),
+        }
+    }
+}
+
 impl StatsValueThresholds for Protection {
     fn base_value_thresholds(&self) -> (i8, i8) {
         (0, 0)
@@ -142,6 +201,9 @@ fn modify_gold_value<T: StatModifier + StatsValueThresholds>(item: &T, base: u32
 
 #[cfg(test)]
 mod tests {
+    use crate::equipment::weapon::WeaponKind;
+    use crate::loot::generate_loot;
+
     use super::*;
 
     struct TestStatsModifier {
@@ -176,6 +238,16 @@ mod tests {
         assert_eq!(40, modify_gold_value(&modifier, 0));
     }
 
+    #[test]
+    fn rarity_increases_gold_value() {
+        assert_eq!(100, rarity_gold_value(&Rarity::Common, 100));
+        assert_eq!(150, rarity_gold_value(&Rarity::Rare, 100));
+        assert_eq!(300, rarity_gold_value(&Rarity::Unique, 100));
+        let common = Weapon::new(WeaponKind::Sword);
+        let rare = generate_loot(Weapon::new(WeaponKind::Sword), Rarity::Rare);
+        assert!(rare.gold_value() > common.gold_value());
+    }
+
     #[test]
     fn stat_modifier_value_scales_correctly() {
         let test_values = [
@@ -1,21 +1,68 @@
 use std::{collections::HashMap, u32};
 
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::crafting::Material;
+
+use super::error::{InventoryError, InventoryErrorKind};
 use super::item::{Item, MutableItems};
 
 const INVENTORY_MAX_SLOTS: usize = 32;
+// Slots added by each capacity upgrade
+pub const CAPACITY_UPGRADE_SLOTS: usize = 8;
+pub const MAX_CAPACITY_UPGRADES: u8 = 4;
+// Each upgrade costs this much more than the previous one
+const CAPACITY_UPGRADE_COST_STEP: u32 = 250;
+// Seconds an overflowing item waits in the stash before being lost
+pub const STASH_DURATION: i64 = 7 * 24 * 60 * 60;
+
+#[derive(Debug, PartialEq)]
+pub enum ItemStorage {
+    Inventory(Uuid),
+    Stash,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StashedItem {
+    item: Item,
+    expires_at: i64,
+}
+
+impl StashedItem {
+    pub fn item(&self) -> &Item {
+        &self.item
+    }
+
+    pub fn expires_at(&self) -> i64 {
+        self.expires_at
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Inventory {
     gold: u32,
-    items: HashMap<Uuid, Item>
+    items: HashMap<Uuid, Item>,
+    #[serde(default)]
+    capacity_upgrades: u8,
+    // Items that did not fit in the inventory
+    #[serde(default)]
+    stash: Vec<StashedItem>,
+    // Salvaged materials used for crafting
+    #[serde(default)]
+    materials: HashMap<Material, u32>,
 }
 
 impl Inventory {
     pub fn new() -> Self {
-        Self { gold: 0, items: HashMap::new() }
+        Self {
+            gold: 0,
+            items: HashMap::new(),
+            capacity_upgrades: 0,
+            stash: vec![],
+            materials: HashMap::new(),
+        }
     }
 
     pub fn items(&self) -> &HashMap<Uuid, Item> {
@@ -27,6 +74,110 @@ impl Inventory {
         &mut self.items
     }
 
+    pub fn capacity(&self) -> usize {
+        INVENTORY_MAX_SLOTS + CAPACITY_UPGRADE_SLOTS * self.capacity_upgrades as usize
+    }
+
+    pub fn free_slots(&self) -> usize {
+        self.capacity().saturating_sub(self.items.len())
+    }
+
+    pub fn capacity_upgrades(&self) -> u8 {
+        self.capacity_upgrades
+    }
+
+    pub fn upgrade_cost(&self) -> Option<u32> {
+        if self.capacity_upgrades >= MAX_CAPACITY_UPGRADES {
+            return None;
+        }
+        Some(CAPACITY_UPGRADE_COST_STEP * (self.capacity_upgrades as u32 + 1))
+    }
+
+    // server only
+    pub fn buy_capacity_upgrade(&mut self) -> Result<u32, InventoryError<u8>> {
+        let cost = match self.upgrade_cost() {
+            None => return Err(InventoryError::new(
+                &InventoryErrorKind::MaxCapacity,
+                self.capacity_upgrades,
+            )),
+            Some(cost) => cost,
+        };
+        if self.gold < cost {
+            return Err(InventoryError::new(&InventoryErrorKind::NotEnoughGold, self.capacity_upgrades));
+        }
+        self.remove_gold(cost);
+        self.capacity_upgrades += 1;
+        Ok(cost)
+    }
+
+    pub fn stash(&self) -> &Vec<StashedItem> {
+        &self.stash
+    }
+
+    // server only
+    // Items that do not fit are kept in the stash for a while
+    pub fn store_item(&mut self, item: Item) -> ItemStorage {
+        match self.add_item(item) {
+            Ok(slot_uuid) => ItemStorage::Inventory(slot_uuid),
+            Err(e) => {
+                self.stash.push(StashedItem {
+                    item: e.into_context(),
+                    expires_at: Utc::now().timestamp() + STASH_DURATION,
+                });
+                ItemStorage::Stash
+            },
+        }
+    }
+
+    // server only
+    pub fn claim_stashed(&mut self, index: usize) -> Result<Uuid, InventoryError<usize>> {
+        if index >= self.stash.len() {
+            return Err(InventoryError::new(&InventoryErrorKind::ItemNotFound, index));
+        }
+        if self.free_slots() == 0 {
+            return Err(InventoryError::new(&InventoryErrorKind::InventoryFull, index));
+        }
+        let stashed = self.stash.remove(index);
+        let slot_uuid = Uuid::new_v4();
+        self.items.insert(slot_uuid, stashed.item);
+        Ok(slot_uuid)
+    }
+
+    // server only
+    // Returns the items that stayed too long in the stash
+    pub fn drop_expired_stash(&mut self) -> Vec<Item> {
+        let now = Utc::now().timestamp();
+        let (expired, kept): (Vec<StashedItem>, Vec<StashedItem>) = self.stash
+            .drain(..)
+            .partition(|stashed| stashed.expires_at <= now);
+        self.stash = kept;
+        expired.into_iter().map(|stashed| stashed.item).collect()
+    }
+
+    pub fn material(&self, material: &Material) -> u32 {
+        self.materials.get(material).copied().unwrap_or(0)
+    }
+
+    pub fn has_materials(&self, materials: &[(Material, u32)]) -> bool {
+        materials.iter().all(|(material, amount)| self.material(material) >= *amount)
+    }
+
+    // server only
+    pub fn add_materials(&mut self, materials: &[(Material, u32)]) {
+        for (material, amount) in materials {
+            let current = self.materials.entry(material.clone()).or_insert(0);
+            *current = current.saturating_add(*amount);
+        }
+    }
+
+    // server only
+    pub fn remove_materials(&mut self, materials: &[(Material, u32)]) {
+        for (material, amount) in materials {
+            let current = self.materials.entry(material.clone()).or_insert(0);
+            *current = current.saturating_sub(*amount);
+        }
+    }
+
     pub fn gold(&self) -> u32 {
         self.gold
     }
@@ -35,7 +186,7 @@ impl Inventory {
     fn set_gold(&mut self, gold: u32) {
         self.gold = gold;
     }
-    
+
     // server only
     pub fn add_gold(&mut self, gold: u32) {
         match self.gold.checked_add(gold) {
@@ -53,21 +204,32 @@ impl Inventory {
     }
 
     // server only
-    pub fn join(&mut self, mut inventory: Inventory) {
+    // Returns how many items ended up in the stash
+    pub fn join(&mut self, mut inventory: Inventory) -> usize {
         self.add_gold(inventory.gold());
-        for (_, item) in inventory.items.drain() {
-            self.add_item(item);
+        let materials: Vec<(Material, u32)> = inventory.materials.drain().collect();
+        self.add_materials(&materials);
+        let items = inventory.items.drain()
+            .map(|(_, item)| item)
+            .chain(inventory.stash.drain(..).map(|stashed| stashed.item));
+        let mut stashed = 0;
+        for item in items {
+            if self.store_item(item) == ItemStorage::Stash {
+                stashed += 1;
+            }
         }
+        stashed
     }
 }
 
 impl MutableItems for Inventory {
-    fn add_item(&mut self, item: Item) -> Option<Item> {
-        if self.items.len() < INVENTORY_MAX_SLOTS {
-            self.items.insert(Uuid::new_v4(), item);
-            None
+    fn add_item(&mut self, item: Item) -> Result<Uuid, InventoryError<Item>> {
+        if self.items.len() < self.capacity() {
+            let slot_uuid = Uuid::new_v4();
+            self.items.insert(slot_uuid, item);
+            Ok(slot_uuid)
         } else {
-            Some(item)
+            Err(InventoryError::new(&InventoryErrorKind::InventoryFull, item))
         }
     }
 
@@ -83,3 +245,83 @@ pub trait HasInventory {
 pub trait HasMutableInventory: HasInventory {
     fn inventory_mut(&mut self) -> &mut Inventory;
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::equipment::weapon::Weapon;
+    use crate::random::Random;
+
+    use super::*;
+
+    fn full_inventory() -> Inventory {
+        let mut inventory = Inventory::new();
+        for _ in 0..INVENTORY_MAX_SLOTS {
+            inventory.add_item(Item::Weapon(Weapon::random())).unwrap();
+        }
+        inventory
+    }
+
+    #[test]
+    fn full_inventory_rejects_item_with_error() {
+        let mut inventory = full_inventory();
+        let error = inventory.add_item(Item::Weapon(Weapon::random())).unwrap_err();
+        assert_eq!(error.kind(), &InventoryErrorKind::InventoryFull);
+        assert!(matches!(error.into_context(), Item::Weapon(_)));
+    }
+
+    #[test]
+    fn overflow_goes_to_stash_and_can_be_claimed() {
+        let mut inventory = full_inventory();
+        assert_eq!(inventory.store_item(Item::Weapon(Weapon::random())), ItemStorage::Stash);
+        assert_eq!(inventory.stash().len(), 1);
+        assert_eq!(
+            inventory.claim_stashed(0).unwrap_err().kind(),
+            &InventoryErrorKind::InventoryFull,
+        );
+        let slot = *inventory.items().keys().next().unwrap();
+        inventory.remove_item(&slot);
+        assert!(inventory.claim_stashed(0).is_ok());
+        assert!(inventory.stash().is_empty());
+    }
+
+    #[test]
+    fn join_stashes_what_does_not_fit() {
+        let mut inventory = full_inventory();
+        let mut loot = Inventory::new();
+        loot.add_gold(10);
+        loot.add_item(Item::Weapon(Weapon::random())).unwrap();
+        loot.add_item(Item::Weapon(Weapon::random())).unwrap();
+        assert_eq!(inventory.join(loot), 2);
+        assert_eq!(inventory.gold(), 10);
+        assert_eq!(inventory.stash().len(), 2);
+    }
+
+    #[test]
+    fn expired_stash_is_dropped() {
+        let mut inventory = full_inventory();
+        inventory.store_item(Item::Weapon(Weapon::random()));
+        inventory.store_item(Item::Weapon(Weapon::random()));
+        inventory.stash[0].expires_at = Utc::now().timestamp() - 1;
+        assert_eq!(inventory.drop_expired_stash().len(), 1);
+        assert_eq!(inventory.stash().len(), 1);
+    }
+
+    #[test]
+    fn capacity_upgrades_cost_gold_and_are_capped() {
+        let mut inventory = full_inventory();
+        assert_eq!(
+            inventory.buy_capacity_upgrade().unwrap_err().kind(),
+            &InventoryErrorKind::NotEnoughGold,
+        );
+        inventory.add_gold(10_000);
+        for _ in 0..MAX_CAPACITY_UPGRADES {
+            inventory.buy_capacity_upgrade().unwrap();
+        }
+        assert_eq!(inventory.gold(), 10_000 - 250 - 500 - 750 - 1000);
+        assert_eq!(inventory.free_slots(), CAPACITY_UPGRADE_SLOTS * MAX_CAPACITY_UPGRADES as usize);
+        assert_eq!(
+            inventory.buy_capacity_upgrade().unwrap_err().kind(),
+            &InventoryErrorKind::MaxCapacity,
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::equipment::{protection::Protection, weapon::Weapon};
+use crate::consumable::Consumable;
+use crate::equipment::{protection::Protection, repair::Repairable, shield::Shield, weapon::Weapon};
 
 use super::{error::InventoryErrorKind, InventoryError};
 
@@ -9,6 +10,30 @@ use super::{error::InventoryErrorKind, InventoryError};
 pub enum Item {
     Weapon(Weapon),
     Protection(Protection),
+    Shield(Shield),
+    Consumable(Consumable),
+}
+
+impl Item {
+    // Consumables are used up rather than repaired
+    pub fn repairable(&self) -> Option<&dyn Repairable> {
+        match self {
+            Item::Weapon(weapon) => Some(weapon),
+            Item::Protection(protection) => Some(protection),
+            Item::Shield(shield) => Some(shield),
+            Item::Consumable(_) => None,
+        }
+    }
+
+    // server only
+    pub fn repairable_mut(&mut self) -> Option<&mut dyn Repairable> {
+        match self {
+            Item::Weapon(weapon) => Some(weapon),
+            Item::Protection(protection) => Some(protection),
+            Item::Shield(shield) => Some(shield),
+            Item::Consumable(_) => None,
+        }
+    }
 }
 
 impl TryFrom<Item> for Weapon {
@@ -33,7 +58,29 @@ impl TryFrom<Item> for Protection {
     }
 }
 
+impl TryFrom<Item> for Consumable {
+    type Error = InventoryError<Item>;
+
+    fn try_from(value: Item) -> Result<Self, Self::Error> {
+        match value {
+            Item::Consumable(consumable) => Ok(consumable),
+            _ => Err(InventoryError::new(&InventoryErrorKind::NotAConsumable, value)),
+        }
+    }
+}
+
+impl TryFrom<Item> for Shield {
+    type Error = InventoryError<Item>;
+
+    fn try_from(value: Item) -> Result<Self, Self::Error> {
+        match value {
+            Item::Shield(shield) => Ok(shield),
+            _ => Err(InventoryError::new(&InventoryErrorKind::NotAShield, value)),
+        }
+    }
+}
+
 pub trait MutableItems {
-    fn add_item(&mut self, item: Item) -> Option<Item>;
+    fn add_item(&mut self, item: Item) -> Result<Uuid, InventoryError<Item>>;
     fn remove_item(&mut self, index: &Uuid) -> Option<Item>;
 }
@@ -13,8 +13,10 @@ pub mod assault {
         mod critical_hit;
         mod resolve_critical_hit;
         mod deal_critical_hit;
+        mod critical_hit_table;
 
         pub use critical_hit::CriticalHit;
+        pub use critical_hit_table::CriticalHitTable;
         pub use resolve_critical_hit::ResolveCriticalHit;
         pub use resolve_critical_hit::ResolveCriticalHitSelf;
         pub use deal_critical_hit::DealCriticalHit;
@@ -45,7 +47,6 @@ pub mod assault {
         pub use resolve_critical_parry::ResolveCriticalParry;
         pub use deal_critical_parry::DealCriticalParry;
     }
-    pub mod duration_damages;
     pub mod end_turn_consequences;
 }
 
@@ -53,6 +54,9 @@ pub mod warrior {
     pub mod body {
         pub mod injury;
         pub mod body_part;
+        pub mod hit_location;
+        pub mod recovery;
+        pub mod prosthetic;
         mod body_injuries;
         mod main;
 
@@ -64,12 +68,61 @@ pub mod warrior {
     mod names;
     mod warrior_collection;
     pub use warrior_collection::{WarriorCollection, MutableWarriorCollection};
+    mod tactics;
+    pub use tactics::{Tactics, HasTactics, HasMutableTactics, severed_limbs};
+    mod stance;
+    pub use stance::{Stance, StanceCondition, StanceSwitch, HasStance};
+    mod perk;
+    pub use perk::{
+        Perk,
+        Perks,
+        PerksModifier,
+        PerkError,
+        PerkErrorKind,
+        HasPerks,
+        HasMutablePerks,
+        PERKS,
+        perk_slots,
+        respec_cost,
+        respec_for_gold,
+    };
+    mod proficiency;
+    pub use proficiency::{
+        Proficiency,
+        Proficiencies,
+        ProficiencyRank,
+        HasProficiencies,
+        HasMutableProficiencies,
+    };
+    mod training;
+    pub use training::{
+        Training,
+        TrainingProgram,
+        TrainingError,
+        TrainingErrorKind,
+        HasTraining,
+        Trainee,
+        stat_practice_limit,
+        SPARRING_DURATIONS,
+    };
+    mod personality;
+    pub use personality::{
+        Background,
+        Personality,
+        Trait,
+        HasPersonality,
+        BACKGROUNDS,
+        TRAITS,
+    };
 }
 
 pub mod equipment {
     pub mod rupture;
     pub mod protection;
     pub mod weapon;
+    pub mod shield;
+    pub mod repair;
+    pub mod loadout;
 }
 
 pub mod random;
@@ -85,7 +138,13 @@ pub mod health {
         IsUnconscious,
     };
     mod passive_healing;
-    pub use passive_healing::{PassiveHealing, MutablePassiveHealing};
+    pub use passive_healing::{
+        Activity,
+        HealingConfig,
+        PassiveHealing,
+        MutablePassiveHealing,
+        ACTIVITIES,
+    };
 }
 
 pub mod stats;
@@ -106,6 +165,18 @@ pub mod temporary_handicap {
     pub use main::*;
 }
 
+pub mod status_effect {
+    mod main;
+
+    pub use main::*;
+}
+
+pub mod stamina {
+    mod main;
+
+    pub use main::*;
+}
+
 pub mod dice;
 pub mod knock_out;
 
@@ -113,6 +184,10 @@ pub mod replay {
     pub mod turn_summary;
     mod fight_replay;
     pub use fight_replay::{FightReplay, FightReplaySummary};
+    mod fight_end_reason;
+    pub use fight_end_reason::FightEndReason;
+    mod xp_award;
+    pub use xp_award::{FightFeats, TournamentPlacement, XpAward, XpSource};
 }
 
 pub mod player {
@@ -133,7 +208,16 @@ pub mod experience;
 
 pub mod inventory {
     mod inventory;
-    pub use inventory::{Inventory, HasInventory, HasMutableInventory};
+    pub use inventory::{
+        Inventory,
+        HasInventory,
+        HasMutableInventory,
+        ItemStorage,
+        StashedItem,
+        CAPACITY_UPGRADE_SLOTS,
+        MAX_CAPACITY_UPGRADES,
+        STASH_DURATION,
+    };
     mod error;
     pub use error::{InventoryError, InventoryErrorKind};
     mod item;
@@ -142,7 +226,69 @@ pub mod inventory {
     pub use gold_value::GoldValue;
 }
 
+pub mod loot {
+    mod rarity;
+    pub use rarity::Rarity;
+    mod affix;
+    pub use affix::{Affix, Lootable};
+    mod generator;
+    pub use generator::{generate_loot, generated_name, random_loot};
+    mod uniques;
+    pub use uniques::random_unique;
+}
+
 pub mod shop {
     mod shop;
-    pub use shop::Shop;
+    pub use shop::{Shop, ShopStock, RESTOCK_INTERVAL};
+}
+
+pub mod consumable {
+    mod error;
+    pub use error::{ConsumableError, ConsumableErrorKind};
+    mod main;
+    pub use main::{
+        Consumable,
+        HasMutableTonic,
+        HasTonic,
+        Tonic,
+        UseConsumable,
+        HEALING_POTION_HP,
+        TONIC_BONUS,
+    };
+}
+
+pub mod crafting {
+    mod error;
+    pub use error::{CraftingError, CraftingErrorKind};
+    mod material;
+    pub use material::{Material, MATERIALS};
+    mod salvage;
+    pub use salvage::{Salvage, salvage_item};
+    mod recipe;
+    pub use recipe::{CraftedKind, Recipe, craft_item, recipes};
+}
+
+pub mod marketplace {
+    mod error;
+    pub use error::{MarketplaceError, MarketplaceErrorKind};
+    mod listing;
+    pub use listing::{
+        Bid,
+        Listing,
+        ListingKind,
+        NewListing,
+        check_price,
+        transaction_fee,
+        AUCTION_DURATION,
+        LISTING_DURATION,
+        MAX_LISTING_PRICE,
+        TRANSACTION_FEE_PERCENT,
+    };
+}
+
+pub mod trade {
+    mod error;
+    pub use error::{TradeError, TradeErrorKind};
+    mod offer;
+    pub use offer::{NewTradeOffer, TradeOffer, TradeStatus};
 }
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use crate::equipment::rupture::Rupture;
+use crate::name::Name;
+
+use super::Rarity;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum Affix {
+    // +1 damages
+    Keen,
+    // +1 AT
+    Accurate,
+    // +1 PRD
+    Guarding,
+    // +1 DEX
+    Nimble,
+    // -1 rupture
+    Tempered,
+    // +1 protection or shield absorption
+    Padded,
+}
+
+impl Affix {
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Affix::Keen => "Keen",
+            Affix::Accurate => "Precise",
+            Affix::Guarding => "Guarding",
+            Affix::Nimble => "Nimble",
+            Affix::Tempered => "Tempered",
+            Affix::Padded => "Padded",
+        }
+    }
+
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Affix::Keen => "of Slaughter",
+            Affix::Accurate => "of Precision",
+            Affix::Guarding => "of the Wall",
+            Affix::Nimble => "of the Fox",
+            Affix::Tempered => "of Endurance",
+            Affix::Padded => "of the Turtle",
+        }
+    }
+}
+
+pub trait Lootable: Name + Rupture {
+    fn rarity(&self) -> &Rarity;
+    fn set_rarity(&mut self, rarity: Rarity);
+    fn affixes(&self) -> &Vec<Affix>;
+    fn allowed_affixes(&self) -> Vec<Affix>;
+    // Modifies the item stats and records the affix
+    fn apply_affix(&mut self, affix: Affix);
+    // Name of the item kind, used to generate names
+    fn base_name(&self) -> &str;
+    fn rename(&mut self, name: String);
+
+    // Rupture never goes below 1, unbreakable stays unbreakable
+    fn temper(&mut self) {
+        if let Some(rupture) = self.rupture() {
+            if *rupture > 1 {
+                self.set_rupture(Some(*rupture - 1));
+            }
+        }
+    }
+}
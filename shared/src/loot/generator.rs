@@ -0,0 +1,128 @@
+use rand::Rng;
+
+use crate::equipment::protection::Protection;
+use crate::equipment::shield::Shield;
+use crate::equipment::weapon::Weapon;
+use crate::inventory::Item;
+use crate::random::Random;
+
+use super::uniques::random_unique;
+use super::{Lootable, Rarity};
+
+// One loot in UNIQUE_CHANCE is a named unique
+const UNIQUE_CHANCE: u32 = 100;
+
+pub fn generate_loot<T: Lootable>(mut item: T, rarity: Rarity) -> T {
+    let mut allowed_affixes = item.allowed_affixes();
+    for _ in 0..rarity.affix_count() {
+        if allowed_affixes.is_empty() {
+            break;
+        }
+        let index = rand::thread_rng().gen_range(0..allowed_affixes.len());
+        item.apply_affix(allowed_affixes.remove(index));
+    }
+    if rarity != Rarity::Common {
+        let name = generated_name(&item);
+        item.rename(name);
+    }
+    item.set_rarity(rarity);
+    item
+}
+
+pub fn generated_name<T: Lootable>(item: &T) -> String {
+    let mut name = item.base_name().to_string();
+    if let Some(affix) = item.affixes().first() {
+        name = format!("{} {}", affix.prefix(), name);
+    }
+    if let Some(affix) = item.affixes().get(1) {
+        name = format!("{} {}", name, affix.suffix());
+    }
+    name
+}
+
+pub fn random_loot() -> Item {
+    if rand::thread_rng().gen_range(0..UNIQUE_CHANCE) == 0 {
+        return random_unique();
+    }
+    let rarity = Rarity::random();
+    match rand::thread_rng().gen_range(0..3) {
+        0 => Item::Weapon(generate_loot(Weapon::random(), rarity)),
+        1 => Item::Protection(generate_loot(Protection::random(), rarity)),
+        2 => Item::Shield(generate_loot(Shield::random(), rarity)),
+        other => panic!("{other} not in range"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::equipment::rupture::Rupture;
+    use crate::equipment::weapon::WeaponKind;
+    use crate::name::Name;
+    use crate::stats::{StatKind, StatModifier};
+
+    use super::*;
+
+    #[test]
+    fn common_loot_is_the_template() {
+        let template = Weapon::new(WeaponKind::Sword);
+        let loot = generate_loot(Weapon::new(WeaponKind::Sword), Rarity::Common);
+        assert_eq!(loot.name(), template.name());
+        assert!(loot.affixes().is_empty());
+        assert_eq!(loot.rarity(), &Rarity::Common);
+    }
+
+    #[test]
+    fn affixes_match_rarity() {
+        for rarity in [Rarity::Uncommon, Rarity::Rare, Rarity::Epic] {
+            let loot = generate_loot(Weapon::new(WeaponKind::Axe), rarity.clone());
+            assert_eq!(loot.affixes().len(), rarity.affix_count());
+            assert_eq!(loot.rarity(), &rarity);
+            for affix in loot.affixes() {
+                assert_eq!(loot.affixes().iter().filter(|a| *a == affix).count(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn affixes_improve_the_item() {
+        let template = Weapon::new(WeaponKind::Hammer);
+        let loot = generate_loot(Weapon::new(WeaponKind::Hammer), Rarity::Epic);
+        let template_total = template.additional_damages() as i8
+            + template.value(&StatKind::Attack)
+            + template.value(&StatKind::Parry)
+            + template.value(&StatKind::Dexterity)
+            - template.rupture().unwrap() as i8;
+        let loot_total = loot.additional_damages() as i8
+            + loot.value(&StatKind::Attack)
+            + loot.value(&StatKind::Parry)
+            + loot.value(&StatKind::Dexterity)
+            - loot.rupture().unwrap() as i8;
+        assert_eq!(loot_total, template_total + 3);
+    }
+
+    #[test]
+    fn names_are_generated_from_affixes() {
+        let loot = generate_loot(Weapon::new(WeaponKind::WarHammer), Rarity::Rare);
+        let affixes = loot.affixes();
+        assert_eq!(
+            loot.name(),
+            format!("{} War Hammer {}", affixes[0].prefix(), affixes[1].suffix()),
+        );
+    }
+
+    #[test]
+    fn random_loot_is_valid() {
+        for _ in 0..200 {
+            let loot = random_loot();
+            let (rarity, affixes) = match &loot {
+                Item::Weapon(weapon) => (weapon.rarity(), weapon.affixes()),
+                Item::Protection(protection) => (protection.rarity(), protection.affixes()),
+                Item::Shield(shield) => (shield.rarity(), shield.affixes()),
+                Item::Consumable(_) => panic!("Consumables are not generated as loot"),
+            };
+            if rarity != &Rarity::Unique {
+                assert_eq!(affixes.len(), rarity.affix_count());
+            }
+        }
+    }
+}
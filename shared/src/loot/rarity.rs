@@ -0,0 +1,50 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::random::Random;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub enum Rarity {
+    #[default]
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Unique,
+}
+
+impl Rarity {
+    // Uniques come with their own fixed affixes
+    pub fn affix_count(&self) -> usize {
+        match self {
+            Rarity::Common => 0,
+            Rarity::Uncommon => 1,
+            Rarity::Rare => 2,
+            Rarity::Epic => 3,
+            Rarity::Unique => 0,
+        }
+    }
+
+    pub fn gold_value_percent(&self) -> u32 {
+        match self {
+            Rarity::Common => 100,
+            Rarity::Uncommon => 120,
+            Rarity::Rare => 150,
+            Rarity::Epic => 200,
+            Rarity::Unique => 300,
+        }
+    }
+}
+
+// Uniques are never rolled here, see random_loot
+impl Random for Rarity {
+    fn random() -> Self {
+        match rand::thread_rng().gen_range(1..=20) {
+            1..=11 => Rarity::Common,
+            12..=16 => Rarity::Uncommon,
+            17..=19 => Rarity::Rare,
+            20 => Rarity::Epic,
+            other => panic!("{other} not in range"),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use rand::Rng;
+
+use crate::equipment::protection::{Protection, ProtectionKind};
+use crate::equipment::shield::{Shield, ShieldKind};
+use crate::equipment::weapon::{Weapon, WeaponKind};
+use crate::inventory::Item;
+
+use super::{Affix, Lootable, Rarity};
+
+fn unique<T: Lootable>(mut item: T, name: &str, affixes: [Affix; 4]) -> T {
+    for affix in affixes {
+        item.apply_affix(affix);
+    }
+    item.rename(name.to_string());
+    item.set_rarity(Rarity::Unique);
+    item
+}
+
+pub fn random_unique() -> Item {
+    match rand::thread_rng().gen_range(0..5) {
+        0 => Item::Weapon(unique(
+            Weapon::new(WeaponKind::GreatSword),
+            "Widowmaker",
+            [Affix::Keen, Affix::Keen, Affix::Accurate, Affix::Tempered],
+        )),
+        1 => Item::Weapon(unique(
+            Weapon::new(WeaponKind::Sword),
+            "Oathkeeper",
+            [Affix::Guarding, Affix::Guarding, Affix::Accurate, Affix::Tempered],
+        )),
+        2 => Item::Weapon(unique(
+            Weapon::new(WeaponKind::WarHammer),
+            "Skullsplitter",
+            [Affix::Keen, Affix::Keen, Affix::Tempered, Affix::Tempered],
+        )),
+        3 => Item::Shield(unique(
+            Shield::new(ShieldKind::TowerShield),
+            "Aegis of the Fallen",
+            [Affix::Guarding, Affix::Nimble, Affix::Padded, Affix::Tempered],
+        )),
+        4 => Item::Protection(unique(
+            Protection::new(ProtectionKind::Boots),
+            "Whisperstep",
+            [Affix::Nimble, Affix::Nimble, Affix::Padded, Affix::Tempered],
+        )),
+        other => panic!("{other} not in range"),
+    }
+}
@@ -0,0 +1,48 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use super::MAX_LISTING_PRICE;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarketplaceErrorKind {
+    OwnListing,
+    NotAnAuction,
+    NotForSale,
+    BidTooLow,
+    Expired,
+    HasBids,
+    InvalidPrice,
+}
+
+#[derive(Debug)]
+pub struct MarketplaceError {
+    kind: MarketplaceErrorKind,
+    message: String,
+}
+
+impl MarketplaceError {
+    pub fn new(kind: MarketplaceErrorKind) -> Self {
+        let message = match kind {
+            MarketplaceErrorKind::OwnListing => "Cannot buy or bid on your own listing".to_string(),
+            MarketplaceErrorKind::NotAnAuction => "Listing is not an auction".to_string(),
+            MarketplaceErrorKind::NotForSale => "Listing can only be bid on".to_string(),
+            MarketplaceErrorKind::BidTooLow => "Bid is lower than the minimum bid".to_string(),
+            MarketplaceErrorKind::Expired => "Listing has expired".to_string(),
+            MarketplaceErrorKind::HasBids => "Listing already has bids".to_string(),
+            MarketplaceErrorKind::InvalidPrice => format!("Price must be between 1 and {MAX_LISTING_PRICE} gold"),
+        };
+        Self { kind, message }
+    }
+
+    pub fn kind(&self) -> &MarketplaceErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for MarketplaceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for MarketplaceError {}
@@ -0,0 +1,294 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::inventory::Item;
+use crate::unique_entity::UniqueEntity;
+
+use super::{MarketplaceError, MarketplaceErrorKind};
+
+// Seconds a fixed price listing stays on the marketplace
+pub const LISTING_DURATION: i64 = 48 * 60 * 60;
+// Seconds an auction stays open
+pub const AUCTION_DURATION: i64 = 24 * 60 * 60;
+// Share of every sale kept by the marketplace
+pub const TRANSACTION_FEE_PERCENT: u32 = 5;
+// A new bid must beat the current one by at least this share
+const MIN_BID_INCREMENT_PERCENT: u32 = 5;
+// Highest fixed price or starting bid a seller can ask for
+pub const MAX_LISTING_PRICE: u32 = 1_000_000;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum ListingKind {
+    FixedPrice,
+    Auction,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Bid {
+    bidder: Uuid,
+    amount: u32,
+}
+
+impl Bid {
+    pub fn bidder(&self) -> &Uuid {
+        &self.bidder
+    }
+
+    pub fn amount(&self) -> u32 {
+        self.amount
+    }
+}
+
+// Body of a listing request
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NewListing {
+    pub slot_uuid: Uuid,
+    pub kind: ListingKind,
+    pub price: u32,
+}
+
+// Rounded up so that cheap sales are not free of charge
+pub fn transaction_fee(amount: u32) -> u32 {
+    (amount as u64 * TRANSACTION_FEE_PERCENT as u64).div_ceil(100) as u32
+}
+
+pub fn check_price(price: u32) -> Result<(), MarketplaceError> {
+    if price == 0 || price > MAX_LISTING_PRICE {
+        return Err(MarketplaceError::new(MarketplaceErrorKind::InvalidPrice));
+    }
+    Ok(())
+}
+
+// While listed, the item and the highest bid's gold are held in escrow
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Listing {
+    uuid: Uuid,
+    seller: Uuid,
+    seller_name: String,
+    item: Item,
+    kind: ListingKind,
+    // Fixed price or starting bid
+    price: u32,
+    bid: Option<Bid>,
+    // Timestamp after which the listing is settled
+    expires_at: i64,
+}
+
+impl Listing {
+    pub fn new(seller: Uuid, seller_name: String, item: Item, kind: ListingKind, price: u32) -> Self {
+        let duration = match kind {
+            ListingKind::FixedPrice => LISTING_DURATION,
+            ListingKind::Auction => AUCTION_DURATION,
+        };
+        Self {
+            uuid: Uuid::new_v4(),
+            seller,
+            seller_name,
+            item,
+            kind,
+            price,
+            bid: None,
+            expires_at: Utc::now().timestamp() + duration,
+        }
+    }
+
+    pub fn seller(&self) -> &Uuid {
+        &self.seller
+    }
+
+    pub fn seller_name(&self) -> &str {
+        &self.seller_name
+    }
+
+    pub fn item(&self) -> &Item {
+        &self.item
+    }
+
+    pub fn kind(&self) -> &ListingKind {
+        &self.kind
+    }
+
+    pub fn price(&self) -> u32 {
+        self.price
+    }
+
+    pub fn bid(&self) -> Option<&Bid> {
+        self.bid.as_ref()
+    }
+
+    pub fn expires_at(&self) -> i64 {
+        self.expires_at
+    }
+
+    pub fn is_expired(&self) -> bool {
+        Utc::now().timestamp() >= self.expires_at
+    }
+
+    pub fn min_bid(&self) -> u32 {
+        match &self.bid {
+            None => self.price,
+            Some(bid) => {
+                let increment = (bid.amount as u64 * MIN_BID_INCREMENT_PERCENT as u64 / 100).max(1);
+                (bid.amount as u64 + increment).min(u32::MAX as u64) as u32
+            },
+        }
+    }
+
+    // Returns the price the buyer has to pay
+    pub fn check_purchase(&self, buyer: &Uuid) -> Result<u32, MarketplaceError> {
+        if self.kind != ListingKind::FixedPrice {
+            return Err(MarketplaceError::new(MarketplaceErrorKind::NotForSale));
+        }
+        if self.seller == *buyer {
+            return Err(MarketplaceError::new(MarketplaceErrorKind::OwnListing));
+        }
+        if self.is_expired() {
+            return Err(MarketplaceError::new(MarketplaceErrorKind::Expired));
+        }
+        Ok(self.price)
+    }
+
+    pub fn check_cancel(&self) -> Result<(), MarketplaceError> {
+        if self.bid.is_some() {
+            return Err(MarketplaceError::new(MarketplaceErrorKind::HasBids));
+        }
+        Ok(())
+    }
+
+    // server only
+    // Returns the outbid bid, whose gold goes back to its bidder
+    pub fn place_bid(&mut self, bidder: &Uuid, amount: u32) -> Result<Option<Bid>, MarketplaceError> {
+        if self.kind != ListingKind::Auction {
+            return Err(MarketplaceError::new(MarketplaceErrorKind::NotAnAuction));
+        }
+        if self.seller == *bidder {
+            return Err(MarketplaceError::new(MarketplaceErrorKind::OwnListing));
+        }
+        if self.is_expired() {
+            return Err(MarketplaceError::new(MarketplaceErrorKind::Expired));
+        }
+        if amount < self.min_bid() {
+            return Err(MarketplaceError::new(MarketplaceErrorKind::BidTooLow));
+        }
+        Ok(self.bid.replace(Bid { bidder: *bidder, amount }))
+    }
+
+    // Who gets the item once the listing is settled
+    pub fn recipient(&self) -> &Uuid {
+        match &self.bid {
+            Some(bid) => &bid.bidder,
+            None => &self.seller,
+        }
+    }
+
+    // server only
+    pub fn into_item(self) -> Item {
+        self.item
+    }
+}
+
+impl UniqueEntity for Listing {
+    fn uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::equipment::weapon::Weapon;
+    use crate::random::Random;
+
+    use super::*;
+
+    fn auction(price: u32) -> Listing {
+        let item = Item::Weapon(Weapon::random());
+        Listing::new(Uuid::new_v4(), String::from("seller"), item, ListingKind::Auction, price)
+    }
+
+    #[test]
+    fn first_bid_must_reach_starting_price() {
+        let mut listing = auction(100);
+        let bidder = Uuid::new_v4();
+        assert_eq!(
+            listing.place_bid(&bidder, 99).unwrap_err().kind(),
+            &MarketplaceErrorKind::BidTooLow,
+        );
+        assert!(listing.place_bid(&bidder, 100).unwrap().is_none());
+        assert_eq!(listing.recipient(), &bidder);
+    }
+
+    #[test]
+    fn huge_amounts_do_not_overflow() {
+        assert_eq!(transaction_fee(u32::MAX), (u32::MAX as u64 * 5).div_ceil(100) as u32);
+        let mut listing = auction(100);
+        listing.place_bid(&Uuid::new_v4(), u32::MAX).unwrap();
+        assert_eq!(listing.min_bid(), u32::MAX);
+        assert!(check_price(MAX_LISTING_PRICE).is_ok());
+        assert_eq!(check_price(MAX_LISTING_PRICE + 1).unwrap_err().kind(), &MarketplaceErrorKind::InvalidPrice);
+        assert_eq!(check_price(0).unwrap_err().kind(), &MarketplaceErrorKind::InvalidPrice);
+    }
+
+    #[test]
+    fn outbid_is_returned_for_refund() {
+        let mut listing = auction(100);
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        listing.place_bid(&first, 100).unwrap();
+        assert_eq!(listing.min_bid(), 105);
+        let outbid = listing.place_bid(&second, 105).unwrap().unwrap();
+        assert_eq!(outbid.bidder(), &first);
+        assert_eq!(outbid.amount(), 100);
+        assert_eq!(listing.recipient(), &second);
+    }
+
+    #[test]
+    fn seller_cannot_bid_or_buy() {
+        let mut listing = auction(100);
+        let seller = *listing.seller();
+        assert_eq!(
+            listing.place_bid(&seller, 200).unwrap_err().kind(),
+            &MarketplaceErrorKind::OwnListing,
+        );
+        listing.kind = ListingKind::FixedPrice;
+        assert_eq!(
+            listing.check_purchase(&seller).unwrap_err().kind(),
+            &MarketplaceErrorKind::OwnListing,
+        );
+    }
+
+    #[test]
+    fn expired_listing_returns_to_seller() {
+        let mut listing = auction(100);
+        listing.expires_at = Utc::now().timestamp() - 1;
+        assert!(listing.is_expired());
+        assert_eq!(
+            listing.place_bid(&Uuid::new_v4(), 200).unwrap_err().kind(),
+            &MarketplaceErrorKind::Expired,
+        );
+        assert_eq!(listing.recipient(), listing.seller());
+    }
+
+    #[test]
+    fn auctions_are_not_for_sale_and_cannot_be_cancelled_with_bids() {
+        let mut listing = auction(100);
+        assert_eq!(
+            listing.check_purchase(&Uuid::new_v4()).unwrap_err().kind(),
+            &MarketplaceErrorKind::NotForSale,
+        );
+        assert!(listing.check_cancel().is_ok());
+        listing.place_bid(&Uuid::new_v4(), 100).unwrap();
+        assert_eq!(
+            listing.check_cancel().unwrap_err().kind(),
+            &MarketplaceErrorKind::HasBids,
+        );
+    }
+
+    #[test]
+    fn fee_is_taken_from_sales() {
+        assert_eq!(transaction_fee(200), 10);
+        assert_eq!(transaction_fee(10), 1);
+        assert_eq!(transaction_fee(1), 1);
+        assert_eq!(transaction_fee(21), 2);
+    }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum FightEndReason {
+    Death,
+    KnockOut,
+    Disarmed,
+    Surrender,
+    JudgeDecision,
+    Draw,
+}
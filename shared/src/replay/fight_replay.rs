@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::turn_summary::TurnSummary;
+use super::xp_award::XpAward;
+use super::FightEndReason;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FightReplay {
@@ -46,6 +48,10 @@ pub struct FightReplaySummary {
     winner: Option<Uuid>,
     blue_corner_uuid: Uuid,
     red_corner_uuid: Uuid,
+    #[serde(default)]
+    end_reason: Option<FightEndReason>,
+    #[serde(default)]
+    xp_awards: Vec<XpAward>,
 }
 
 impl FightReplaySummary {
@@ -65,18 +71,39 @@ impl FightReplaySummary {
         &self.red_corner_uuid
     }
 
+    // Summaries written before end reasons were recorded have none
+    pub fn end_reason(&self) -> &Option<FightEndReason> {
+        &self.end_reason
+    }
+
+    pub fn xp_awards(&self) -> &Vec<XpAward> {
+        &self.xp_awards
+    }
+
+    pub fn xp_award_for(&self, warrior_uuid: &Uuid) -> Option<&XpAward> {
+        self.xp_awards.iter().find(|award| award.warrior_uuid() == warrior_uuid)
+    }
+
+    // server only
+    pub fn push_xp_award(&mut self, award: XpAward) {
+        self.xp_awards.push(award)
+    }
+
     // server only
     pub fn new(
         replay_uuid: Uuid,
         winner: Option<Uuid>,
         blue_corner_uuid: Uuid,
         red_corner_uuid: Uuid,
+        end_reason: FightEndReason,
     ) -> Self {
         Self {
             replay_uuid,
             winner,
             blue_corner_uuid,
             red_corner_uuid,
+            end_reason: Some(end_reason),
+            xp_awards: vec![],
         }
     }
 }
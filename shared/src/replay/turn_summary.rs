@@ -4,21 +4,53 @@ use uuid::Uuid;
 use crate::assault::assailant::Assailant;
 use crate::assault::assault_summary::AssaultSummary;
 use crate::assault::end_turn_consequences::EndTurnConsequences;
+use crate::warrior::Stance;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TurnSummary {
     first_assailant_uuid: Uuid,
     second_assailant_uuid: Uuid,
+    #[serde(default)]
+    first_assailant_stance: Stance,
+    #[serde(default)]
+    second_assailant_stance: Stance,
     assaults: [AssaultSummary; 2],
     first_assailant_turn_end: EndTurnConsequences,
     second_assailant_turn_end: EndTurnConsequences,
 }
 
 impl TurnSummary {
+    pub fn first_assailant_uuid(&self) -> &Uuid {
+        &self.first_assailant_uuid
+    }
+
+    pub fn second_assailant_uuid(&self) -> &Uuid {
+        &self.second_assailant_uuid
+    }
+
+    pub fn first_assailant_stance(&self) -> &Stance {
+        &self.first_assailant_stance
+    }
+
+    pub fn second_assailant_stance(&self) -> &Stance {
+        &self.second_assailant_stance
+    }
+
     pub fn assaults(&self) -> &[AssaultSummary; 2] {
         &self.assaults
     }
 
+    pub fn damages_dealt_by(&self, uuid: &Uuid) -> u8 {
+        self.assaults.iter().fold(0, |total, assault| total.saturating_add(assault.damages_dealt_by(uuid)))
+    }
+
+    // Assaults the warrior was in a position to attempt this turn
+    pub fn attacks_made_by(&self, uuid: &Uuid) -> u32 {
+        self.assaults.iter()
+            .filter(|assault| assault.assailant_uuid() == uuid && assault.not_possible().is_none())
+            .count() as u32
+    }
+
     pub fn first_assailant_turn_end(&self) -> &EndTurnConsequences {
         &self.first_assailant_turn_end
     }
@@ -32,6 +64,8 @@ impl TurnSummary {
         first_assailant: &mut dyn Assailant,
         second_assailant: &mut dyn Assailant,
     ) -> Self {
+        let first_assailant_stance = first_assailant.stance().clone();
+        let second_assailant_stance = second_assailant.stance().clone();
         let blue_assault = AssaultSummary::new(first_assailant, second_assailant);
         blue_assault.consequences().apply(
             first_assailant,
@@ -42,12 +76,18 @@ impl TurnSummary {
             second_assailant,
             first_assailant,
         );
+        let first_assailant_turn_end = first_assailant.end_turn();
+        first_assailant_turn_end.apply(first_assailant);
+        let second_assailant_turn_end = second_assailant.end_turn();
+        second_assailant_turn_end.apply(second_assailant);
         Self {
             first_assailant_uuid: first_assailant.uuid().clone(),
             second_assailant_uuid: second_assailant.uuid().clone(),
+            first_assailant_stance,
+            second_assailant_stance,
             assaults: [blue_assault, red_assault],
-            first_assailant_turn_end: first_assailant.end_turn(),
-            second_assailant_turn_end: second_assailant.end_turn(),
+            first_assailant_turn_end,
+            second_assailant_turn_end,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::turn_summary::TurnSummary;
+use super::{FightEndReason, FightReplaySummary};
+
+const PARTICIPATION_XP: u64 = 5;
+// A warrior who surrendered lives to fight another day but learns less
+const SURRENDER_XP: u64 = 5;
+const VICTORY_XP: u64 = 20;
+const KILL_XP: u64 = 10;
+const CRITICAL_HIT_XP: u64 = 3;
+const CRITICAL_PARRY_XP: u64 = 4;
+// Per level the beaten opponent had over the winner
+const UNDERDOG_XP_PER_LEVEL: u64 = 5;
+const CHAMPION_XP: u64 = 50;
+const RUNNER_UP_XP: u64 = 20;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum TournamentPlacement {
+    Champion,
+    RunnerUp,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum XpSource {
+    Participation,
+    Surrender,
+    Victory,
+    Kill,
+    CriticalHits(u32),
+    CriticalParries(u32),
+    // Levels the beaten opponent had over the winner
+    Underdog(u8),
+    Placement(TournamentPlacement),
+}
+
+impl XpSource {
+    pub fn xp(&self) -> u64 {
+        match self {
+            Self::Participation => PARTICIPATION_XP,
+            Self::Surrender => SURRENDER_XP,
+            Self::Victory => VICTORY_XP,
+            Self::Kill => KILL_XP,
+            Self::CriticalHits(count) => CRITICAL_HIT_XP * *count as u64,
+            Self::CriticalParries(count) => CRITICAL_PARRY_XP * *count as u64,
+            Self::Underdog(level_gap) => UNDERDOG_XP_PER_LEVEL * *level_gap as u64,
+            Self::Placement(TournamentPlacement::Champion) => CHAMPION_XP,
+            Self::Placement(TournamentPlacement::RunnerUp) => RUNNER_UP_XP,
+        }
+    }
+}
+
+// Criticals a warrior landed or parried during a fight
+#[derive(Debug, Default, PartialEq)]
+pub struct FightFeats {
+    pub critical_hits: u32,
+    pub critical_parries: u32,
+}
+
+impl FightFeats {
+    pub fn from_turns(warrior_uuid: &Uuid, turn_summaries: &[TurnSummary]) -> Self {
+        let mut feats = Self::default();
+        for assault in turn_summaries.iter().flat_map(|turn| turn.assaults()) {
+            if assault.assailant_uuid() == warrior_uuid && assault.attack_critical().is_some() {
+                feats.critical_hits += 1;
+            }
+            if assault.victim_uuid() == warrior_uuid && assault.parry_critical().is_some() {
+                feats.critical_parries += 1;
+            }
+        }
+        feats
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct XpAward {
+    warrior_uuid: Uuid,
+    sources: Vec<XpSource>,
+}
+
+impl XpAward {
+    pub fn warrior_uuid(&self) -> &Uuid {
+        &self.warrior_uuid
+    }
+
+    pub fn sources(&self) -> &Vec<XpSource> {
+        &self.sources
+    }
+
+    pub fn total(&self) -> u64 {
+        self.sources.iter().map(XpSource::xp).sum()
+    }
+
+    // server only
+    // The dead learn nothing, so a killed loser gets no award at all
+    pub fn for_fight(
+        warrior_uuid: &Uuid,
+        levels: (u8, u8),
+        summary: &FightReplaySummary,
+        feats: &FightFeats,
+    ) -> Option<Self> {
+        let (level, opponent_level) = levels;
+        let won = summary.winner().is_some_and(|winner| &winner == warrior_uuid);
+        if !won && summary.end_reason() == &Some(FightEndReason::Death) {
+            return None;
+        }
+        let mut sources = vec![XpSource::Participation];
+        if !won && summary.end_reason() == &Some(FightEndReason::Surrender) {
+            sources.push(XpSource::Surrender);
+        }
+        if won {
+            sources.push(XpSource::Victory);
+            if summary.end_reason() == &Some(FightEndReason::Death) {
+                sources.push(XpSource::Kill);
+            }
+            if opponent_level > level {
+                sources.push(XpSource::Underdog(opponent_level - level));
+            }
+        }
+        if feats.critical_hits > 0 {
+            sources.push(XpSource::CriticalHits(feats.critical_hits));
+        }
+        if feats.critical_parries > 0 {
+            sources.push(XpSource::CriticalParries(feats.critical_parries));
+        }
+        Some(Self { warrior_uuid: *warrior_uuid, sources })
+    }
+
+    // server only
+    pub fn add_placement(&mut self, placement: TournamentPlacement) {
+        self.sources.push(XpSource::Placement(placement));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(winner: &Uuid, loser: &Uuid, end_reason: FightEndReason) -> FightReplaySummary {
+        FightReplaySummary::new(Uuid::new_v4(), Some(*winner), *winner, *loser, end_reason)
+    }
+
+    #[test]
+    fn underdog_killer_earns_every_bonus() {
+        let (winner, loser) = (Uuid::new_v4(), Uuid::new_v4());
+        let feats = FightFeats { critical_hits: 2, critical_parries: 1 };
+        let award = XpAward::for_fight(&winner, (2, 5), &summary(&winner, &loser, FightEndReason::Death), &feats)
+            .unwrap();
+        assert_eq!(award.sources(), &vec![
+            XpSource::Participation,
+            XpSource::Victory,
+            XpSource::Kill,
+            XpSource::Underdog(3),
+            XpSource::CriticalHits(2),
+            XpSource::CriticalParries(1),
+        ]);
+        assert_eq!(award.total(), 5 + 20 + 10 + 15 + 6 + 4);
+    }
+
+    #[test]
+    fn losers_still_learn_something() {
+        let (winner, loser) = (Uuid::new_v4(), Uuid::new_v4());
+        let result = summary(&winner, &loser, FightEndReason::KnockOut);
+        let mut award = XpAward::for_fight(&loser, (1, 9), &result, &FightFeats::from_turns(&loser, &[]))
+            .unwrap();
+        assert_eq!(award.sources(), &vec![XpSource::Participation]);
+        award.add_placement(TournamentPlacement::RunnerUp);
+        assert_eq!(award.total(), PARTICIPATION_XP + RUNNER_UP_XP);
+    }
+
+    #[test]
+    fn surrendering_earns_more_than_a_knock_out_and_dying_earns_nothing() {
+        let (winner, loser) = (Uuid::new_v4(), Uuid::new_v4());
+        let feats = FightFeats::default();
+        let knock_out = XpAward::for_fight(&loser, (3, 3), &summary(&winner, &loser, FightEndReason::KnockOut), &feats)
+            .unwrap();
+        let surrender = XpAward::for_fight(&loser, (3, 3), &summary(&winner, &loser, FightEndReason::Surrender), &feats)
+            .unwrap();
+        assert_eq!(surrender.sources(), &vec![XpSource::Participation, XpSource::Surrender]);
+        assert!(surrender.total() > knock_out.total());
+        assert!(XpAward::for_fight(&loser, (3, 3), &summary(&winner, &loser, FightEndReason::Death), &feats).is_none());
+    }
+}
@@ -1,15 +1,109 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::inventory::{GoldValue, HasInventory, HasMutableInventory, Inventory, Item};
+use crate::unique_entity::UniqueEntity;
+
+// Seconds between two restocks
+pub const RESTOCK_INTERVAL: i64 = 6 * 60 * 60;
+// Each item sold since the last restock raises its price
+const DEMAND_PERCENT_STEP: i32 = 10;
+// Each extra copy in stock lowers its price
+const SUPPLY_PERCENT_STEP: i32 = 5;
+const MIN_PRICE_PERCENT: i32 = 50;
+const MAX_PRICE_PERCENT: i32 = 200;
 
-use crate::inventory::{HasInventory, HasMutableInventory, Inventory};
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ShopStock {
+    count: u8,
+    sold: u8,
+}
+
+impl ShopStock {
+    pub fn count(&self) -> u8 {
+        self.count
+    }
+
+    pub fn sold(&self) -> u8 {
+        self.sold
+    }
+
+    fn price_percent(&self) -> i32 {
+        let percent = 100
+            + DEMAND_PERCENT_STEP * self.sold as i32
+            - SUPPLY_PERCENT_STEP * (self.count as i32 - 1).max(0);
+        percent.clamp(MIN_PRICE_PERCENT, MAX_PRICE_PERCENT)
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Shop {
+    uuid: Uuid,
     inventory: Inventory,
+    stocks: HashMap<Uuid, ShopStock>,
+    // Timestamp of the next restock
+    next_restock: i64,
 }
 
 impl Shop {
-    pub fn new(inventory: Inventory) -> Self {
-        Self { inventory }
+    pub fn new(uuid: Uuid) -> Self {
+        Self {
+            uuid,
+            inventory: Inventory::new(),
+            stocks: HashMap::new(),
+            next_restock: Utc::now().timestamp() + RESTOCK_INTERVAL,
+        }
+    }
+
+    pub fn next_restock(&self) -> i64 {
+        self.next_restock
+    }
+
+    pub fn needs_restock(&self) -> bool {
+        Utc::now().timestamp() >= self.next_restock
+    }
+
+    pub fn stock(&self, slot_uuid: &Uuid) -> Option<&ShopStock> {
+        self.stocks.get(slot_uuid)
+    }
+
+    pub fn price(&self, slot_uuid: &Uuid) -> Option<u32> {
+        let item = self.inventory.items().get(slot_uuid)?;
+        let percent = self.stocks.get(slot_uuid)?.price_percent();
+        Some(item.gold_value() * percent as u32 / 100)
+    }
+
+    // server only
+    pub fn add_stock(&mut self, item: Item, count: u8) -> Option<Uuid> {
+        if count == 0 {
+            return None;
+        }
+        let slot_uuid = Uuid::new_v4();
+        self.inventory.items_mut().insert(slot_uuid, item);
+        self.stocks.insert(slot_uuid, ShopStock { count, sold: 0 });
+        Some(slot_uuid)
+    }
+
+    // server only
+    pub fn take_one(&mut self, slot_uuid: &Uuid) -> Option<Item> {
+        let stock = self.stocks.get_mut(slot_uuid)?;
+        stock.count -= 1;
+        stock.sold += 1;
+        if stock.count > 0 {
+            self.inventory.items().get(slot_uuid).cloned()
+        } else {
+            self.stocks.remove(slot_uuid);
+            self.inventory.items_mut().remove(slot_uuid)
+        }
+    }
+}
+
+impl UniqueEntity for Shop {
+    fn uuid(&self) -> &Uuid {
+        &self.uuid
     }
 }
 
@@ -24,3 +118,64 @@ impl HasMutableInventory for Shop {
         &mut self.inventory
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::equipment::weapon::{Weapon, WeaponKind};
+
+    use super::*;
+
+    fn sword() -> Item {
+        Item::Weapon(Weapon::new(WeaponKind::Sword))
+    }
+
+    #[test]
+    fn empty_stock_is_not_added() {
+        let mut shop = Shop::new(Uuid::new_v4());
+        assert!(shop.add_stock(sword(), 0).is_none());
+        assert!(shop.inventory().items().is_empty());
+    }
+
+    #[test]
+    fn take_one_decrements_stock() {
+        let mut shop = Shop::new(Uuid::new_v4());
+        let slot = shop.add_stock(sword(), 2).unwrap();
+        assert!(shop.take_one(&slot).is_some());
+        assert_eq!(shop.stock(&slot).unwrap().count(), 1);
+        assert_eq!(shop.stock(&slot).unwrap().sold(), 1);
+        assert!(shop.take_one(&slot).is_some());
+        assert!(shop.stock(&slot).is_none());
+        assert!(shop.inventory().items().get(&slot).is_none());
+        assert!(shop.take_one(&slot).is_none());
+    }
+
+    #[test]
+    fn price_follows_supply_and_demand() {
+        let mut shop = Shop::new(Uuid::new_v4());
+        let single = shop.add_stock(sword(), 1).unwrap();
+        let plenty = shop.add_stock(sword(), 5).unwrap();
+        let base_price = sword().gold_value();
+        assert_eq!(shop.price(&single).unwrap(), base_price);
+        assert!(shop.price(&plenty).unwrap() < base_price);
+
+        let before_sale = shop.price(&plenty).unwrap();
+        shop.take_one(&plenty);
+        shop.take_one(&plenty);
+        assert!(shop.price(&plenty).unwrap() > before_sale);
+    }
+
+    #[test]
+    fn price_is_clamped() {
+        let stock = ShopStock { count: 1, sold: 100 };
+        assert_eq!(stock.price_percent(), MAX_PRICE_PERCENT);
+        let stock = ShopStock { count: 200, sold: 0 };
+        assert_eq!(stock.price_percent(), MIN_PRICE_PERCENT);
+    }
+
+    #[test]
+    fn new_shop_is_not_due_for_restock() {
+        let shop = Shop::new(Uuid::new_v4());
+        assert!(!shop.needs_restock());
+        assert!(shop.next_restock() > Utc::now().timestamp());
+    }
+}
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+
+use crate::equipment::weapon::Weapon;
+use crate::stats::{StatKind, StatModifier};
+
+pub const MAX_STAMINA: u8 = 100;
+// Spent every turn just by standing in the arena
+const TURN_COST: u8 = 1;
+const ONE_HANDED_SWING_COST: u8 = 1;
+const TWO_HANDED_SWING_COST: u8 = 3;
+// Part of the missing stamina regained while waiting for the next round
+const ROUND_RECOVERY_RATIO: u8 = 2;
+// Stamina spent past exhaustion for each extra damage a winded fighter's blows deal
+const EXHAUSTION_PER_DAMAGE: u16 = 50;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Stamina {
+    max: u8,
+    current: u8,
+    // Stamina spent while there was none left
+    #[serde(default)]
+    exhaustion: u16,
+}
+
+impl Default for Stamina {
+    fn default() -> Self {
+        Self::new(MAX_STAMINA)
+    }
+}
+
+impl Stamina {
+    pub fn new(max: u8) -> Self {
+        Self { max, current: max, exhaustion: 0 }
+    }
+
+    pub fn max(&self) -> u8 {
+        self.max
+    }
+
+    pub fn current(&self) -> u8 {
+        self.current
+    }
+
+    pub fn exhaustion(&self) -> u16 {
+        self.exhaustion
+    }
+
+    // What can not be paid from the pool wears the fighter out
+    pub fn spend(&mut self, amount: u8) {
+        let overdraft = amount.saturating_sub(self.current);
+        self.current = self.current.saturating_sub(amount);
+        self.exhaustion = self.exhaustion.saturating_add(overdraft as u16);
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.exhaustion > 0
+    }
+
+    // Blows land harder as an exhausted fighter's guard drops
+    pub fn exhaustion_damages(&self) -> u8 {
+        (self.exhaustion / EXHAUSTION_PER_DAMAGE).min(u8::MAX as u16) as u8
+    }
+
+    // Half of what was lost comes back between two rounds
+    pub fn recover_between_rounds(&mut self) {
+        self.exhaustion = 0;
+        let missing = self.max - self.current;
+        self.current += missing.div_ceil(ROUND_RECOVERY_RATIO);
+    }
+
+    pub fn refill(&mut self) {
+        self.exhaustion = 0;
+        self.current = self.max;
+    }
+
+    pub fn is_low(&self) -> bool {
+        (self.current as u16) * 2 <= self.max as u16
+    }
+}
+
+// Stamina a fighter spends in one turn, swinging the given weapon
+pub fn turn_cost(weapon: &Option<Weapon>) -> u8 {
+    match weapon {
+        None => TURN_COST,
+        Some(weapon) if weapon.is_two_handed() => TURN_COST + TWO_HANDED_SWING_COST,
+        Some(_) => TURN_COST + ONE_HANDED_SWING_COST,
+    }
+}
+
+// A winded fighter can neither strike nor guard properly
+impl StatModifier for Stamina {
+    fn value(&self, stat: &StatKind) -> i8 {
+        let penalty = if self.current == 0 {
+            4
+        } else if (self.current as u16) * 4 <= self.max as u16 {
+            2
+        } else if self.is_low() {
+            1
+        } else {
+            0
+        };
+        match stat {
+            StatKind::Attack | StatKind::Parry => -penalty,
+            _ => 0,
+        }
+    }
+}
+
+pub trait HasStamina {
+    fn stamina(&self) -> &Stamina;
+}
+
+pub trait HasMutableStamina: HasStamina {
+    fn stamina_mut(&mut self) -> &mut Stamina;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::equipment::weapon::WeaponKind;
+
+    use super::*;
+
+    #[test]
+    fn two_handed_weapons_cost_more() {
+        let sword = Some(Weapon::new(WeaponKind::Sword));
+        let greatsword = Some(Weapon::new(WeaponKind::GreatSword));
+        assert!(turn_cost(&greatsword) > turn_cost(&sword));
+        assert!(turn_cost(&sword) > turn_cost(&None));
+    }
+
+    #[test]
+    fn low_stamina_lowers_attack_and_parry() {
+        let mut stamina = Stamina::default();
+        assert_eq!(stamina.value(&StatKind::Attack), 0);
+        stamina.spend(MAX_STAMINA / 2);
+        assert_eq!(stamina.value(&StatKind::Parry), -1);
+        stamina.spend(MAX_STAMINA);
+        assert_eq!(stamina.current(), 0);
+        assert_eq!(stamina.value(&StatKind::Attack), -4);
+        assert_eq!(stamina.value(&StatKind::Strength), 0);
+    }
+
+    #[test]
+    fn stamina_partially_recovers_between_rounds() {
+        let mut stamina = Stamina::default();
+        stamina.spend(40);
+        stamina.recover_between_rounds();
+        assert_eq!(stamina.current(), MAX_STAMINA - 20);
+        stamina.refill();
+        assert_eq!(stamina.current(), MAX_STAMINA);
+    }
+
+    #[test]
+    fn spending_past_empty_exhausts() {
+        let mut stamina = Stamina::default();
+        stamina.spend(MAX_STAMINA);
+        assert!(!stamina.is_exhausted());
+        for _ in 0..EXHAUSTION_PER_DAMAGE / 2 {
+            stamina.spend(2);
+        }
+        assert_eq!(stamina.exhaustion(), EXHAUSTION_PER_DAMAGE);
+        assert_eq!(stamina.exhaustion_damages(), 1);
+        stamina.recover_between_rounds();
+        assert!(!stamina.is_exhausted());
+        assert_eq!(stamina.current(), MAX_STAMINA / 2);
+    }
+}
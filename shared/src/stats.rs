@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{dice::Dice, random::Random};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum StatKind {
     Attack,
     Parry,
@@ -107,6 +107,17 @@ impl StatsManager {
         real
     }
 
+    // Applies a modifier to the natural stats themselves
+    pub fn modified(&self, modifier: &dyn StatModifier) -> Self {
+        Self {
+            nat_attack: modifier.modify_stat(self.nat_attack.clone()),
+            nat_parry: modifier.modify_stat(self.nat_parry.clone()),
+            nat_strength: modifier.modify_stat(self.nat_strength.clone()),
+            nat_dexterity: modifier.modify_stat(self.nat_dexterity.clone()),
+            nat_courage: modifier.modify_stat(self.nat_courage.clone()),
+        }
+    }
+
     pub fn increment_nat_stat(&mut self, stat: &StatKind) {
         match stat {
             &StatKind::Attack => self.nat_attack = self.nat_attack.modify(1),
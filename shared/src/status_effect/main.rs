@@ -0,0 +1,254 @@
+use serde::{Deserialize, Serialize};
+
+use crate::stats::{StatKind, StatModifier};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum StatusEffectKind {
+    Bleeding,
+    Stunned,
+    Poisoned,
+    Enraged,
+    Blinded,
+    Exhausted,
+}
+
+impl StatusEffectKind {
+    // Turns the effect lasts when freshly applied
+    pub fn duration(&self) -> u8 {
+        match self {
+            Self::Bleeding => 4,
+            Self::Stunned => 1,
+            Self::Poisoned => 3,
+            Self::Enraged => 3,
+            Self::Blinded => 2,
+            Self::Exhausted => 5,
+        }
+    }
+
+    // Effects that can not stack only get their duration refreshed
+    pub fn max_stacks(&self) -> u8 {
+        match self {
+            Self::Bleeding => 3,
+            Self::Poisoned => 5,
+            _ => 1,
+        }
+    }
+
+    // Wounds keep hurting once the fight is over, the rest wears off
+    pub fn lingers(&self) -> bool {
+        matches!(self, Self::Bleeding | Self::Poisoned)
+    }
+
+    fn damages_per_stack(&self) -> u8 {
+        match self {
+            Self::Bleeding | Self::Poisoned => 1,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StatusEffect {
+    kind: StatusEffectKind,
+    stacks: u8,
+    turns_remaining: u8,
+    // Skips one tick so the turn the effect was inflicted in does not count
+    #[serde(default)]
+    applied_this_turn: bool,
+}
+
+impl StatusEffect {
+    pub fn new(kind: StatusEffectKind) -> Self {
+        Self::with_stacks(kind, 1)
+    }
+
+    pub fn with_stacks(kind: StatusEffectKind, stacks: u8) -> Self {
+        Self {
+            stacks: stacks.clamp(1, kind.max_stacks()),
+            turns_remaining: kind.duration(),
+            applied_this_turn: false,
+            kind,
+        }
+    }
+
+    pub fn kind(&self) -> &StatusEffectKind {
+        &self.kind
+    }
+
+    pub fn stacks(&self) -> u8 {
+        self.stacks
+    }
+
+    pub fn turns_remaining(&self) -> u8 {
+        self.turns_remaining
+    }
+
+    fn tick_damages(&self) -> u8 {
+        self.kind.damages_per_stack().saturating_mul(self.stacks)
+    }
+}
+
+impl StatModifier for StatusEffect {
+    fn value(&self, stat: &StatKind) -> i8 {
+        let per_stack = match (&self.kind, stat) {
+            (StatusEffectKind::Stunned, StatKind::Attack) => -3,
+            (StatusEffectKind::Stunned, StatKind::Parry) => -3,
+            (StatusEffectKind::Stunned, StatKind::Dexterity) => -2,
+            (StatusEffectKind::Poisoned, StatKind::Strength) => -1,
+            (StatusEffectKind::Enraged, StatKind::Attack) => 2,
+            (StatusEffectKind::Enraged, StatKind::Parry) => -3,
+            (StatusEffectKind::Enraged, StatKind::Courage) => 3,
+            (StatusEffectKind::Enraged, StatKind::Strength) => 1,
+            (StatusEffectKind::Blinded, StatKind::Attack) => -3,
+            (StatusEffectKind::Blinded, StatKind::Parry) => -4,
+            (StatusEffectKind::Exhausted, StatKind::Attack) => -1,
+            (StatusEffectKind::Exhausted, StatKind::Parry) => -1,
+            (StatusEffectKind::Exhausted, StatKind::Dexterity) => -1,
+            (StatusEffectKind::Exhausted, StatKind::Strength) => -2,
+            _ => 0,
+        };
+        per_stack * self.stacks as i8
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct StatusEffects(Vec<StatusEffect>);
+
+impl StatusEffects {
+    pub fn list(&self) -> &Vec<StatusEffect> {
+        &self.0
+    }
+
+    pub fn has(&self, kind: &StatusEffectKind) -> bool {
+        self.0.iter().any(|effect| &effect.kind == kind)
+    }
+
+    // The same effect stacks up to its cap and keeps the longest duration
+    pub fn add(&mut self, effect: StatusEffect) {
+        match self.0.iter_mut().find(|current| current.kind == effect.kind) {
+            Some(current) => {
+                current.stacks = current.stacks
+                    .saturating_add(effect.stacks)
+                    .min(current.kind.max_stacks());
+                if effect.turns_remaining >= current.turns_remaining {
+                    current.turns_remaining = effect.turns_remaining;
+                    current.applied_this_turn = effect.applied_this_turn;
+                }
+            },
+            None => self.0.push(effect),
+        }
+    }
+
+    // Effects inflicted during a fight only start wearing off on the next turn
+    pub fn inflict(&mut self, mut effect: StatusEffect) {
+        effect.applied_this_turn = true;
+        self.add(effect);
+    }
+
+    // Removes a single stack, the effect is gone with its last one
+    pub fn relieve(&mut self, kind: &StatusEffectKind) {
+        if let Some(index) = self.0.iter().position(|effect| &effect.kind == kind) {
+            self.0[index].stacks -= 1;
+            if self.0[index].stacks == 0 {
+                self.0.remove(index);
+            }
+        }
+    }
+
+    pub fn remove(&mut self, kind: &StatusEffectKind) {
+        self.0.retain(|effect| &effect.kind != kind);
+    }
+
+    pub fn tick_damages(&self) -> u8 {
+        self.0.iter().fold(0, |total, effect| total.saturating_add(effect.tick_damages()))
+    }
+
+    // Effects that will wear off on the next tick
+    pub fn expiring(&self) -> Vec<StatusEffectKind> {
+        self.0.iter()
+            .filter(|effect| !effect.applied_this_turn && effect.turns_remaining <= 1)
+            .map(|effect| effect.kind.clone())
+            .collect()
+    }
+
+    pub fn tick(&mut self) {
+        for effect in self.0.iter_mut() {
+            if effect.applied_this_turn {
+                effect.applied_this_turn = false;
+            } else {
+                effect.turns_remaining = effect.turns_remaining.saturating_sub(1);
+            }
+        }
+        self.0.retain(|effect| effect.turns_remaining > 0);
+    }
+
+    pub fn retain_lingering(&mut self) {
+        self.0.retain(|effect| effect.kind.lingers());
+    }
+
+    // Rest heals wounds, a draught keeps until the next fight
+    pub fn cure_lingering(&mut self) {
+        self.0.retain(|effect| !effect.kind.lingers());
+    }
+}
+
+impl StatModifier for StatusEffects {
+    fn value(&self, stat: &StatKind) -> i8 {
+        self.0.iter().map(|effect| effect.value(stat)).sum()
+    }
+}
+
+pub trait HasStatusEffects {
+    fn status_effects(&self) -> &StatusEffects;
+}
+
+pub trait HasMutableStatusEffects: HasStatusEffects {
+    fn status_effects_mut(&mut self) -> &mut StatusEffects;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stackable_effects_stack_up_to_their_cap() {
+        let mut effects = StatusEffects::default();
+        effects.add(StatusEffect::with_stacks(StatusEffectKind::Bleeding, 2));
+        effects.add(StatusEffect::with_stacks(StatusEffectKind::Bleeding, 2));
+        assert_eq!(effects.list().len(), 1);
+        assert_eq!(effects.list()[0].stacks(), StatusEffectKind::Bleeding.max_stacks());
+        assert_eq!(effects.tick_damages(), 3);
+    }
+
+    #[test]
+    fn other_effects_only_refresh_duration() {
+        let mut effects = StatusEffects::default();
+        effects.add(StatusEffect::new(StatusEffectKind::Blinded));
+        effects.tick();
+        assert_eq!(effects.list()[0].turns_remaining(), 1);
+        assert_eq!(effects.expiring(), vec![StatusEffectKind::Blinded]);
+        effects.add(StatusEffect::new(StatusEffectKind::Blinded));
+        assert_eq!(effects.list()[0].stacks(), 1);
+        assert_eq!(effects.list()[0].turns_remaining(), StatusEffectKind::Blinded.duration());
+        assert_eq!(effects.value(&StatKind::Parry), -4);
+    }
+
+    #[test]
+    fn effects_wear_off_after_their_duration() {
+        let mut effects = StatusEffects::default();
+        effects.inflict(StatusEffect::new(StatusEffectKind::Stunned));
+        effects.inflict(StatusEffect::new(StatusEffectKind::Poisoned));
+        // The turn the effects were applied in does not count
+        effects.tick();
+        assert!(effects.has(&StatusEffectKind::Stunned));
+        assert!(effects.expiring().contains(&StatusEffectKind::Stunned));
+        effects.tick();
+        assert!(!effects.has(&StatusEffectKind::Stunned));
+        assert!(effects.has(&StatusEffectKind::Poisoned));
+        effects.add(StatusEffect::new(StatusEffectKind::Enraged));
+        effects.retain_lingering();
+        assert!(!effects.has(&StatusEffectKind::Enraged));
+        effects.relieve(&StatusEffectKind::Poisoned);
+        assert!(effects.list().is_empty());
+    }
+}
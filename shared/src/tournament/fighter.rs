@@ -8,7 +8,6 @@ use crate::assault::clumsiness::ResolveClumsiness;
 use crate::assault::common_traits::{DealDamages, ReduceDamages, ResolveBreakWeapon, ResolveDropWeapon, ResolveGougeRandomEye, ResolveMissAssaults, TakeDamage};
 use crate::assault::critical_hit::{DealCriticalHit, ResolveCriticalHit, ResolveCriticalHitSelf};
 use crate::assault::critical_parry::{DealCriticalParry, ResolveCriticalParry};
-use crate::assault::duration_damages::TakeDurationDamages;
 use crate::assault::end_turn_consequences::EndTurnConsequencesBuilder;
 use crate::assault::parry_attempt::{ParryAttempt, ParryThreshold};
 use crate::assault::parry_clumsiness::ResolveParryClumsiness;
@@ -17,34 +16,46 @@ use crate::assault::parry_success::ResolveParrySuccess;
 use crate::assault::{
     assailant::Assailant,
     assault_order_comparable::AssaultOrderComparable,
-    duration_damages::DurationDamages,
 };
+use crate::consumable::HasTonic;
+use crate::equipment::shield::{CanHoldShield, OptionalMutableShield, Shield};
 use crate::equipment::weapon::{OptionalMutableWeapon, Weapon};
 use crate::health::{Health, IsDead, IsUnconscious, MutableHealth};
 use crate::inventory::{HasInventory, HasMutableInventory, Inventory};
 use crate::knock_out::KnockOut;
 use crate::name::Name;
+use crate::stamina::{HasMutableStamina, HasStamina, Stamina};
 use crate::stats::{StatKind, StatModifier, Stats, StatsManager};
+use crate::status_effect::{HasMutableStatusEffects, HasStatusEffects, StatusEffects};
 use crate::temporary_handicap::{OptionalAssaultMisses, OptionalMutableAssaultMisses, OptionalMutableParryMisses, OptionalParryMisses, TemporaryHandicap};
 use crate::unique_entity::UniqueEntity;
 use crate::warrior::body::{Body, HasBody, HasMutableBody};
-use crate::warrior::Warrior;
+use crate::warrior::{HasMutableProficiencies, HasPerks, HasPersonality, HasProficiencies, HasStance, HasTactics, Perks, Personality, Proficiencies, Stance, Tactics, Warrior};
 
 pub struct Fighter {
     uuid: Uuid,
     name: String,
     health: Health,
     weapon: Option<Weapon>,
+    shield: Option<Shield>,
     // current_tournament: Option<Uuid>,
     assault_misses: Option<TemporaryHandicap>,
     parry_misses: Option<TemporaryHandicap>,
     body: Body,
-    duration_damages: Vec<DurationDamages>,
+    status_effects: StatusEffects,
+    stamina: Stamina,
+    perks: Perks,
+    personality: Personality,
+    proficiencies: Proficiencies,
+    // Proficiency earned during the fight, only counts once it is over
+    proficiency_gains: Proficiencies,
     stats: StatsManager,
     is_unconscious: bool,
     // last_passive_heal: i64,
     // experience: u64,
     inventory: Inventory,
+    tactics: Tactics,
+    stance: Stance,
 }
 
 impl Fighter {
@@ -53,7 +64,7 @@ impl Fighter {
         Health,
         Option<Weapon>,
         Body,
-        Vec<DurationDamages>,
+        StatusEffects,
         Inventory,
     ) {
         (
@@ -61,16 +72,37 @@ impl Fighter {
             self.health,
             self.weapon,
             self.body,
-            self.duration_damages,
+            self.status_effects,
             self.inventory,
         )
     }
 
+    // server only
+    pub fn practice(&mut self, xp: u32) {
+        if let Some(weapon) = &self.weapon {
+            self.proficiency_gains.gain(weapon.kind(), xp);
+        }
+    }
+
+    // Follows the warrior's stance switches for the coming turn
+    pub fn adapt_stance(&mut self) {
+        self.stance = self.tactics.stance_for(self);
+    }
+
+    // client only, replays the stance recorded in a turn summary
+    pub fn set_stance(&mut self, stance: Stance) {
+        self.stance = stance;
+    }
+
     pub fn consume(self, warrior: &mut Warrior) -> Inventory {
         *warrior.health_mut() = self.health;
         *warrior.weapon_mut() = self.weapon;
+        *warrior.shield_mut() = self.shield;
         *warrior.body_mut() = self.body;
-        *warrior.duration_damages_mut() = self.duration_damages;
+        *warrior.status_effects_mut() = self.status_effects;
+        warrior.status_effects_mut().retain_lingering();
+        *warrior.stamina_mut() = self.stamina;
+        warrior.proficiencies_mut().merge(&self.proficiency_gains);
         self.inventory
     }
 }
@@ -82,34 +114,61 @@ impl From<&Warrior> for Fighter {
             name: warrior.name().to_string(),
             health: warrior.health().clone(),
             weapon: warrior.weapon().clone(),
+            shield: warrior.shield().clone(),
             assault_misses: None,
             parry_misses: None,
             body: warrior.body().clone(),
-            duration_damages: warrior.duration_damages().clone(),
-            stats: warrior.stats().clone(),
+            status_effects: warrior.status_effects().clone(),
+            stamina: warrior.stamina().clone(),
+            perks: warrior.perks().clone(),
+            personality: warrior.personality().clone(),
+            proficiencies: warrior.proficiencies().clone(),
+            proficiency_gains: Proficiencies::default(),
+            stats: match warrior.tonic() {
+                Some(tonic) => warrior.stats().modified(tonic),
+                None => warrior.stats().clone(),
+            },
             is_unconscious: warrior.is_unconscious(),
             inventory: Inventory::new(),
+            tactics: warrior.tactics().clone(),
+            stance: warrior.tactics().stance().clone(),
         }
     }
 }
 
 impl AssaultOrderComparable for Fighter {
     fn assault_order_comparable(&self) -> u8 {
+        let perks = self.perks.modifier(&self.weapon);
         let mut modifiers: Vec<Box<&dyn StatModifier>> = vec![Box::new(&self.body)];
         if let Some(weapon) = &self.weapon {
             modifiers.push(Box::new(weapon))
         }
+        modifiers.push(Box::new(&self.status_effects));
+        modifiers.push(Box::new(&perks));
+        modifiers.push(Box::new(&self.personality));
         self.stats.stat(&modifiers, &StatKind::Courage).value()
     }
 }
 
 impl EndTurnConsequencesBuilder for Fighter {}
-impl TakeDurationDamages for Fighter {
-    fn duration_damages(&self) -> &Vec<DurationDamages> {
-        &self.duration_damages
+impl HasStatusEffects for Fighter {
+    fn status_effects(&self) -> &StatusEffects {
+        &self.status_effects
+    }
+}
+impl HasMutableStatusEffects for Fighter {
+    fn status_effects_mut(&mut self) -> &mut StatusEffects {
+        &mut self.status_effects
     }
-    fn duration_damages_mut(&mut self) -> &mut Vec<DurationDamages> {
-        &mut self.duration_damages
+}
+impl HasStamina for Fighter {
+    fn stamina(&self) -> &Stamina {
+        &self.stamina
+    }
+}
+impl HasMutableStamina for Fighter {
+    fn stamina_mut(&mut self) -> &mut Stamina {
+        &mut self.stamina
     }
 }
 impl MutableHealth for Fighter {
@@ -127,25 +186,47 @@ impl KnockOut for Fighter {
 }
 impl AttackThreshold for Fighter {
     fn attack_threshold(&self) -> u8 {
+        let perks = self.perks.modifier(&self.weapon);
+        let proficiency = self.proficiencies.rank(&self.weapon);
         let mut modifiers: Vec<Box<&dyn StatModifier>> = vec![Box::new(&self.body)];
         if let Some(weapon) = self.weapon() {
             modifiers.push(Box::new(weapon));
         }
+        if let Some(shield) = self.usable_shield() {
+            modifiers.push(Box::new(shield));
+        }
+        modifiers.push(Box::new(&self.stance));
+        modifiers.push(Box::new(&self.status_effects));
+        modifiers.push(Box::new(&perks));
+        modifiers.push(Box::new(&self.stamina));
+        modifiers.push(Box::new(&proficiency));
+        modifiers.push(Box::new(&self.personality));
         self.stats.stat(&modifiers, &StatKind::Attack).value()
     }
 }
 impl ParryThreshold for Fighter {
     fn parry_threshold(&self) -> u8 {
+        let perks = self.perks.modifier(&self.weapon);
+        let proficiency = self.proficiencies.rank(&self.weapon);
         let mut modifiers: Vec<Box<&dyn StatModifier>> = vec![Box::new(&self.body)];
         if let Some(weapon) = self.weapon() {
             modifiers.push(Box::new(weapon));
         }
+        if let Some(shield) = self.usable_shield() {
+            modifiers.push(Box::new(shield));
+        }
+        modifiers.push(Box::new(&self.stance));
+        modifiers.push(Box::new(&self.status_effects));
+        modifiers.push(Box::new(&perks));
+        modifiers.push(Box::new(&self.stamina));
+        modifiers.push(Box::new(&proficiency));
+        modifiers.push(Box::new(&self.personality));
         self.stats.stat(&modifiers, &StatKind::Parry).value()
     }
 }
 impl ReduceDamages for Fighter {
     fn reduce_damages(&self, damages: u8) -> u8 {
-        self.body().reduce_damages(damages)
+        self.body().reduce_damages(damages).saturating_sub(self.perks.natural_armor())
     }
 }
 impl DealDamages for Fighter {
@@ -153,13 +234,20 @@ impl DealDamages for Fighter {
         if let Some(weapon) = self.weapon() {
             let mut damages = weapon.deal_damages();
             let str = self.stats.stat(
-                &[Box::new(weapon), Box::new(&self.body)],
+                &[
+                    Box::new(weapon),
+                    Box::new(&self.body),
+                    Box::new(&self.status_effects),
+                    Box::new(&self.personality),
+                ],
                 &StatKind::Strength,
             );
             if str.value() < 8 {
                 damages -= 1;
             }
             damages
+                .saturating_add(self.stamina.exhaustion_damages())
+                .saturating_add_signed(self.stance.damages_modifier())
         } else {
             0
         }
@@ -224,6 +312,24 @@ impl OptionalMutableWeapon for Fighter {
         &mut self.weapon
     }
 }
+impl OptionalMutableShield for Fighter {
+    fn shield(&self) -> &Option<Shield> {
+        &self.shield
+    }
+    fn shield_mut(&mut self) -> &mut Option<Shield> {
+        &mut self.shield
+    }
+}
+impl HasTactics for Fighter {
+    fn tactics(&self) -> &Tactics {
+        &self.tactics
+    }
+}
+impl HasStance for Fighter {
+    fn stance(&self) -> &Stance {
+        &self.stance
+    }
+}
 impl HasInventory for Fighter {
     fn inventory(&self) -> &Inventory {
         &self.inventory
@@ -239,7 +345,11 @@ impl ResolveGougeRandomEye for Fighter {}
 impl ResolveBreakWeapon for Fighter {}
 impl ResolveDropWeapon for Fighter {}
 impl ResolveMissAssaults for Fighter {}
-impl ResolveAttackSuccess for Fighter {}
+impl ResolveAttackSuccess for Fighter {
+    fn natural_armor(&self) -> u8 {
+        self.perks.natural_armor()
+    }
+}
 impl ResolveClumsiness for Fighter {}
 impl DealCriticalHit for Fighter {}
 impl ResolveCriticalHit for Fighter {}
@@ -252,7 +362,11 @@ impl TakeDamage for Fighter {}
 impl ResolveParrySuccess for Fighter {}
 impl CanAttack for Fighter {}
 impl CanBeAttacked for Fighter {}
-impl AttackAttempt for Fighter {}
+impl AttackAttempt for Fighter {
+    fn attack_clumsiness_threshold(&self) -> u8 {
+        self.proficiencies.clumsiness_threshold(self.stance.clumsiness_threshold(), &self.weapon)
+    }
+}
 impl ParryAttempt for Fighter {}
 impl CanParry for Fighter {}
 
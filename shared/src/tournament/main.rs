@@ -94,15 +94,17 @@ impl Tournament {
     }
 
     // server only
+    // Returns how many items ended up in the contestant's stash
     pub fn add_to_contestant_inventory(
         &mut self,
         warrior_uuid: &Uuid,
         inventory: Inventory,
-    ) {
+    ) -> usize {
         if let Some(existing_inventory) = self.contestants_inventories.get_mut(warrior_uuid) {
-            existing_inventory.join(inventory);
+            existing_inventory.join(inventory)
         } else {
             self.contestants_inventories.insert(warrior_uuid.clone(), inventory);
+            0
         }
     }
 
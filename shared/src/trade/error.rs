@@ -0,0 +1,44 @@
+use std::error::Error;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TradeErrorKind {
+    SelfTrade,
+    EmptyOffer,
+    NotRecipient,
+    NotSender,
+    NotPending,
+    AlreadyAccepted,
+}
+
+#[derive(Debug)]
+pub struct TradeError {
+    kind: TradeErrorKind,
+    message: String,
+}
+
+impl TradeError {
+    pub fn new(kind: TradeErrorKind) -> Self {
+        let message = match kind {
+            TradeErrorKind::SelfTrade => "Cannot trade with yourself".to_string(),
+            TradeErrorKind::EmptyOffer => "Offer has neither items nor gold".to_string(),
+            TradeErrorKind::NotRecipient => "Offer is not addressed to this player".to_string(),
+            TradeErrorKind::NotSender => "Offer was not made by this player".to_string(),
+            TradeErrorKind::NotPending => "Offer was already answered".to_string(),
+            TradeErrorKind::AlreadyAccepted => "Offer was already accepted".to_string(),
+        };
+        Self { kind, message }
+    }
+
+    pub fn kind(&self) -> &TradeErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for TradeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for TradeError {}
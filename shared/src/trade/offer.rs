@@ -0,0 +1,233 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::inventory::Inventory;
+use crate::unique_entity::UniqueEntity;
+
+use super::{TradeError, TradeErrorKind};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum TradeStatus {
+    Pending,
+    Declined,
+    Accepted,
+}
+
+// Body of a trade proposal
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NewTradeOffer {
+    pub recipient_username: String,
+    pub slot_uuids: Vec<Uuid>,
+    pub gold: u32,
+    // Gold asked in return, an offer asking nothing is a gift
+    pub requested_gold: u32,
+}
+
+// Offered items and gold are held in escrow until the offer is accepted or reclaimed
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TradeOffer {
+    uuid: Uuid,
+    sender: Uuid,
+    sender_name: String,
+    recipient: Uuid,
+    recipient_name: String,
+    offered: Inventory,
+    requested_gold: u32,
+    status: TradeStatus,
+    created_at: i64,
+    completed_at: Option<i64>,
+}
+
+impl TradeOffer {
+    pub fn new(
+        sender: (Uuid, String),
+        recipient: (Uuid, String),
+        offered: Inventory,
+        requested_gold: u32,
+    ) -> Result<Self, TradeError> {
+        if sender.0 == recipient.0 {
+            return Err(TradeError::new(TradeErrorKind::SelfTrade));
+        }
+        if offered.items().is_empty() && offered.gold() == 0 {
+            return Err(TradeError::new(TradeErrorKind::EmptyOffer));
+        }
+        Ok(Self {
+            uuid: Uuid::new_v4(),
+            sender: sender.0,
+            sender_name: sender.1,
+            recipient: recipient.0,
+            recipient_name: recipient.1,
+            offered,
+            requested_gold,
+            status: TradeStatus::Pending,
+            created_at: Utc::now().timestamp(),
+            completed_at: None,
+        })
+    }
+
+    pub fn sender(&self) -> &Uuid {
+        &self.sender
+    }
+
+    pub fn sender_name(&self) -> &str {
+        &self.sender_name
+    }
+
+    pub fn recipient(&self) -> &Uuid {
+        &self.recipient
+    }
+
+    pub fn recipient_name(&self) -> &str {
+        &self.recipient_name
+    }
+
+    pub fn offered(&self) -> &Inventory {
+        &self.offered
+    }
+
+    pub fn requested_gold(&self) -> u32 {
+        self.requested_gold
+    }
+
+    pub fn is_gift(&self) -> bool {
+        self.requested_gold == 0
+    }
+
+    pub fn status(&self) -> &TradeStatus {
+        &self.status
+    }
+
+    pub fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    pub fn completed_at(&self) -> Option<i64> {
+        self.completed_at
+    }
+
+    pub fn involves(&self, player_uuid: &Uuid) -> bool {
+        self.sender == *player_uuid || self.recipient == *player_uuid
+    }
+
+    pub fn check_answer(&self, player_uuid: &Uuid) -> Result<(), TradeError> {
+        if self.recipient != *player_uuid {
+            return Err(TradeError::new(TradeErrorKind::NotRecipient));
+        }
+        if self.status != TradeStatus::Pending {
+            return Err(TradeError::new(TradeErrorKind::NotPending));
+        }
+        Ok(())
+    }
+
+    // Pending and declined offers can be taken back by their sender
+    pub fn check_reclaim(&self, player_uuid: &Uuid) -> Result<(), TradeError> {
+        if self.sender != *player_uuid {
+            return Err(TradeError::new(TradeErrorKind::NotSender));
+        }
+        if self.status == TradeStatus::Accepted {
+            return Err(TradeError::new(TradeErrorKind::AlreadyAccepted));
+        }
+        Ok(())
+    }
+
+    // server only
+    // Returns the escrow, the offer keeps a copy of it as history
+    pub fn accept(&mut self, player_uuid: &Uuid) -> Result<Inventory, TradeError> {
+        self.check_answer(player_uuid)?;
+        self.status = TradeStatus::Accepted;
+        self.completed_at = Some(Utc::now().timestamp());
+        Ok(self.offered.clone())
+    }
+
+    // server only
+    pub fn decline(&mut self, player_uuid: &Uuid) -> Result<(), TradeError> {
+        self.check_answer(player_uuid)?;
+        self.status = TradeStatus::Declined;
+        Ok(())
+    }
+
+    // server only
+    pub fn reclaim(self, player_uuid: &Uuid) -> Result<Inventory, TradeError> {
+        self.check_reclaim(player_uuid)?;
+        Ok(self.offered)
+    }
+}
+
+impl UniqueEntity for TradeOffer {
+    fn uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offer(sender: Uuid, recipient: Uuid, gold: u32) -> Result<TradeOffer, TradeError> {
+        let mut offered = Inventory::new();
+        offered.add_gold(gold);
+        TradeOffer::new(
+            (sender, String::from("sender")),
+            (recipient, String::from("recipient")),
+            offered,
+            0,
+        )
+    }
+
+    #[test]
+    fn cannot_trade_with_yourself_or_offer_nothing() {
+        let player = Uuid::new_v4();
+        assert_eq!(
+            offer(player, player, 10).unwrap_err().kind(),
+            &TradeErrorKind::SelfTrade,
+        );
+        assert_eq!(
+            offer(player, Uuid::new_v4(), 0).unwrap_err().kind(),
+            &TradeErrorKind::EmptyOffer,
+        );
+    }
+
+    #[test]
+    fn only_recipient_answers_once() {
+        let sender = Uuid::new_v4();
+        let recipient = Uuid::new_v4();
+        let mut offer = offer(sender, recipient, 10).unwrap();
+        assert!(offer.is_gift());
+        assert_eq!(
+            offer.accept(&sender).unwrap_err().kind(),
+            &TradeErrorKind::NotRecipient,
+        );
+        assert_eq!(offer.accept(&recipient).unwrap().gold(), 10);
+        assert!(offer.completed_at().is_some());
+        assert_eq!(
+            offer.decline(&recipient).unwrap_err().kind(),
+            &TradeErrorKind::NotPending,
+        );
+    }
+
+    #[test]
+    fn declined_offer_is_reclaimed_by_sender() {
+        let sender = Uuid::new_v4();
+        let recipient = Uuid::new_v4();
+        let mut offer = offer(sender, recipient, 10).unwrap();
+        offer.decline(&recipient).unwrap();
+        assert_eq!(
+            offer.check_reclaim(&recipient).unwrap_err().kind(),
+            &TradeErrorKind::NotSender,
+        );
+        assert_eq!(offer.reclaim(&sender).unwrap().gold(), 10);
+    }
+
+    #[test]
+    fn accepted_offer_cannot_be_reclaimed() {
+        let sender = Uuid::new_v4();
+        let recipient = Uuid::new_v4();
+        let mut offer = offer(sender, recipient, 10).unwrap();
+        offer.accept(&recipient).unwrap();
+        assert_eq!(
+            offer.reclaim(&sender).unwrap_err().kind(),
+            &TradeErrorKind::AlreadyAccepted,
+        );
+    }
+}
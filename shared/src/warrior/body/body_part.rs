@@ -5,6 +5,9 @@ use crate::equipment::protection::{CanWearProtection, OptionalMutableProtection,
 use crate::random::Random;
 use crate::stats::{StatKind, StatModifier};
 
+use super::prosthetic::Prosthetic;
+use super::recovery::recovery_hours;
+
 pub trait OptionalBodyPart {
     fn body_part(&self, body_part_kind: &BodyPartKind) -> &Option<BodyPart>;
 }
@@ -54,8 +57,11 @@ pub trait OptionalMutableBodyPart: OptionalBodyPart {
         }
     }
     fn break_part(&mut self, body_part_kind: &BodyPartKind) {
+        // Prosthetics do not break
         if let Some(part) = self.body_part_mut(body_part_kind) {
-            part.set_is_broken(true)
+            if part.prosthetic().is_none() {
+                part.set_is_broken(true)
+            }
         }
     }
 }
@@ -65,6 +71,11 @@ pub struct BodyPart {
     kind: BodyPartKind,
     protection: Option<Protection>,
     is_broken: bool,
+    // Hours left before a broken part heals on its own
+    #[serde(default)]
+    recovery: u32,
+    #[serde(default)]
+    prosthetic: Option<Prosthetic>,
 }
 
 impl BodyPart {
@@ -81,11 +92,50 @@ impl BodyPart {
             kind,
             protection: None,
             is_broken: false,
+            recovery: 0,
+            prosthetic: None,
         }
     }
 
+    pub fn with_prosthetic(kind: BodyPartKind, prosthetic: Prosthetic) -> Self {
+        Self {
+            prosthetic: Some(prosthetic),
+            ..Self::new(kind)
+        }
+    }
+
+    pub fn prosthetic(&self) -> &Option<Prosthetic> {
+        &self.prosthetic
+    }
+
     pub fn set_is_broken(&mut self, is_broken: bool) {
         self.is_broken = is_broken;
+        self.recovery = match is_broken {
+            true => recovery_hours(&self.kind),
+            false => 0,
+        };
+    }
+
+    // Parts broken in saves predating recovery start it over
+    pub fn recovery(&self) -> u32 {
+        if self.is_broken && self.recovery == 0 {
+            recovery_hours(&self.kind)
+        } else {
+            self.recovery
+        }
+    }
+
+    pub fn set_recovery(&mut self, hours: u32) {
+        self.recovery = hours;
+        if self.recovery == 0 {
+            self.is_broken = false;
+        }
+    }
+
+    pub fn recover(&mut self, hours: u32) {
+        if self.is_broken {
+            self.set_recovery(self.recovery().saturating_sub(hours));
+        }
     }
 }
 
@@ -256,7 +306,7 @@ impl StatModifier for BodyPart {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub enum BodyPartKind {
     Eye(BodySide),
     Finger(BodySide, FingerName),
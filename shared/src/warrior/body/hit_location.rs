@@ -0,0 +1,84 @@
+use crate::dice::Dice;
+
+use super::body_part::{BodyPartKind, BodySide, OptionalBodyPart};
+use super::Body;
+
+// Weighted over the protectable body parts, the torso being the easiest target
+pub fn hit_location(face: u8) -> BodyPartKind {
+    match face {
+        1 | 2 | 20 => BodyPartKind::Head,
+        3..=9 => BodyPartKind::Torso,
+        10 => BodyPartKind::Arm(BodySide::Left),
+        11 => BodyPartKind::Arm(BodySide::Right),
+        12 => BodyPartKind::Hand(BodySide::Left),
+        13 => BodyPartKind::Hand(BodySide::Right),
+        14 | 15 => BodyPartKind::Leg(BodySide::Left),
+        16 | 17 => BodyPartKind::Leg(BodySide::Right),
+        18 => BodyPartKind::Foot(BodySide::Left),
+        19 => BodyPartKind::Foot(BodySide::Right),
+        other => panic!("D20 roll resulted in {other}"),
+    }
+}
+
+impl Body {
+    // A blow aimed at a missing body part lands on the torso
+    pub fn roll_hit_location(&self) -> BodyPartKind {
+        let location = hit_location(Dice::D20.roll());
+        if self.body_part(&location).is_some() {
+            location
+        } else {
+            BodyPartKind::Torso
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::warrior::body::body_part::OptionalMutableBodyPart;
+
+    use super::*;
+
+    #[test]
+    fn every_face_hits_a_protectable_body_part() {
+        for face in 1..=20 {
+            assert!(!matches!(
+                hit_location(face),
+                BodyPartKind::Eye(_)
+                    | BodyPartKind::Finger(_, _)
+                    | BodyPartKind::Knee(_)
+                    | BodyPartKind::Genitals
+            ));
+        }
+    }
+
+    #[test]
+    fn torso_is_the_most_likely_location() {
+        let torso = (1..=20)
+            .filter(|face| matches!(hit_location(*face), BodyPartKind::Torso))
+            .count();
+        let head = (1..=20)
+            .filter(|face| matches!(hit_location(*face), BodyPartKind::Head))
+            .count();
+        assert_eq!(torso, 7);
+        assert_eq!(head, 3);
+    }
+
+    #[test]
+    fn missing_body_part_redirects_to_torso() {
+        let mut body = Body::new();
+        let mut severed = vec![];
+        for side in [BodySide::Left, BodySide::Right] {
+            body.remove_part(&BodyPartKind::Arm(side.clone()), &mut severed);
+            body.remove_part(&BodyPartKind::Leg(side), &mut severed);
+        }
+        for _ in 0..200 {
+            assert!(!matches!(
+                body.roll_hit_location(),
+                BodyPartKind::Arm(_)
+                    | BodyPartKind::Hand(_)
+                    | BodyPartKind::Leg(_)
+                    | BodyPartKind::Foot(_)
+            ));
+        }
+    }
+}
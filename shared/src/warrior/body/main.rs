@@ -14,6 +14,7 @@ use super::body_part::{
     PROTECTABLE_BODY_PARTS,
 };
 use super::injury::Injuries;
+use super::prosthetic::Prosthetic;
 
 pub trait HasBody {
     fn body(&self) -> &Body;
@@ -184,6 +185,107 @@ impl OptionalBodyPart for Body {
     }
 }
 
+impl Body {
+    // Only the protection worn on the struck body part absorbs the blow
+    pub fn reduce_damages_at(&self, damages: u8, location: &BodyPartKind) -> u8 {
+        match self.body_part(location) {
+            Some(body_part) => match body_part.protection() {
+                Some(protection) => protection.reduce_damages(damages),
+                None => damages,
+            },
+            None => damages,
+        }
+    }
+
+    pub fn broken_parts(&self) -> Vec<&BodyPart> {
+        self.parts()
+            .into_iter()
+            .flatten()
+            .filter(|part| part.is_broken())
+            .collect()
+    }
+
+    // Each fitted prosthetic once, even when it replaces several parts
+    pub fn prosthetics(&self) -> Vec<&Prosthetic> {
+        self.parts()
+            .into_iter()
+            .flatten()
+            .filter_map(|part| match part.prosthetic() {
+                Some(prosthetic) if prosthetic.replaces(part.kind()) => Some(prosthetic),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // Severed parts are gone for good, only broken ones recover
+    pub fn recover(&mut self, hours: u32) {
+        for part in self.parts_mut().into_iter().flatten() {
+            part.recover(hours);
+        }
+    }
+
+    fn parts(&self) -> [&Option<BodyPart>; 25] {
+        [
+            &self.left_eye,
+            &self.right_eye,
+            &self.head,
+            &self.torso,
+            &self.left_hand,
+            &self.right_hand,
+            &self.left_arm,
+            &self.right_arm,
+            &self.left_foot,
+            &self.right_foot,
+            &self.left_knee,
+            &self.right_knee,
+            &self.left_leg,
+            &self.right_leg,
+            &self.genitals,
+            &self.left_thumb,
+            &self.right_thumb,
+            &self.left_pointer_finger,
+            &self.right_pointer_finger,
+            &self.left_middle_finger,
+            &self.right_middle_finger,
+            &self.left_ring_finger,
+            &self.right_ring_finger,
+            &self.left_pinky_finger,
+            &self.right_pinky_finger,
+        ]
+    }
+
+    fn parts_mut(&mut self) -> [&mut Option<BodyPart>; 25] {
+        [
+            &mut self.left_eye,
+            &mut self.right_eye,
+            &mut self.head,
+            &mut self.torso,
+            &mut self.left_hand,
+            &mut self.right_hand,
+            &mut self.left_arm,
+            &mut self.right_arm,
+            &mut self.left_foot,
+            &mut self.right_foot,
+            &mut self.left_knee,
+            &mut self.right_knee,
+            &mut self.left_leg,
+            &mut self.right_leg,
+            &mut self.genitals,
+            &mut self.left_thumb,
+            &mut self.right_thumb,
+            &mut self.left_pointer_finger,
+            &mut self.right_pointer_finger,
+            &mut self.left_middle_finger,
+            &mut self.right_middle_finger,
+            &mut self.left_ring_finger,
+            &mut self.right_ring_finger,
+            &mut self.left_pinky_finger,
+            &mut self.right_pinky_finger,
+        ]
+    }
+}
+
+// Blows with no recorded location go through every piece of armor
 impl ReduceDamages for Body {
     fn reduce_damages(&self, damages: u8) -> u8 {
         let mut final_damages = damages;
@@ -209,6 +311,9 @@ impl StatModifier for Body {
                 modifier += body_part.value(stat);
             }
         }
+        for prosthetic in self.prosthetics() {
+            modifier += prosthetic.value(stat);
+        }
         modifier
     }
 }
@@ -0,0 +1,228 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::{GoldValue, Inventory};
+use crate::stats::{StatKind, StatModifier};
+
+use super::body_part::{BodyPart, BodyPartKind, BodySide, OptionalBodyPart, OptionalMutableBodyPart, ALL_FINGERS};
+use super::Body;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum Prosthetic {
+    PegLeg,
+    HookHand,
+    GlassEye,
+}
+
+pub const PROSTHETICS: [Prosthetic; 3] = [
+    Prosthetic::PegLeg,
+    Prosthetic::HookHand,
+    Prosthetic::GlassEye,
+];
+
+impl Prosthetic {
+    pub fn body_part_kind(&self, side: &BodySide) -> BodyPartKind {
+        match self {
+            Prosthetic::PegLeg => BodyPartKind::Leg(side.clone()),
+            Prosthetic::HookHand => BodyPartKind::Hand(side.clone()),
+            Prosthetic::GlassEye => BodyPartKind::Eye(side.clone()),
+        }
+    }
+
+    pub fn replaces(&self, kind: &BodyPartKind) -> bool {
+        matches!(
+            (self, kind),
+            (Prosthetic::PegLeg, BodyPartKind::Leg(_)) |
+            (Prosthetic::HookHand, BodyPartKind::Hand(_)) |
+            (Prosthetic::GlassEye, BodyPartKind::Eye(_))
+        )
+    }
+
+    // A peg leg also stands for the knee and foot, a hook for the fingers
+    fn covered_parts(&self, side: &BodySide) -> Vec<BodyPartKind> {
+        let mut parts = vec![self.body_part_kind(side)];
+        match self {
+            Prosthetic::PegLeg => {
+                parts.push(BodyPartKind::Knee(side.clone()));
+                parts.push(BodyPartKind::Foot(side.clone()));
+            },
+            Prosthetic::HookHand => {
+                for finger_name in ALL_FINGERS {
+                    parts.push(BodyPartKind::Finger(side.clone(), finger_name));
+                }
+            },
+            Prosthetic::GlassEye => {},
+        }
+        parts
+    }
+
+    pub fn can_fit(&self, body: &Body, side: &BodySide) -> Result<(), ProstheticError> {
+        if body.body_part(&self.body_part_kind(side)).is_some() {
+            return Err(ProstheticError::new(ProstheticErrorKind::NotMissing));
+        }
+        if self == &Prosthetic::HookHand && body.body_part(&BodyPartKind::Arm(side.clone())).is_none() {
+            return Err(ProstheticError::new(ProstheticErrorKind::NothingToAttachTo));
+        }
+        Ok(())
+    }
+
+    // server only
+    pub fn fit(&self, body: &mut Body, side: &BodySide) -> Result<(), ProstheticError> {
+        self.can_fit(body, side)?;
+        for kind in self.covered_parts(side) {
+            let part = body.body_part_mut(&kind);
+            if part.is_none() {
+                part.replace(BodyPart::with_prosthetic(kind, self.clone()));
+            }
+        }
+        Ok(())
+    }
+}
+
+// Prosthetics are better than nothing, not as good as the real thing
+impl StatModifier for Prosthetic {
+    fn value(&self, stat: &StatKind) -> i8 {
+        match stat {
+            StatKind::Attack => match self {
+                Prosthetic::PegLeg => -2,
+                Prosthetic::HookHand => -1,
+                Prosthetic::GlassEye => -1,
+            },
+            StatKind::Parry => match self {
+                Prosthetic::PegLeg => -3,
+                Prosthetic::HookHand => -2,
+                Prosthetic::GlassEye => -1,
+            },
+            StatKind::Courage => 0,
+            StatKind::Dexterity => match self {
+                Prosthetic::PegLeg => -3,
+                Prosthetic::HookHand => -2,
+                Prosthetic::GlassEye => -1,
+            },
+            StatKind::Strength => match self {
+                Prosthetic::PegLeg => -1,
+                Prosthetic::HookHand => 0,
+                Prosthetic::GlassEye => 0,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProstheticRequest {
+    pub prosthetic: Prosthetic,
+    pub side: BodySide,
+}
+
+#[derive(Debug)]
+pub enum ProstheticErrorKind {
+    NotMissing,
+    NothingToAttachTo,
+    NotEnoughGold,
+}
+
+#[derive(Debug)]
+pub struct ProstheticError {
+    kind: ProstheticErrorKind,
+    message: String,
+}
+
+impl ProstheticError {
+    fn new(kind: ProstheticErrorKind) -> Self {
+        let message = match kind {
+            ProstheticErrorKind::NotMissing => "Body part is not missing".to_string(),
+            ProstheticErrorKind::NothingToAttachTo => "There is nothing to attach the prosthetic to".to_string(),
+            ProstheticErrorKind::NotEnoughGold => "Not enough gold to buy the prosthetic".to_string(),
+        };
+        Self { kind, message }
+    }
+
+    pub fn kind(&self) -> &ProstheticErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for ProstheticError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ProstheticError {}
+
+// server only
+pub fn fit_for_gold(
+    body: &mut Body,
+    request: &ProstheticRequest,
+    inventory: &mut Inventory,
+) -> Result<(), ProstheticError> {
+    request.prosthetic.can_fit(body, &request.side)?;
+    let cost = request.prosthetic.gold_value();
+    if inventory.gold() < cost {
+        return Err(ProstheticError::new(ProstheticErrorKind::NotEnoughGold));
+    }
+    inventory.remove_gold(cost);
+    request.prosthetic.fit(body, &request.side)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::warrior::body::injury::{Injuries, Injury};
+
+    use super::*;
+
+    #[test]
+    fn peg_leg_replaces_severed_leg() {
+        let mut body = Body::new();
+        let mut severed = vec![];
+        body.remove_part(&BodyPartKind::Leg(BodySide::Left), &mut severed);
+        let crippled_dexterity = body.value(&StatKind::Dexterity);
+        assert!(body.injuries().contains(&Injury::OneLegSevered(BodySide::Left)));
+
+        Prosthetic::PegLeg.fit(&mut body, &BodySide::Left).unwrap();
+        assert!(body.injuries().is_empty());
+        assert_eq!(body.prosthetics(), vec![&Prosthetic::PegLeg]);
+        assert_eq!(body.value(&StatKind::Dexterity), Prosthetic::PegLeg.value(&StatKind::Dexterity));
+        assert!(body.value(&StatKind::Dexterity) > crippled_dexterity);
+
+        body.break_part(&BodyPartKind::Leg(BodySide::Left));
+        assert!(body.broken_parts().is_empty());
+    }
+
+    #[test]
+    fn hook_hand_needs_an_arm() {
+        let mut body = Body::new();
+        let mut severed = vec![];
+        body.remove_part(&BodyPartKind::Arm(BodySide::Right), &mut severed);
+        assert!(matches!(
+            Prosthetic::HookHand.can_fit(&body, &BodySide::Right).unwrap_err().kind(),
+            ProstheticErrorKind::NothingToAttachTo,
+        ));
+        assert!(matches!(
+            Prosthetic::HookHand.can_fit(&body, &BodySide::Left).unwrap_err().kind(),
+            ProstheticErrorKind::NotMissing,
+        ));
+    }
+
+    #[test]
+    fn fit_for_gold_charges_the_inventory() {
+        let mut body = Body::new();
+        let mut severed = vec![];
+        body.remove_part(&BodyPartKind::Eye(BodySide::Left), &mut severed);
+        let mut inventory = Inventory::new();
+        let request = ProstheticRequest {
+            prosthetic: Prosthetic::GlassEye,
+            side: BodySide::Left,
+        };
+        assert!(matches!(
+            fit_for_gold(&mut body, &request, &mut inventory).unwrap_err().kind(),
+            ProstheticErrorKind::NotEnoughGold,
+        ));
+        inventory.add_gold(Prosthetic::GlassEye.gold_value());
+        fit_for_gold(&mut body, &request, &mut inventory).unwrap();
+        assert_eq!(inventory.gold(), 0);
+        assert!(body.body_part(&BodyPartKind::Eye(BodySide::Left)).is_some());
+    }
+}
@@ -0,0 +1,216 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+
+use super::body_part::{BodyPart, BodyPartKind, OptionalMutableBodyPart};
+use super::Body;
+
+// Gold the healer asks for each hour of recovery a treatment saves
+const HEALER_COST_PER_HOUR: u32 = 3;
+const HEALER_MIN_COST: u32 = 10;
+
+// Hours a broken part needs to heal without treatment
+pub fn recovery_hours(kind: &BodyPartKind) -> u32 {
+    match kind {
+        BodyPartKind::Knee(_) => 12,
+        BodyPartKind::Finger(_, _) => 12,
+        BodyPartKind::Foot(_) => 24,
+        BodyPartKind::Hand(_) => 24,
+        BodyPartKind::Genitals => 24,
+        BodyPartKind::Arm(_) => 48,
+        BodyPartKind::Leg(_) => 72,
+        BodyPartKind::Eye(_) => 24,
+        BodyPartKind::Head => 48,
+        BodyPartKind::Torso => 48,
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum Treatment {
+    // Halves the remaining recovery
+    Splint,
+    // Sets the bone or joint right away
+    Set,
+}
+
+pub const TREATMENTS: [Treatment; 2] = [Treatment::Splint, Treatment::Set];
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TreatmentRequest {
+    pub body_part: BodyPartKind,
+    pub treatment: Treatment,
+}
+
+#[derive(Debug)]
+pub enum TreatmentErrorKind {
+    PartSevered,
+    NotInjured,
+    NoEffect,
+    NotEnoughGold,
+}
+
+#[derive(Debug)]
+pub struct TreatmentError {
+    kind: TreatmentErrorKind,
+    message: String,
+}
+
+impl TreatmentError {
+    fn new(kind: TreatmentErrorKind) -> Self {
+        let message = match kind {
+            TreatmentErrorKind::PartSevered => "Severed parts can not be healed".to_string(),
+            TreatmentErrorKind::NotInjured => "Body part is not injured".to_string(),
+            TreatmentErrorKind::NoEffect => "Treatment would not shorten the recovery".to_string(),
+            TreatmentErrorKind::NotEnoughGold => "Not enough gold to pay the healer".to_string(),
+        };
+        Self { kind, message }
+    }
+
+    pub fn kind(&self) -> &TreatmentErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for TreatmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for TreatmentError {}
+
+impl Treatment {
+    pub fn saved_hours(&self, part: &BodyPart) -> u32 {
+        match self {
+            Treatment::Splint => part.recovery() / 2,
+            Treatment::Set => part.recovery(),
+        }
+    }
+
+    pub fn cost(&self, part: &BodyPart) -> u32 {
+        if !part.is_broken() || self.saved_hours(part) == 0 {
+            return 0;
+        }
+        (self.saved_hours(part) * HEALER_COST_PER_HOUR).max(HEALER_MIN_COST)
+    }
+}
+
+// server only
+// Returns the hours of recovery left after the treatment
+pub fn treat_for_gold(
+    body: &mut Body,
+    request: &TreatmentRequest,
+    inventory: &mut Inventory,
+) -> Result<u32, TreatmentError> {
+    let part = match body.body_part_mut(&request.body_part) {
+        None => return Err(TreatmentError::new(TreatmentErrorKind::PartSevered)),
+        Some(part) => part,
+    };
+    if !part.is_broken() {
+        return Err(TreatmentError::new(TreatmentErrorKind::NotInjured));
+    }
+    let saved_hours = request.treatment.saved_hours(part);
+    if saved_hours == 0 {
+        return Err(TreatmentError::new(TreatmentErrorKind::NoEffect));
+    }
+    let cost = request.treatment.cost(part);
+    if inventory.gold() < cost {
+        return Err(TreatmentError::new(TreatmentErrorKind::NotEnoughGold));
+    }
+    inventory.remove_gold(cost);
+    part.set_recovery(part.recovery() - saved_hours);
+    Ok(part.recovery())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::warrior::body::body_part::{BodySide, OptionalBodyPart};
+
+    use super::*;
+
+    fn broken_leg_body() -> Body {
+        let mut body = Body::new();
+        body.break_part(&BodyPartKind::Leg(BodySide::Left));
+        body
+    }
+
+    #[test]
+    fn broken_parts_recover_over_time() {
+        let mut body = broken_leg_body();
+        body.break_part(&BodyPartKind::Knee(BodySide::Right));
+        assert_eq!(body.broken_parts().len(), 2);
+        body.recover(recovery_hours(&BodyPartKind::Knee(BodySide::Right)));
+        assert_eq!(body.broken_parts().len(), 1);
+        body.recover(recovery_hours(&BodyPartKind::Leg(BodySide::Left)));
+        assert!(body.broken_parts().is_empty());
+    }
+
+    #[test]
+    fn splint_halves_recovery_and_set_heals() {
+        let mut body = broken_leg_body();
+        let mut inventory = Inventory::new();
+        inventory.add_gold(1000);
+        let mut request = TreatmentRequest {
+            body_part: BodyPartKind::Leg(BodySide::Left),
+            treatment: Treatment::Splint,
+        };
+        let full_recovery = recovery_hours(&request.body_part);
+        assert_eq!(treat_for_gold(&mut body, &request, &mut inventory).unwrap(), full_recovery / 2);
+        request.treatment = Treatment::Set;
+        assert_eq!(treat_for_gold(&mut body, &request, &mut inventory).unwrap(), 0);
+        assert!(body.broken_parts().is_empty());
+        assert!(inventory.gold() < 1000);
+        assert!(matches!(
+            treat_for_gold(&mut body, &request, &mut inventory).unwrap_err().kind(),
+            TreatmentErrorKind::NotInjured,
+        ));
+    }
+
+    #[test]
+    fn splint_that_saves_nothing_is_rejected() {
+        let mut body = broken_leg_body();
+        let kind = BodyPartKind::Leg(BodySide::Left);
+        body.body_part_mut(&kind).as_mut().unwrap().set_recovery(1);
+        let mut inventory = Inventory::new();
+        inventory.add_gold(1000);
+        let request = TreatmentRequest { body_part: kind, treatment: Treatment::Splint };
+        assert!(matches!(
+            treat_for_gold(&mut body, &request, &mut inventory).unwrap_err().kind(),
+            TreatmentErrorKind::NoEffect,
+        ));
+        assert_eq!(inventory.gold(), 1000);
+    }
+
+    #[test]
+    fn legacy_broken_parts_restart_their_recovery() {
+        let mut part: BodyPart = serde_json::from_str(
+            r#"{"kind":"Head","protection":null,"is_broken":true}"#,
+        ).unwrap();
+        assert_eq!(part.recovery(), recovery_hours(&BodyPartKind::Head));
+        part.recover(1);
+        assert!(part.is_broken());
+        assert_eq!(part.recovery(), recovery_hours(&BodyPartKind::Head) - 1);
+    }
+
+    #[test]
+    fn severed_parts_can_not_be_treated() {
+        let mut body = broken_leg_body();
+        let mut severed = vec![];
+        body.remove_part(&BodyPartKind::Leg(BodySide::Left), &mut severed);
+        assert!(body.body_part(&BodyPartKind::Leg(BodySide::Left)).is_none());
+        let mut inventory = Inventory::new();
+        inventory.add_gold(1000);
+        let request = TreatmentRequest {
+            body_part: BodyPartKind::Leg(BodySide::Left),
+            treatment: Treatment::Set,
+        };
+        assert!(matches!(
+            treat_for_gold(&mut body, &request, &mut inventory).unwrap_err().kind(),
+            TreatmentErrorKind::PartSevered,
+        ));
+        assert_eq!(inventory.gold(), 1000);
+    }
+}
@@ -1,5 +1,5 @@
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use uuid::Uuid;
 
 use crate::assault::assault_order_comparable::AssaultOrderComparable;
@@ -10,27 +10,38 @@ use crate::assault::clumsiness::ResolveClumsiness;
 use crate::assault::common_traits::{DealDamages, ReduceDamages, ResolveBreakWeapon, ResolveDropWeapon, ResolveGougeRandomEye, ResolveMissAssaults, TakeDamage};
 use crate::assault::critical_hit::{DealCriticalHit, ResolveCriticalHit, ResolveCriticalHitSelf};
 use crate::assault::critical_parry::{DealCriticalParry, ResolveCriticalParry};
-use crate::assault::duration_damages::{DurationDamages, TakeDurationDamages};
 use crate::assault::attack_not_possible::CanBeAttacked;
 use crate::assault::parry_attempt::{ParryAttempt, ParryThreshold};
 use crate::assault::parry_clumsiness::ResolveParryClumsiness;
 use crate::assault::parry_success::ResolveParrySuccess;
 use crate::assault::end_turn_consequences::EndTurnConsequencesBuilder;
+use crate::consumable::{HasMutableTonic, HasTonic, Tonic, UseConsumable};
 use crate::dice::Dice;
+use crate::equipment::loadout::{HasLoadouts, HasMutableLoadouts, Loadout};
+use crate::equipment::shield::{CanHoldShield, OptionalMutableShield, Shield};
 use crate::equipment::weapon::{OptionalMutableWeapon, Weapon};
-use crate::experience::{Experience, ExperienceError, ExperienceErrorKind, GainExperience};
-use crate::health::{Health, IsDead, IsUnconscious, MutableHealth, MutablePassiveHealing, PassiveHealing};
+use crate::experience::{Experience, ExperienceError, ExperienceErrorKind, GainExperience, ATTRIBUTE_POINTS_PER_LEVEL};
+use crate::health::{Activity, Health, IsDead, IsUnconscious, MutableHealth, MutablePassiveHealing, PassiveHealing};
 use crate::knock_out::KnockOut;
 use crate::name::Name;
 use crate::random::{Random, RandomDictionary};
+use crate::stamina::{HasMutableStamina, HasStamina, Stamina};
 use crate::stats::{StatKind, StatModifier, Stats, StatsManager};
+use crate::status_effect::{HasMutableStatusEffects, HasStatusEffects, StatusEffects};
 use crate::tournament::contestant::TournamentContestant;
 use crate::unique_entity::UniqueEntity;
 
 use super::body::{Body, HasBody, HasMutableBody};
 use super::names::WarriorNameDictionary;
+use super::perk::{HasMutablePerks, HasPerks, Perks};
+use super::personality::{HasPersonality, Personality};
+use super::proficiency::{HasMutableProficiencies, HasProficiencies, Proficiencies};
+use super::training::{HasTraining, Trainee, Training, TrainingProgram};
+use super::stance::{HasStance, Stance};
+use super::tactics::{HasMutableTactics, HasTactics, Tactics};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(remote = "Self")]
 pub struct Warrior {
     uuid: Uuid,
     name: String,
@@ -38,12 +49,54 @@ pub struct Warrior {
     weapon: Option<Weapon>,
     current_tournament: Option<Uuid>,
     body: Body,
-    duration_damages: Vec<DurationDamages>,
+    #[serde(default)]
+    status_effects: StatusEffects,
+    #[serde(default)]
+    stamina: Stamina,
     stats: StatsManager,
     is_unconscious: bool,
     last_passive_heal: i64,
     experience: u64,
     level: u8,
+    #[serde(default)]
+    attribute_points: u8,
+    #[serde(default)]
+    tactics: Tactics,
+    #[serde(default)]
+    shield: Option<Shield>,
+    #[serde(default)]
+    loadouts: Vec<Loadout>,
+    #[serde(default)]
+    tonic: Option<Tonic>,
+    #[serde(default)]
+    activity: Activity,
+    #[serde(default)]
+    perks: Perks,
+    #[serde(default)]
+    proficiencies: Proficiencies,
+    #[serde(default)]
+    training: Option<Training>,
+    #[serde(default)]
+    training_report: Option<TrainingProgram>,
+    #[serde(default)]
+    stat_practices: u8,
+    #[serde(default)]
+    personality: Personality,
+}
+
+impl Serialize for Warrior {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Warrior::serialize(self, serializer)
+    }
+}
+
+// Warriors saved before personalities existed get their biography written on load
+impl<'de> Deserialize<'de> for Warrior {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut warrior = Warrior::deserialize(deserializer)?;
+        warrior.personality.write_missing_biography(&warrior.name);
+        Ok(warrior)
+    }
 }
 
 impl UniqueEntity for Warrior {
@@ -67,21 +120,46 @@ impl OptionalMutableWeapon for Warrior {
     }
 }
 
+impl OptionalMutableShield for Warrior {
+    fn shield(&self) -> &Option<Shield> {
+        &self.shield
+    }
+    fn shield_mut(&mut self) -> &mut Option<Shield> {
+        &mut self.shield
+    }
+}
+
 impl Random for Warrior {
     fn random() -> Self {
+        let name = String::from(WarriorNameDictionary::random_item());
+        let personality = Personality::random_for(&name);
+        let max_health = 30 + personality.health_bonus();
         Self {
             uuid: Uuid::new_v4(),
-            name: String::from(WarriorNameDictionary::random_item()),
-            health: Health::new(30, 30),
+            name,
+            health: Health::new(max_health, max_health),
             weapon: Some(Weapon::random()),
             current_tournament: None,
             body: Body::new(),
-            duration_damages: vec![],
+            status_effects: StatusEffects::default(),
+            stamina: Stamina::default(),
             stats: StatsManager::random(),
             is_unconscious: false,
             last_passive_heal: Utc::now().timestamp(),
             experience: 0,
             level: 1,
+            attribute_points: 0,
+            tactics: Tactics::default(),
+            shield: None,
+            loadouts: vec![],
+            tonic: None,
+            activity: Activity::Resting,
+            perks: Perks::default(),
+            proficiencies: Proficiencies::default(),
+            training: None,
+            training_report: None,
+            stat_practices: 0,
+            personality,
         }
     }
 }
@@ -102,6 +180,46 @@ impl IsUnconscious for Warrior {
     }
 }
 
+impl HasTactics for Warrior {
+    fn tactics(&self) -> &Tactics {
+        &self.tactics
+    }
+}
+impl HasMutableTactics for Warrior {
+    fn tactics_mut(&mut self) -> &mut Tactics {
+        &mut self.tactics
+    }
+}
+
+impl HasLoadouts for Warrior {
+    fn loadouts(&self) -> &Vec<Loadout> {
+        &self.loadouts
+    }
+}
+impl HasMutableLoadouts for Warrior {
+    fn loadouts_mut(&mut self) -> &mut Vec<Loadout> {
+        &mut self.loadouts
+    }
+}
+
+impl HasTonic for Warrior {
+    fn tonic(&self) -> &Option<Tonic> {
+        &self.tonic
+    }
+}
+impl HasMutableTonic for Warrior {
+    fn tonic_mut(&mut self) -> &mut Option<Tonic> {
+        &mut self.tonic
+    }
+}
+impl UseConsumable for Warrior {}
+
+impl HasStance for Warrior {
+    fn stance(&self) -> &Stance {
+        self.tactics.stance()
+    }
+}
+
 impl TournamentContestant for Warrior {
     fn current_tournament(&self) -> &Option<Uuid> {
         &self.current_tournament
@@ -123,18 +241,33 @@ impl HasMutableBody for Warrior {
     }
 }
 
-impl TakeDurationDamages for Warrior {
-    fn duration_damages(&self) -> &Vec<DurationDamages> {
-        &self.duration_damages
+impl HasStatusEffects for Warrior {
+    fn status_effects(&self) -> &StatusEffects {
+        &self.status_effects
+    }
+}
+
+impl HasMutableStatusEffects for Warrior {
+    fn status_effects_mut(&mut self) -> &mut StatusEffects {
+        &mut self.status_effects
+    }
+}
+
+impl HasStamina for Warrior {
+    fn stamina(&self) -> &Stamina {
+        &self.stamina
     }
-    fn duration_damages_mut(&mut self) -> &mut Vec<DurationDamages> {
-        &mut self.duration_damages
+}
+
+impl HasMutableStamina for Warrior {
+    fn stamina_mut(&mut self) -> &mut Stamina {
+        &mut self.stamina
     }
 }
 
 impl ReduceDamages for Warrior {
     fn reduce_damages(&self, damages: u8) -> u8 {
-        self.body().reduce_damages(damages)
+        self.body().reduce_damages(damages).saturating_sub(self.perks.natural_armor())
     }
 }
 
@@ -143,7 +276,7 @@ impl DealDamages for Warrior {
         if let Some(weapon) = self.weapon() {
             let mut damages = weapon.deal_damages();
             let str = self.stats.stat(
-                &[Box::new(weapon), Box::new(&self.body)],
+                &[Box::new(weapon), Box::new(&self.body), Box::new(&self.personality)],
                 &StatKind::Strength,
             );
             if str.value() < 8 {
@@ -164,20 +297,36 @@ impl Stats for Warrior {
 
 impl AttackThreshold for Warrior {
     fn attack_threshold(&self) -> u8 {
+        let perks = self.perks.modifier(&self.weapon);
+        let proficiency = self.proficiencies.rank(&self.weapon);
         let mut modifiers: Vec<Box<&dyn StatModifier>> = vec![Box::new(&self.body)];
         if let Some(weapon) = self.weapon() {
             modifiers.push(Box::new(weapon));
         }
+        if let Some(shield) = self.usable_shield() {
+            modifiers.push(Box::new(shield));
+        }
+        modifiers.push(Box::new(&perks));
+        modifiers.push(Box::new(&proficiency));
+        modifiers.push(Box::new(&self.personality));
         self.stats().stat(&modifiers, &&StatKind::Attack).value()
     }
 }
 
 impl ParryThreshold for Warrior {
     fn parry_threshold(&self) -> u8 {
+        let perks = self.perks.modifier(&self.weapon);
+        let proficiency = self.proficiencies.rank(&self.weapon);
         let mut modifiers: Vec<Box<&dyn StatModifier>> = vec![Box::new(&self.body)];
         if let Some(weapon) = self.weapon() {
             modifiers.push(Box::new(weapon));
         }
+        if let Some(shield) = self.usable_shield() {
+            modifiers.push(Box::new(shield));
+        }
+        modifiers.push(Box::new(&perks));
+        modifiers.push(Box::new(&proficiency));
+        modifiers.push(Box::new(&self.personality));
         self.stats.stat(&modifiers, &&StatKind::Parry).value()
     }
 }
@@ -192,6 +341,73 @@ impl PassiveHealing for Warrior {
     fn last_passive_heal(&self) -> DateTime<Utc> {
         DateTime::from_timestamp(self.last_passive_heal, 0).unwrap()
     }
+
+    fn activity(&self) -> Activity {
+        match (&self.current_tournament, &self.training) {
+            (Some(_), _) => Activity::Registered,
+            (None, Some(_)) => Activity::Training,
+            (None, None) => self.activity.clone(),
+        }
+    }
+
+    fn healing_bonus(&self) -> u8 {
+        self.perks.healing_bonus()
+    }
+}
+
+impl HasPersonality for Warrior {
+    fn personality(&self) -> &Personality {
+        &self.personality
+    }
+}
+
+impl HasProficiencies for Warrior {
+    fn proficiencies(&self) -> &Proficiencies {
+        &self.proficiencies
+    }
+}
+
+impl HasMutableProficiencies for Warrior {
+    fn proficiencies_mut(&mut self) -> &mut Proficiencies {
+        &mut self.proficiencies
+    }
+}
+
+impl HasTraining for Warrior {
+    fn training(&self) -> &Option<Training> {
+        &self.training
+    }
+    fn training_report(&self) -> &Option<TrainingProgram> {
+        &self.training_report
+    }
+    fn stat_practices(&self) -> u8 {
+        self.stat_practices
+    }
+}
+
+impl Trainee for Warrior {
+    fn training_mut(&mut self) -> &mut Option<Training> {
+        &mut self.training
+    }
+    fn training_report_mut(&mut self) -> &mut Option<TrainingProgram> {
+        &mut self.training_report
+    }
+    fn practice_stat(&mut self, stat: &StatKind) {
+        self.stats.increment_nat_stat(stat);
+        self.stat_practices = self.stat_practices.saturating_add(1);
+    }
+}
+
+impl HasPerks for Warrior {
+    fn perks(&self) -> &Perks {
+        &self.perks
+    }
+}
+
+impl HasMutablePerks for Warrior {
+    fn perks_mut(&mut self) -> &mut Perks {
+        &mut self.perks
+    }
 }
 
 // server only
@@ -199,6 +415,24 @@ impl MutablePassiveHealing for Warrior {
     fn set_last_passive_heal(&mut self, last_passive_heal: DateTime<Utc>) {
         self.last_passive_heal = last_passive_heal.timestamp()
     }
+
+    fn set_activity(&mut self, activity: Activity) {
+        self.activity = activity
+    }
+
+    // Bleeding and poison wear off with rest, like injuries
+    fn recover_injuries(&mut self, hours: u32) {
+        self.body.recover(hours);
+        self.status_effects.cure_lingering();
+    }
+
+    fn wake_up(&mut self) {
+        self.is_unconscious = false
+    }
+
+    fn catch_breath(&mut self) {
+        self.stamina.refill()
+    }
 }
 
 impl Experience for Warrior {
@@ -213,32 +447,30 @@ impl GainExperience for Warrior {
     fn gain_xp(&mut self, xp: u64) {
         self.experience += xp;
     }
-    fn level_up(&mut self, stat: &StatKind) -> Result<(), ExperienceError> {
-        let stat_is_incrementable = if (self.level + 1) % 2 == 0 {
-            match stat {
-                &StatKind::Courage | &StatKind::Dexterity | &StatKind::Strength => true,
-                _ => false,
-            }
-        } else {
-            match stat {
-                &StatKind::Attack | &StatKind::Parry => true,
-                _ => false,
-            }
-        };
-        if !stat_is_incrementable {
-            return Err(ExperienceError::new(
-                &ExperienceErrorKind::InvalidStatIncrement(self.level + 1, stat.clone()),
-            ));
+    fn level_up(&mut self) -> Result<(), ExperienceError> {
+        if !self.can_level_up() {
+            return Err(ExperienceError::new(&ExperienceErrorKind::NotEnoughXp(self.level + 1)));
         }
         let health_gain = Dice::D6.roll();
         let current = self.health.current();
         let max = self.health.max();
         self.health.set_max(max + health_gain);
         self.health.set(current + health_gain);
-        self.stats.increment_nat_stat(stat);
+        self.attribute_points = self.attribute_points.saturating_add(ATTRIBUTE_POINTS_PER_LEVEL);
         self.level += 1;
         Ok(())
     }
+    fn attribute_points(&self) -> u8 {
+        self.attribute_points
+    }
+    fn spend_attribute_point(&mut self, stat: &StatKind) -> Result<(), ExperienceError> {
+        if self.attribute_points == 0 {
+            return Err(ExperienceError::new(&ExperienceErrorKind::NoAttributePoint));
+        }
+        self.stats.increment_nat_stat(stat);
+        self.attribute_points -= 1;
+        Ok(())
+    }
 }
 
 impl AssaultOrderComparable for Warrior {
@@ -247,6 +479,7 @@ impl AssaultOrderComparable for Warrior {
         if let Some(weapon) = &self.weapon {
             modifiers.push(Box::new(weapon))
         }
+        modifiers.push(Box::new(&self.personality));
         self.stats.stat(&modifiers, &&StatKind::Courage).value()
     }
 }
@@ -257,7 +490,11 @@ impl ResolveGougeRandomEye for Warrior {}
 impl ResolveBreakWeapon for Warrior {}
 impl ResolveDropWeapon for Warrior {}
 impl ResolveMissAssaults for Warrior {}
-impl ResolveAttackSuccess for Warrior {}
+impl ResolveAttackSuccess for Warrior {
+    fn natural_armor(&self) -> u8 {
+        self.perks.natural_armor()
+    }
+}
 impl ResolveClumsiness for Warrior {}
 impl DealCriticalHit for Warrior {}
 impl ResolveCriticalHit for Warrior {}
@@ -269,5 +506,9 @@ impl ResolveParryClumsiness for Warrior {}
 impl TakeDamage for Warrior {}
 impl ResolveParrySuccess for Warrior {}
 impl CanBeAttacked for Warrior {}
-impl AttackAttempt for Warrior {}
+impl AttackAttempt for Warrior {
+    fn attack_clumsiness_threshold(&self) -> u8 {
+        self.proficiencies.clumsiness_threshold(20, &self.weapon)
+    }
+}
 impl ParryAttempt for Warrior {}
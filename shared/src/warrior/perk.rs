@@ -0,0 +1,282 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::equipment::weapon::{Weapon, WeaponKind};
+use crate::inventory::Inventory;
+use crate::stats::{StatKind, StatModifier};
+
+// A new perk can be picked every few levels
+const LEVELS_PER_PERK: u8 = 3;
+const RESPEC_COST_PER_LEVEL: u32 = 25;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum Perk {
+    SwordMaster,
+    AxeMaster,
+    HammerMaster,
+    // Natural armor, absorbs one damage of every blow
+    IronSkin,
+    FastHealer,
+    Fearless,
+    Nimble,
+}
+
+pub const PERKS: [Perk; 7] = [
+    Perk::SwordMaster,
+    Perk::AxeMaster,
+    Perk::HammerMaster,
+    Perk::IronSkin,
+    Perk::FastHealer,
+    Perk::Fearless,
+    Perk::Nimble,
+];
+
+impl Perk {
+    pub fn required_level(&self) -> u8 {
+        match self {
+            Perk::SwordMaster | Perk::AxeMaster | Perk::HammerMaster => 3,
+            Perk::Fearless | Perk::Nimble => 3,
+            Perk::FastHealer => 6,
+            Perk::IronSkin => 9,
+        }
+    }
+
+    // Perks form a tree, a branch has to be learned from its root
+    pub fn prerequisite(&self) -> Option<Perk> {
+        match self {
+            Perk::FastHealer => Some(Perk::Fearless),
+            Perk::IronSkin => Some(Perk::FastHealer),
+            _ => None,
+        }
+    }
+
+    fn masters(&self, weapon: &Weapon) -> bool {
+        matches!(
+            (self, weapon.kind()),
+            (Perk::SwordMaster, WeaponKind::Sword | WeaponKind::GreatSword) |
+            (Perk::AxeMaster, WeaponKind::Axe | WeaponKind::BattleAxe) |
+            (Perk::HammerMaster, WeaponKind::Hammer | WeaponKind::WarHammer)
+        )
+    }
+
+    fn value(&self, weapon: Option<&Weapon>, stat: &StatKind) -> i8 {
+        match (self, stat) {
+            (Perk::SwordMaster | Perk::AxeMaster | Perk::HammerMaster, StatKind::Attack) => {
+                match weapon {
+                    Some(weapon) if self.masters(weapon) => 1,
+                    _ => 0,
+                }
+            },
+            (Perk::Fearless, StatKind::Courage) => 2,
+            (Perk::Nimble, StatKind::Dexterity) => 1,
+            (Perk::Nimble, StatKind::Parry) => 1,
+            _ => 0,
+        }
+    }
+}
+
+pub fn perk_slots(level: u8) -> usize {
+    (level / LEVELS_PER_PERK) as usize
+}
+
+pub fn respec_cost(level: u8) -> u32 {
+    level as u32 * RESPEC_COST_PER_LEVEL
+}
+
+#[derive(Debug)]
+pub enum PerkErrorKind {
+    LevelTooLow,
+    MissingPrerequisite,
+    NoSlotLeft,
+    AlreadyKnown,
+    NothingToRespec,
+    NotEnoughGold,
+}
+
+#[derive(Debug)]
+pub struct PerkError {
+    kind: PerkErrorKind,
+    message: String,
+}
+
+impl PerkError {
+    fn new(kind: PerkErrorKind) -> Self {
+        let message = match kind {
+            PerkErrorKind::LevelTooLow => "Warrior level is too low for this perk".to_string(),
+            PerkErrorKind::MissingPrerequisite => "Warrior must learn the previous perk first".to_string(),
+            PerkErrorKind::NoSlotLeft => "Warrior can not learn more perks yet".to_string(),
+            PerkErrorKind::AlreadyKnown => "Warrior already has this perk".to_string(),
+            PerkErrorKind::NothingToRespec => "Warrior has no perk to forget".to_string(),
+            PerkErrorKind::NotEnoughGold => "Not enough gold to respec".to_string(),
+        };
+        Self { kind, message }
+    }
+
+    pub fn kind(&self) -> &PerkErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for PerkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for PerkError {}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Perks(Vec<Perk>);
+
+impl Perks {
+    pub fn list(&self) -> &Vec<Perk> {
+        &self.0
+    }
+
+    pub fn has(&self, perk: &Perk) -> bool {
+        self.0.contains(perk)
+    }
+
+    pub fn can_learn(&self, perk: &Perk, level: u8) -> Result<(), PerkError> {
+        if self.has(perk) {
+            return Err(PerkError::new(PerkErrorKind::AlreadyKnown));
+        }
+        if level < perk.required_level() {
+            return Err(PerkError::new(PerkErrorKind::LevelTooLow));
+        }
+        if perk.prerequisite().is_some_and(|prerequisite| !self.has(&prerequisite)) {
+            return Err(PerkError::new(PerkErrorKind::MissingPrerequisite));
+        }
+        if self.0.len() >= perk_slots(level) {
+            return Err(PerkError::new(PerkErrorKind::NoSlotLeft));
+        }
+        Ok(())
+    }
+
+    pub fn learn(&mut self, perk: Perk, level: u8) -> Result<(), PerkError> {
+        self.can_learn(&perk, level)?;
+        self.0.push(perk);
+        Ok(())
+    }
+
+    pub fn natural_armor(&self) -> u8 {
+        if self.has(&Perk::IronSkin) { 1 } else { 0 }
+    }
+
+    pub fn healing_bonus(&self) -> u8 {
+        if self.has(&Perk::FastHealer) { 1 } else { 0 }
+    }
+
+    // Weapon mastery only applies with the matching weapon in hand
+    pub fn modifier<'a>(&'a self, weapon: &'a Option<Weapon>) -> PerksModifier<'a> {
+        PerksModifier { perks: self, weapon: weapon.as_ref() }
+    }
+}
+
+pub struct PerksModifier<'a> {
+    perks: &'a Perks,
+    weapon: Option<&'a Weapon>,
+}
+
+impl StatModifier for PerksModifier<'_> {
+    fn value(&self, stat: &StatKind) -> i8 {
+        self.perks.0.iter().map(|perk| perk.value(self.weapon, stat)).sum()
+    }
+}
+
+pub trait HasPerks {
+    fn perks(&self) -> &Perks;
+}
+
+pub trait HasMutablePerks: HasPerks {
+    fn perks_mut(&mut self) -> &mut Perks;
+}
+
+// server only
+// Forgets every perk so the slots can be picked again
+pub fn respec_for_gold(perks: &mut Perks, level: u8, inventory: &mut Inventory) -> Result<(), PerkError> {
+    if perks.0.is_empty() {
+        return Err(PerkError::new(PerkErrorKind::NothingToRespec));
+    }
+    let cost = respec_cost(level);
+    if inventory.gold() < cost {
+        return Err(PerkError::new(PerkErrorKind::NotEnoughGold));
+    }
+    inventory.remove_gold(cost);
+    perks.0.clear();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::assault::attack_success::ResolveAttackSuccess;
+    use crate::random::Random;
+    use crate::warrior::body::body_part::BodyPartKind;
+    use crate::warrior::{HasMutablePerks, Warrior};
+
+    use super::*;
+
+    #[test]
+    fn perks_need_level_and_free_slot() {
+        let mut perks = Perks::default();
+        assert!(matches!(perks.learn(Perk::AxeMaster, 2).unwrap_err().kind(), PerkErrorKind::LevelTooLow));
+        perks.learn(Perk::AxeMaster, 3).unwrap();
+        assert!(matches!(perks.learn(Perk::AxeMaster, 6).unwrap_err().kind(), PerkErrorKind::AlreadyKnown));
+        assert!(matches!(perks.learn(Perk::Nimble, 5).unwrap_err().kind(), PerkErrorKind::NoSlotLeft));
+        perks.learn(Perk::Nimble, 6).unwrap();
+        assert_eq!(perks.list().len(), perk_slots(6));
+    }
+
+    #[test]
+    fn perks_follow_the_tree() {
+        let mut perks = Perks::default();
+        assert!(matches!(perks.learn(Perk::IronSkin, 9).unwrap_err().kind(), PerkErrorKind::MissingPrerequisite));
+        assert!(matches!(perks.learn(Perk::FastHealer, 9).unwrap_err().kind(), PerkErrorKind::MissingPrerequisite));
+        perks.learn(Perk::Fearless, 9).unwrap();
+        perks.learn(Perk::FastHealer, 9).unwrap();
+        perks.learn(Perk::IronSkin, 9).unwrap();
+    }
+
+    #[test]
+    fn weapon_mastery_depends_on_weapon_in_hand() {
+        let mut perks = Perks::default();
+        perks.learn(Perk::AxeMaster, 3).unwrap();
+        let axe = Some(Weapon::new(WeaponKind::BattleAxe));
+        let sword = Some(Weapon::new(WeaponKind::Sword));
+        assert_eq!(perks.modifier(&axe).value(&StatKind::Attack), 1);
+        assert_eq!(perks.modifier(&sword).value(&StatKind::Attack), 0);
+        assert_eq!(perks.modifier(&None).value(&StatKind::Attack), 0);
+    }
+
+    #[test]
+    fn iron_skin_absorbs_located_hits() {
+        let mut warrior = Warrior::random();
+        assert_eq!(warrior.resolve_hit_at(5, &BodyPartKind::Head).damages(), 5);
+        for perk in [Perk::Fearless, Perk::FastHealer, Perk::IronSkin] {
+            warrior.perks_mut().learn(perk, 9).unwrap();
+        }
+        assert_eq!(warrior.resolve_hit_at(5, &BodyPartKind::Head).damages(), 4);
+    }
+
+    #[test]
+    fn respec_costs_gold_and_frees_slots() {
+        let mut perks = Perks::default();
+        let mut inventory = Inventory::new();
+        assert!(matches!(
+            respec_for_gold(&mut perks, 3, &mut inventory).unwrap_err().kind(),
+            PerkErrorKind::NothingToRespec,
+        ));
+        perks.learn(Perk::Fearless, 3).unwrap();
+        assert!(matches!(
+            respec_for_gold(&mut perks, 3, &mut inventory).unwrap_err().kind(),
+            PerkErrorKind::NotEnoughGold,
+        ));
+        inventory.add_gold(respec_cost(3));
+        respec_for_gold(&mut perks, 3, &mut inventory).unwrap();
+        assert!(perks.list().is_empty());
+        assert_eq!(inventory.gold(), 0);
+        perks.learn(Perk::Nimble, 3).unwrap();
+    }
+}
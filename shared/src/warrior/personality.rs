@@ -0,0 +1,254 @@
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::random::Random;
+use crate::stats::{StatKind, StatModifier};
+
+const MAX_TRAITS: usize = 2;
+// Max HP a tough recruit starts with on top of the others
+const TOUGH_HEALTH_BONUS: u8 = 5;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub enum Background {
+    // Veterans recruited before backgrounds were recorded
+    #[default]
+    Drifter,
+    Farmhand,
+    Deserter,
+    Blacksmith,
+    Noble,
+    StreetUrchin,
+    Hunter,
+}
+
+pub const BACKGROUNDS: [Background; 6] = [
+    Background::Farmhand,
+    Background::Deserter,
+    Background::Blacksmith,
+    Background::Noble,
+    Background::StreetUrchin,
+    Background::Hunter,
+];
+
+impl Background {
+    pub fn story(&self) -> &'static str {
+        match self {
+            Background::Drifter => "drifted into the arena, nobody knows from where",
+            Background::Farmhand => "grew up tilling fields far from any arena",
+            Background::Deserter => "fled an army that still has a price on their head",
+            Background::Blacksmith => "spent years swinging a hammer at the forge",
+            Background::Noble => "left a comfortable manor in search of glory",
+            Background::StreetUrchin => "learned to dodge and steal in the city slums",
+            Background::Hunter => "tracked game through the woods since childhood",
+        }
+    }
+}
+
+impl Random for Background {
+    fn random() -> Self {
+        BACKGROUNDS[rand::thread_rng().gen_range(0..BACKGROUNDS.len())].clone()
+    }
+}
+
+impl StatModifier for Background {
+    fn value(&self, stat: &StatKind) -> i8 {
+        match (self, stat) {
+            (Background::Farmhand, StatKind::Strength) => 1,
+            (Background::Deserter, StatKind::Attack) => 1,
+            (Background::Deserter, StatKind::Courage) => -1,
+            (Background::Blacksmith, StatKind::Strength) => 1,
+            (Background::Noble, StatKind::Courage) => 1,
+            (Background::StreetUrchin, StatKind::Dexterity) => 1,
+            (Background::Hunter, StatKind::Attack) => 1,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum Trait {
+    // Opponents are not used to blows coming from that side
+    LeftHanded,
+    Cowardly,
+    Reckless,
+    Tough,
+    Nimble,
+}
+
+pub const TRAITS: [Trait; 5] = [
+    Trait::LeftHanded,
+    Trait::Cowardly,
+    Trait::Reckless,
+    Trait::Tough,
+    Trait::Nimble,
+];
+
+impl Trait {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Trait::LeftHanded => "Fights with the left hand, to the confusion of most opponents.",
+            Trait::Cowardly => "Keeps an eye on the exit more than on the opponent.",
+            Trait::Reckless => "Charges headlong into every fight, guard be damned.",
+            Trait::Tough => "Has taken more beatings than anyone cares to count.",
+            Trait::Nimble => "Moves lightly on their feet.",
+        }
+    }
+
+    fn conflicts_with(&self, other: &Trait) -> bool {
+        self == other || matches!(
+            (self, other),
+            (Trait::Cowardly, Trait::Reckless) | (Trait::Reckless, Trait::Cowardly)
+        )
+    }
+}
+
+impl Random for Trait {
+    fn random() -> Self {
+        TRAITS[rand::thread_rng().gen_range(0..TRAITS.len())].clone()
+    }
+}
+
+impl StatModifier for Trait {
+    fn value(&self, stat: &StatKind) -> i8 {
+        match (self, stat) {
+            (Trait::LeftHanded, StatKind::Attack) => 1,
+            (Trait::Cowardly, StatKind::Courage) => -3,
+            (Trait::Cowardly, StatKind::Parry) => 1,
+            (Trait::Reckless, StatKind::Attack) => 2,
+            (Trait::Reckless, StatKind::Parry) => -2,
+            (Trait::Reckless, StatKind::Courage) => 2,
+            (Trait::Tough, StatKind::Strength) => 1,
+            (Trait::Tough, StatKind::Courage) => 1,
+            (Trait::Nimble, StatKind::Dexterity) => 2,
+            _ => 0,
+        }
+    }
+}
+
+fn write_biography(name: &str, background: &Background, traits: &[Trait]) -> String {
+    let mut biography = format!("{name} {}.", background.story());
+    for warrior_trait in traits {
+        biography += format!(" {}", warrior_trait.description()).as_str();
+    }
+    biography
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Personality {
+    background: Background,
+    traits: Vec<Trait>,
+    biography: String,
+}
+
+impl Personality {
+    pub fn new(name: &str, background: Background, traits: Vec<Trait>) -> Self {
+        let biography = write_biography(name, &background, &traits);
+        Self { background, traits, biography }
+    }
+
+    pub fn write_missing_biography(&mut self, name: &str) {
+        if self.biography.is_empty() {
+            self.biography = write_biography(name, &self.background, &self.traits);
+        }
+    }
+
+    // server only
+    pub fn random_for(name: &str) -> Self {
+        let trait_count = rand::thread_rng().gen_range(0..=MAX_TRAITS);
+        let mut traits: Vec<Trait> = vec![];
+        while traits.len() < trait_count {
+            let candidate = Trait::random();
+            if traits.iter().all(|known| !known.conflicts_with(&candidate)) {
+                traits.push(candidate);
+            }
+        }
+        Self::new(name, Background::random(), traits)
+    }
+
+    pub fn background(&self) -> &Background {
+        &self.background
+    }
+
+    pub fn traits(&self) -> &Vec<Trait> {
+        &self.traits
+    }
+
+    pub fn has(&self, warrior_trait: &Trait) -> bool {
+        self.traits.contains(warrior_trait)
+    }
+
+    pub fn biography(&self) -> &str {
+        &self.biography
+    }
+
+    pub fn health_bonus(&self) -> u8 {
+        if self.has(&Trait::Tough) {
+            TOUGH_HEALTH_BONUS
+        } else {
+            0
+        }
+    }
+}
+
+impl StatModifier for Personality {
+    fn value(&self, stat: &StatKind) -> i8 {
+        self.traits.iter().fold(
+            self.background.value(stat),
+            |total, warrior_trait| total + warrior_trait.value(stat),
+        )
+    }
+}
+
+pub trait HasPersonality {
+    fn personality(&self) -> &Personality;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::name::Name;
+    use crate::warrior::Warrior;
+
+    use super::*;
+
+    #[test]
+    fn generated_traits_never_conflict() {
+        for _ in 0..200 {
+            let personality = Personality::random_for("Ald");
+            assert!(personality.traits().len() <= MAX_TRAITS);
+            assert!(!(personality.has(&Trait::Cowardly) && personality.has(&Trait::Reckless)));
+            assert!(personality.biography().starts_with("Ald "));
+        }
+    }
+
+    #[test]
+    fn background_and_traits_add_up() {
+        let personality = Personality::new(
+            "Arcen",
+            Background::Hunter,
+            vec![Trait::LeftHanded, Trait::Reckless],
+        );
+        assert_eq!(personality.value(&StatKind::Attack), 4);
+        assert_eq!(personality.value(&StatKind::Parry), -2);
+        assert_eq!(personality.value(&StatKind::Courage), 2);
+        assert_eq!(personality.health_bonus(), 0);
+        assert!(personality.biography().contains(Trait::LeftHanded.description()));
+    }
+
+    #[test]
+    fn veterans_have_a_neutral_personality() {
+        let personality = Personality::default();
+        assert_eq!(personality.background(), &Background::Drifter);
+        assert_eq!(personality.value(&StatKind::Courage), 0);
+    }
+
+    #[test]
+    fn veterans_get_a_biography_on_load() {
+        let mut save = serde_json::to_value(Warrior::random()).unwrap();
+        save.as_object_mut().unwrap().remove("personality");
+        let warrior: Warrior = serde_json::from_value(save).unwrap();
+        assert_eq!(
+            warrior.personality().biography(),
+            format!("{} {}.", warrior.name(), Background::Drifter.story()),
+        );
+    }
+}
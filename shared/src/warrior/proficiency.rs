@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+
+use crate::equipment::weapon::{Weapon, WeaponKind};
+use crate::stats::{StatKind, StatModifier};
+
+// Proficiency XP needed to reach each rank
+const TRAINED_XP: u32 = 30;
+const SKILLED_XP: u32 = 100;
+const MASTER_XP: u32 = 250;
+// No face of a D20 reaches it, clumsiness is gone
+const NO_CLUMSINESS_THRESHOLD: u8 = 21;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub enum ProficiencyRank {
+    Novice,
+    Trained,
+    Skilled,
+    Master,
+}
+
+impl ProficiencyRank {
+    pub fn from_xp(xp: u32) -> Self {
+        if xp >= MASTER_XP {
+            Self::Master
+        } else if xp >= SKILLED_XP {
+            Self::Skilled
+        } else if xp >= TRAINED_XP {
+            Self::Trained
+        } else {
+            Self::Novice
+        }
+    }
+
+    fn clumsiness_bonus(&self) -> u8 {
+        match self {
+            Self::Skilled | Self::Master => 1,
+            _ => 0,
+        }
+    }
+}
+
+impl StatModifier for ProficiencyRank {
+    fn value(&self, stat: &StatKind) -> i8 {
+        match (self, stat) {
+            (Self::Trained | Self::Skilled, StatKind::Attack) => 1,
+            (Self::Master, StatKind::Attack) => 2,
+            (Self::Skilled | Self::Master, StatKind::Parry) => 1,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Proficiency {
+    kind: WeaponKind,
+    xp: u32,
+}
+
+impl Proficiency {
+    pub fn kind(&self) -> &WeaponKind {
+        &self.kind
+    }
+
+    pub fn xp(&self) -> u32 {
+        self.xp
+    }
+
+    pub fn rank(&self) -> ProficiencyRank {
+        ProficiencyRank::from_xp(self.xp)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Proficiencies(Vec<Proficiency>);
+
+impl Proficiencies {
+    pub fn list(&self) -> &Vec<Proficiency> {
+        &self.0
+    }
+
+    pub fn xp(&self, kind: &WeaponKind) -> u32 {
+        self.0.iter()
+            .find(|proficiency| &proficiency.kind == kind)
+            .map_or(0, |proficiency| proficiency.xp)
+    }
+
+    pub fn rank(&self, weapon: &Option<Weapon>) -> ProficiencyRank {
+        match weapon {
+            Some(weapon) => ProficiencyRank::from_xp(self.xp(weapon.kind())),
+            None => ProficiencyRank::Novice,
+        }
+    }
+
+    pub fn gain(&mut self, kind: &WeaponKind, xp: u32) {
+        match self.0.iter_mut().find(|proficiency| &proficiency.kind == kind) {
+            Some(proficiency) => proficiency.xp = proficiency.xp.saturating_add(xp),
+            None => self.0.push(Proficiency { kind: kind.clone(), xp }),
+        }
+    }
+
+    pub fn merge(&mut self, gains: &Proficiencies) {
+        for proficiency in gains.0.iter() {
+            self.gain(&proficiency.kind, proficiency.xp);
+        }
+    }
+
+    // Skilled hands drop their weapon less often
+    pub fn clumsiness_threshold(&self, base: u8, weapon: &Option<Weapon>) -> u8 {
+        base.saturating_add(self.rank(weapon).clumsiness_bonus())
+            .min(NO_CLUMSINESS_THRESHOLD)
+    }
+}
+
+pub trait HasProficiencies {
+    fn proficiencies(&self) -> &Proficiencies;
+}
+
+pub trait HasMutableProficiencies: HasProficiencies {
+    fn proficiencies_mut(&mut self) -> &mut Proficiencies;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proficiency_ranks_grow_with_xp() {
+        let mut proficiencies = Proficiencies::default();
+        let axe = Some(Weapon::new(WeaponKind::Axe));
+        assert_eq!(proficiencies.rank(&axe), ProficiencyRank::Novice);
+        proficiencies.gain(&WeaponKind::Axe, TRAINED_XP);
+        assert_eq!(proficiencies.rank(&axe).value(&StatKind::Attack), 1);
+        proficiencies.gain(&WeaponKind::Axe, MASTER_XP);
+        assert_eq!(proficiencies.rank(&axe), ProficiencyRank::Master);
+        assert_eq!(proficiencies.rank(&Some(Weapon::new(WeaponKind::Sword))), ProficiencyRank::Novice);
+        assert_eq!(proficiencies.list().len(), 1);
+    }
+
+    #[test]
+    fn mastery_lowers_clumsiness() {
+        let mut proficiencies = Proficiencies::default();
+        let hammer = Some(Weapon::new(WeaponKind::Hammer));
+        assert_eq!(proficiencies.clumsiness_threshold(19, &hammer), 19);
+        let mut gains = Proficiencies::default();
+        gains.gain(&WeaponKind::Hammer, SKILLED_XP);
+        proficiencies.merge(&gains);
+        assert_eq!(proficiencies.clumsiness_threshold(19, &hammer), 20);
+        assert_eq!(proficiencies.clumsiness_threshold(20, &hammer), NO_CLUMSINESS_THRESHOLD);
+        assert_eq!(proficiencies.clumsiness_threshold(20, &None), 20);
+    }
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use crate::health::MutableHealth;
+use crate::stats::{StatKind, StatModifier};
+use crate::status_effect::{HasStatusEffects, StatusEffectKind};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub enum Stance {
+    Aggressive,
+    Defensive,
+    #[default]
+    Balanced,
+    Berserk,
+}
+
+impl Stance {
+    pub fn damages_modifier(&self) -> i8 {
+        match self {
+            Self::Aggressive => 1,
+            Self::Defensive => -1,
+            Self::Balanced => 0,
+            Self::Berserk => 2,
+        }
+    }
+
+    // Attack rolls at or above this face are clumsy
+    pub fn clumsiness_threshold(&self) -> u8 {
+        match self {
+            Self::Aggressive => 19,
+            Self::Berserk => 18,
+            Self::Defensive | Self::Balanced => 20,
+        }
+    }
+}
+
+impl StatModifier for Stance {
+    fn value(&self, stat: &StatKind) -> i8 {
+        match (self, stat) {
+            (Self::Aggressive, StatKind::Attack) => 2,
+            (Self::Aggressive, StatKind::Parry) => -2,
+            (Self::Defensive, StatKind::Attack) => -2,
+            (Self::Defensive, StatKind::Parry) => 3,
+            (Self::Berserk, StatKind::Attack) => 4,
+            (Self::Berserk, StatKind::Parry) => -6,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum StanceCondition {
+    Bleeding,
+    HealthBelowPercent(u8),
+}
+
+impl StanceCondition {
+    pub fn is_met<T: MutableHealth + HasStatusEffects>(&self, fighter: &T) -> bool {
+        match self {
+            Self::Bleeding => fighter.status_effects().has(&StatusEffectKind::Bleeding),
+            Self::HealthBelowPercent(percent) => {
+                let health = fighter.health();
+                u32::from(health.current()) * 100 < u32::from(health.max()) * u32::from(*percent)
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct StanceSwitch {
+    condition: StanceCondition,
+    stance: Stance,
+}
+
+impl StanceSwitch {
+    pub fn new(condition: StanceCondition, stance: Stance) -> Self {
+        Self { condition, stance }
+    }
+
+    pub fn condition(&self) -> &StanceCondition {
+        &self.condition
+    }
+
+    pub fn stance(&self) -> &Stance {
+        &self.stance
+    }
+}
+
+pub trait HasStance {
+    fn stance(&self) -> &Stance;
+}
@@ -0,0 +1,182 @@
+use serde::{Deserialize, Serialize};
+
+use crate::health::MutableHealth;
+use crate::status_effect::HasStatusEffects;
+use crate::warrior::body::injury::{Injuries, Injury};
+use crate::warrior::body::HasBody;
+
+use super::stance::{Stance, StanceCondition, StanceSwitch};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct Tactics {
+    surrender_health_percent: Option<u8>,
+    surrender_on_severed_limb: bool,
+    #[serde(default)]
+    stance: Stance,
+    // Checked in order, the first matching switch decides the stance of the turn
+    #[serde(default)]
+    stance_switches: Vec<StanceSwitch>,
+}
+
+impl Tactics {
+    pub fn new(
+        surrender_health_percent: Option<u8>,
+        surrender_on_severed_limb: bool,
+        stance: Stance,
+        stance_switches: Vec<StanceSwitch>,
+    ) -> Self {
+        Self {
+            surrender_health_percent,
+            surrender_on_severed_limb,
+            stance,
+            stance_switches,
+        }
+    }
+
+    pub fn stance(&self) -> &Stance {
+        &self.stance
+    }
+
+    pub fn stance_switches(&self) -> &Vec<StanceSwitch> {
+        &self.stance_switches
+    }
+
+    pub fn stance_for<T: MutableHealth + HasStatusEffects>(&self, fighter: &T) -> Stance {
+        match self.stance_switches.iter().find(|switch| switch.condition().is_met(fighter)) {
+            Some(switch) => switch.stance().clone(),
+            None => self.stance.clone(),
+        }
+    }
+
+    pub fn surrender_health_percent(&self) -> Option<u8> {
+        self.surrender_health_percent
+    }
+
+    pub fn surrender_on_severed_limb(&self) -> bool {
+        self.surrender_on_severed_limb
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.surrender_health_percent.is_none_or(|percent| percent <= 100)
+            && self.stance_switches.iter().all(|switch| match switch.condition() {
+                StanceCondition::HealthBelowPercent(percent) => *percent <= 100,
+                StanceCondition::Bleeding => true,
+            })
+    }
+
+    // severed_limbs_at_start prevents old injuries from triggering a surrender
+    pub fn wants_to_surrender<T: MutableHealth + HasBody>(
+        &self,
+        fighter: &T,
+        severed_limbs_at_start: usize,
+    ) -> bool {
+        if let Some(percent) = self.surrender_health_percent {
+            let health = fighter.health();
+            if u32::from(health.current()) * 100 < u32::from(health.max()) * u32::from(percent) {
+                return true;
+            }
+        }
+        self.surrender_on_severed_limb && severed_limbs(fighter) > severed_limbs_at_start
+    }
+}
+
+pub fn severed_limbs<T: HasBody>(fighter: &T) -> usize {
+    fighter.body()
+        .injuries()
+        .iter()
+        .filter(|injury| matches!(
+            injury,
+            Injury::FootSevered(_)
+                | Injury::OneLegSevered(_)
+                | Injury::BothLegsSevered
+                | Injury::RightArmSevered
+                | Injury::RightHandSevered
+                | Injury::LeftArmSevered
+                | Injury::LeftHandSevered
+        ))
+        .count()
+}
+
+pub trait HasTactics {
+    fn tactics(&self) -> &Tactics;
+}
+
+pub trait HasMutableTactics: HasTactics {
+    fn tactics_mut(&mut self) -> &mut Tactics;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::Random;
+    use crate::status_effect::{HasMutableStatusEffects, StatusEffect, StatusEffectKind};
+    use crate::warrior::body::body_part::{BodyPartKind, BodySide, OptionalMutableBodyPart};
+    use crate::warrior::body::HasMutableBody;
+    use crate::warrior::Warrior;
+
+    use super::*;
+
+    #[test]
+    fn default_tactics_never_surrender() {
+        let mut warrior = Warrior::random();
+        warrior.health_mut().set(6);
+        warrior.body_mut().body_part_mut(&BodyPartKind::Hand(BodySide::Left)).take();
+        assert!(!Tactics::default().wants_to_surrender(&warrior, 0));
+    }
+
+    #[test]
+    fn surrender_below_health_percent() {
+        let mut warrior = Warrior::random();
+        let tactics = Tactics::new(Some(50), false, Stance::Balanced, vec![]);
+        // Rounded up so odd max health stays at or above half
+        let half = warrior.health().max().div_ceil(2);
+        warrior.health_mut().set(half);
+        assert!(!tactics.wants_to_surrender(&warrior, 0));
+        warrior.health_mut().set(half - 1);
+        assert!(tactics.wants_to_surrender(&warrior, 0));
+    }
+
+    #[test]
+    fn surrender_on_newly_severed_limb_only() {
+        let mut warrior = Warrior::random();
+        let tactics = Tactics::new(None, true, Stance::Balanced, vec![]);
+        warrior.body_mut().body_part_mut(&BodyPartKind::Foot(BodySide::Left)).take();
+        let severed_at_start = severed_limbs(&warrior);
+        assert_eq!(severed_at_start, 1);
+        assert!(!tactics.wants_to_surrender(&warrior, severed_at_start));
+        warrior.body_mut().body_part_mut(&BodyPartKind::Hand(BodySide::Right)).take();
+        assert!(tactics.wants_to_surrender(&warrior, severed_at_start));
+    }
+
+    #[test]
+    fn percent_above_hundred_is_invalid() {
+        assert!(Tactics::new(Some(100), false, Stance::Balanced, vec![]).is_valid());
+        assert!(!Tactics::new(Some(101), false, Stance::Balanced, vec![]).is_valid());
+        let switches = vec![StanceSwitch::new(StanceCondition::HealthBelowPercent(120), Stance::Berserk)];
+        assert!(!Tactics::new(None, false, Stance::Balanced, switches).is_valid());
+    }
+
+    #[test]
+    fn go_defensive_when_bleeding() {
+        let mut warrior = Warrior::random();
+        let switches = vec![StanceSwitch::new(StanceCondition::Bleeding, Stance::Defensive)];
+        let tactics = Tactics::new(None, false, Stance::Aggressive, switches);
+        assert_eq!(tactics.stance_for(&warrior), Stance::Aggressive);
+        warrior.status_effects_mut().add(StatusEffect::new(StatusEffectKind::Bleeding));
+        assert_eq!(tactics.stance_for(&warrior), Stance::Defensive);
+    }
+
+    #[test]
+    fn first_matching_switch_wins() {
+        let mut warrior = Warrior::random();
+        let switches = vec![
+            StanceSwitch::new(StanceCondition::HealthBelowPercent(30), Stance::Berserk),
+            StanceSwitch::new(StanceCondition::HealthBelowPercent(60), Stance::Defensive),
+        ];
+        let tactics = Tactics::new(None, false, Stance::Balanced, switches);
+        let max = warrior.health().max();
+        warrior.health_mut().set(max / 2);
+        assert_eq!(tactics.stance_for(&warrior), Stance::Defensive);
+        warrior.health_mut().set(max / 5);
+        assert_eq!(tactics.stance_for(&warrior), Stance::Berserk);
+    }
+}
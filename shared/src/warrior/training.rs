@@ -0,0 +1,259 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use chrono::{TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::experience::GainExperience;
+use crate::inventory::Inventory;
+use crate::stats::StatKind;
+use crate::tournament::contestant::TournamentContestant;
+
+const SPARRING_GOLD_PER_HOUR: u32 = 5;
+const SPARRING_XP_PER_HOUR: u64 = 8;
+const STAT_PRACTICE_HOURS: u32 = 48;
+const STAT_PRACTICE_COST: u32 = 200;
+const STAT_PRACTICES_PER_LEVEL: u8 = 1;
+
+// Hours a player can send a warrior sparring for
+pub const SPARRING_DURATIONS: [u32; 3] = [4, 12, 24];
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub enum TrainingProgram {
+    // Earns XP, the longer the better
+    Sparring(u32),
+    // Improves a natural stat by one point
+    StatPractice(StatKind),
+}
+
+impl TrainingProgram {
+    pub fn hours(&self) -> u32 {
+        match self {
+            TrainingProgram::Sparring(hours) => *hours,
+            TrainingProgram::StatPractice(_) => STAT_PRACTICE_HOURS,
+        }
+    }
+
+    pub fn cost(&self) -> u32 {
+        match self {
+            TrainingProgram::Sparring(hours) => hours * SPARRING_GOLD_PER_HOUR,
+            TrainingProgram::StatPractice(_) => STAT_PRACTICE_COST,
+        }
+    }
+
+    pub fn xp(&self) -> u64 {
+        match self {
+            TrainingProgram::Sparring(hours) => *hours as u64 * SPARRING_XP_PER_HOUR,
+            TrainingProgram::StatPractice(_) => 0,
+        }
+    }
+}
+
+// Stat practice sessions a warrior can take up to that level
+pub fn stat_practice_limit(level: u8) -> u8 {
+    level.saturating_mul(STAT_PRACTICES_PER_LEVEL)
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Training {
+    program: TrainingProgram,
+    ends_at: i64,
+}
+
+impl Training {
+    pub fn new(program: TrainingProgram) -> Self {
+        let ends_at = Utc::now() + TimeDelta::hours(program.hours() as i64);
+        Self { program, ends_at: ends_at.timestamp() }
+    }
+
+    pub fn program(&self) -> &TrainingProgram {
+        &self.program
+    }
+
+    pub fn ends_at(&self) -> i64 {
+        self.ends_at
+    }
+
+    pub fn seconds_left(&self) -> u64 {
+        (self.ends_at - Utc::now().timestamp()).max(0) as u64
+    }
+
+    pub fn is_complete(&self) -> bool {
+        Utc::now().timestamp() >= self.ends_at
+    }
+}
+
+#[derive(Debug)]
+pub enum TrainingErrorKind {
+    InTournament,
+    AlreadyTraining,
+    InvalidDuration,
+    PracticeLimitReached,
+    NotEnoughGold,
+}
+
+#[derive(Debug)]
+pub struct TrainingError {
+    kind: TrainingErrorKind,
+    message: String,
+}
+
+impl TrainingError {
+    fn new(kind: TrainingErrorKind) -> Self {
+        let message = match kind {
+            TrainingErrorKind::InTournament => "Warrior is registered to a tournament".to_string(),
+            TrainingErrorKind::AlreadyTraining => "Warrior is already training".to_string(),
+            TrainingErrorKind::InvalidDuration => "Training can not last that long".to_string(),
+            TrainingErrorKind::PracticeLimitReached => "Warrior must level up before practicing again".to_string(),
+            TrainingErrorKind::NotEnoughGold => "Not enough gold to pay the trainer".to_string(),
+        };
+        Self { kind, message }
+    }
+
+    pub fn kind(&self) -> &TrainingErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for TrainingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for TrainingError {}
+
+pub trait HasTraining {
+    fn training(&self) -> &Option<Training>;
+    // Last completed program, until the player has seen it
+    fn training_report(&self) -> &Option<TrainingProgram>;
+    fn stat_practices(&self) -> u8;
+}
+
+pub trait Trainee: HasTraining + GainExperience + TournamentContestant {
+    fn training_mut(&mut self) -> &mut Option<Training>;
+    fn training_report_mut(&mut self) -> &mut Option<TrainingProgram>;
+    fn practice_stat(&mut self, stat: &StatKind);
+
+    // server only
+    fn start_training(&mut self, program: TrainingProgram, inventory: &mut Inventory) -> Result<(), TrainingError> {
+        if self.current_tournament().is_some() {
+            return Err(TrainingError::new(TrainingErrorKind::InTournament));
+        }
+        if self.training().is_some() {
+            return Err(TrainingError::new(TrainingErrorKind::AlreadyTraining));
+        }
+        match program {
+            TrainingProgram::Sparring(hours) if !SPARRING_DURATIONS.contains(&hours) => {
+                return Err(TrainingError::new(TrainingErrorKind::InvalidDuration));
+            },
+            TrainingProgram::StatPractice(_) if self.stat_practices() >= stat_practice_limit(self.level()) => {
+                return Err(TrainingError::new(TrainingErrorKind::PracticeLimitReached));
+            },
+            _ => {},
+        }
+        if inventory.gold() < program.cost() {
+            return Err(TrainingError::new(TrainingErrorKind::NotEnoughGold));
+        }
+        inventory.remove_gold(program.cost());
+        self.training_mut().replace(Training::new(program));
+        Ok(())
+    }
+
+    // server only
+    // Returns whether a training session just ended
+    fn complete_training(&mut self) -> bool {
+        if !self.training().as_ref().is_some_and(|training| training.is_complete()) {
+            return false;
+        }
+        let program = self.training_mut().take().unwrap().program;
+        match &program {
+            TrainingProgram::Sparring(_) => self.gain_xp(program.xp()),
+            TrainingProgram::StatPractice(stat) => self.practice_stat(stat),
+        }
+        self.training_report_mut().replace(program);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::Random;
+    use crate::stats::Stats;
+    use crate::experience::{Experience, GainExperience};
+    use crate::warrior::Warrior;
+
+    use super::*;
+
+    fn finish_now(warrior: &mut Warrior) {
+        if let Some(training) = warrior.training_mut() {
+            training.ends_at = Utc::now().timestamp();
+        }
+    }
+
+    #[test]
+    fn sparring_costs_gold_and_grants_xp_once_over() {
+        let mut warrior = Warrior::random();
+        let mut inventory = Inventory::new();
+        let program = TrainingProgram::Sparring(SPARRING_DURATIONS[0]);
+        assert!(matches!(
+            warrior.start_training(program.clone(), &mut inventory).unwrap_err().kind(),
+            TrainingErrorKind::NotEnoughGold,
+        ));
+        inventory.add_gold(program.cost());
+        warrior.start_training(program.clone(), &mut inventory).unwrap();
+        assert_eq!(inventory.gold(), 0);
+        assert!(!warrior.complete_training());
+        finish_now(&mut warrior);
+        assert!(warrior.complete_training());
+        assert_eq!(warrior.xp(), program.xp());
+        assert_eq!(warrior.training_report(), &Some(program));
+        assert!(warrior.training().is_none());
+    }
+
+    #[test]
+    fn stat_practice_improves_natural_stat() {
+        let mut warrior = Warrior::random();
+        let mut inventory = Inventory::new();
+        inventory.add_gold(STAT_PRACTICE_COST);
+        let before = warrior.stats().nat_stat(&StatKind::Strength).value();
+        warrior.start_training(TrainingProgram::StatPractice(StatKind::Strength), &mut inventory).unwrap();
+        finish_now(&mut warrior);
+        warrior.complete_training();
+        assert_eq!(warrior.stats().nat_stat(&StatKind::Strength).value(), before + 1);
+    }
+
+    #[test]
+    fn stat_practice_is_limited_per_level() {
+        let mut warrior = Warrior::random();
+        let mut inventory = Inventory::new();
+        inventory.add_gold(STAT_PRACTICE_COST * 2);
+        warrior.start_training(TrainingProgram::StatPractice(StatKind::Attack), &mut inventory).unwrap();
+        finish_now(&mut warrior);
+        warrior.complete_training();
+        assert!(matches!(
+            warrior.start_training(TrainingProgram::StatPractice(StatKind::Attack), &mut inventory).unwrap_err().kind(),
+            TrainingErrorKind::PracticeLimitReached,
+        ));
+        // Just past the level 2 threshold
+        warrior.gain_xp(101);
+        warrior.level_up().unwrap();
+        warrior.start_training(TrainingProgram::StatPractice(StatKind::Attack), &mut inventory).unwrap();
+    }
+
+    #[test]
+    fn only_one_session_at_a_time() {
+        let mut warrior = Warrior::random();
+        let mut inventory = Inventory::new();
+        inventory.add_gold(1000);
+        assert!(matches!(
+            warrior.start_training(TrainingProgram::Sparring(5), &mut inventory).unwrap_err().kind(),
+            TrainingErrorKind::InvalidDuration,
+        ));
+        warrior.start_training(TrainingProgram::Sparring(SPARRING_DURATIONS[1]), &mut inventory).unwrap();
+        assert!(matches!(
+            warrior.start_training(TrainingProgram::Sparring(SPARRING_DURATIONS[1]), &mut inventory).unwrap_err().kind(),
+            TrainingErrorKind::AlreadyTraining,
+        ));
+    }
+}
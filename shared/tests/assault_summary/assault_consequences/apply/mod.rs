@@ -4,3 +4,4 @@ mod update_misses;
 mod raw_damages;
 mod armor_damages;
 mod sever_arm;
+mod shield_damages;
@@ -0,0 +1,83 @@
+use crate::common::TestAssailant;
+use shared::assault::assault_consequence::{AssaultConsequences, IndividualConsequences};
+use shared::equipment::rupture::{Rupture, RUPTURE_MAX};
+use shared::equipment::shield::{CanHoldShield, OptionalMutableShield, Shield, ShieldKind};
+use shared::equipment::weapon::{OptionalMutableWeapon, Weapon, WeaponKind};
+use shared::inventory::{HasInventory, Item};
+use shared::warrior::body::injury::Injury;
+
+#[test]
+pub fn victim_drops_shield_when_broken() {
+    let consequences = AssaultConsequences::new(
+        IndividualConsequences::no_consequences(),
+        IndividualConsequences::damage_shield(RUPTURE_MAX),
+    );
+    let mut assailant = TestAssailant::new();
+    let mut victim = TestAssailant::new();
+    victim.replace_shield(Shield::new(ShieldKind::Buckler));
+
+    consequences.apply(&mut assailant, &mut victim);
+    assert!(victim.shield().is_none(), "Victim didn't lose his shield");
+    assert_eq!(victim.inventory().items().len(), 0, "Broken shield is in victim's items");
+}
+
+#[test]
+pub fn victim_keeps_shield_if_only_damaged() {
+    let consequences = AssaultConsequences::new(
+        IndividualConsequences::no_consequences(),
+        IndividualConsequences::damage_shield(1),
+    );
+    let mut assailant = TestAssailant::new();
+    let mut victim = TestAssailant::new();
+    let shield = Shield::new(ShieldKind::TowerShield);
+    let shield_rupture = shield.rupture().unwrap();
+    victim.replace_shield(shield);
+
+    consequences.apply(&mut assailant, &mut victim);
+    let shield = victim.shield().as_ref().expect("Victim dropped his shield");
+    assert_eq!(shield.rupture().unwrap(), shield_rupture + 1);
+}
+
+#[test]
+pub fn shield_goes_to_inventory_when_left_arm_is_severed() {
+    let consequences = AssaultConsequences::new(
+        IndividualConsequences::no_consequences(),
+        IndividualConsequences::injures(0, Injury::LeftArmSevered),
+    );
+    let mut assailant = TestAssailant::new();
+    let mut victim = TestAssailant::new();
+    victim.replace_shield(Shield::new(ShieldKind::RoundShield));
+
+    consequences.apply(&mut assailant, &mut victim);
+    assert!(victim.shield().is_none());
+    assert!(victim.inventory().items().values().any(|item| matches!(item, Item::Shield(_))));
+}
+
+#[test]
+pub fn shield_cannot_be_held_with_two_handed_weapon() {
+    let mut warrior = TestAssailant::new();
+    warrior.replace_shield(Shield::new(ShieldKind::Buckler));
+    warrior.weapon_mut().replace(Weapon::new(WeaponKind::Sword));
+    assert!(warrior.can_hold_shield());
+    assert!(warrior.usable_shield().is_some());
+
+    warrior.weapon_mut().replace(Weapon::new(WeaponKind::GreatSword));
+    assert!(!warrior.can_hold_shield());
+    assert!(warrior.usable_shield().is_none());
+}
+
+#[test]
+pub fn shield_cannot_be_held_with_broken_left_hand() {
+    let consequences = AssaultConsequences::new(
+        IndividualConsequences::no_consequences(),
+        IndividualConsequences::injures(0, Injury::LeftHandBroken),
+    );
+    let mut assailant = TestAssailant::new();
+    let mut victim = TestAssailant::new();
+    victim.replace_shield(Shield::new(ShieldKind::Buckler));
+
+    consequences.apply(&mut assailant, &mut victim);
+    assert!(!victim.can_hold_shield());
+    assert!(victim.shield().is_some(), "A broken hand should not drop the shield");
+    assert!(victim.usable_shield().is_none());
+}
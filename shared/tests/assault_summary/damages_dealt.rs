@@ -0,0 +1,42 @@
+use crate::common::TestAssailant;
+use shared::assault::assault_summary::AssaultSummary;
+use shared::equipment::weapon::{OptionalMutableWeapon, Weapon};
+use shared::random::Random;
+use shared::unique_entity::UniqueEntity;
+
+fn armed_assailant() -> TestAssailant {
+    let mut assailant = TestAssailant::new();
+    assailant.weapon_mut().replace(Weapon::random());
+    assailant
+}
+
+#[test]
+fn fumbles_are_not_credited_to_anyone() {
+    for _ in 0..500 {
+        let assailant = armed_assailant();
+        let victim = armed_assailant();
+        let assault = AssaultSummary::new(&assailant, &victim);
+        if assault.attack_clumsiness().is_some() || assault.parry_clumsiness().is_some() {
+            assert_eq!(assault.damages_dealt_by(assailant.uuid()), 0, "Fumble credited to the assailant");
+        }
+        if assault.parry_critical().is_none() {
+            assert_eq!(assault.damages_dealt_by(victim.uuid()), 0, "Victim credited without a counter");
+        }
+    }
+}
+
+#[test]
+fn landed_blows_are_credited_to_the_assailant() {
+    for _ in 0..500 {
+        let assailant = armed_assailant();
+        let victim = armed_assailant();
+        let assault = AssaultSummary::new(&assailant, &victim);
+        if assault.hit_location().is_some() {
+            let for_victim = assault.consequences().for_victim();
+            assert_eq!(
+                assault.damages_dealt_by(assailant.uuid()),
+                for_victim.damages() + for_victim.raw_damages(),
+            );
+        }
+    }
+}
@@ -1,2 +1,3 @@
 mod assault_not_possible;
-mod assault_consequences;
\ No newline at end of file
+mod assault_consequences;
+mod damages_dealt;
\ No newline at end of file
@@ -0,0 +1,69 @@
+use shared::assault::attack_success::ResolveAttackSuccess;
+use shared::assault::common_traits::ReduceDamages;
+use shared::equipment::protection::{OptionalMutableProtection, Protection, ProtectionKind};
+use shared::warrior::body::body_part::{BodyPartKind, BodySide, OptionalMutableBodyPart};
+use shared::warrior::body::{Body, HasMutableBody};
+
+use crate::common::TestAssailant;
+
+fn body_with_breastplate() -> Body {
+    let mut body = Body::new();
+    body.body_part_mut(&BodyPartKind::Torso)
+        .as_mut()
+        .unwrap()
+        .replace_protection(Protection::new(ProtectionKind::Breastplate));
+    body
+}
+
+#[test]
+fn only_struck_body_part_protection_reduces_damages() {
+    let body = body_with_breastplate();
+
+    assert_eq!(body.reduce_damages_at(5, &BodyPartKind::Torso), 2);
+    assert_eq!(body.reduce_damages_at(5, &BodyPartKind::Foot(BodySide::Left)), 5);
+    assert_eq!(body.reduce_damages_at(5, &BodyPartKind::Head), 5);
+}
+
+#[test]
+fn protections_are_not_stacked() {
+    let mut body = body_with_breastplate();
+    body.body_part_mut(&BodyPartKind::Leg(BodySide::Right))
+        .as_mut()
+        .unwrap()
+        .replace_protection(Protection::new(ProtectionKind::Greaves));
+
+    assert_eq!(body.reduce_damages_at(5, &BodyPartKind::Torso), 2);
+    assert_eq!(body.reduce_damages_at(5, &BodyPartKind::Leg(BodySide::Right)), 4);
+}
+
+#[test]
+fn unlocated_damages_go_through_every_protection() {
+    let mut body = body_with_breastplate();
+    body.body_part_mut(&BodyPartKind::Leg(BodySide::Right))
+        .as_mut()
+        .unwrap()
+        .replace_protection(Protection::new(ProtectionKind::Greaves));
+
+    for _ in 0..50 {
+        assert_eq!(body.reduce_damages(5), 1);
+    }
+}
+
+#[test]
+fn hit_only_damages_struck_protection() {
+    let mut victim = TestAssailant::new();
+    *victim.body_mut() = body_with_breastplate();
+
+    for _ in 0..50 {
+        let consequences = victim.resolve_hit_at(5, &BodyPartKind::Arm(BodySide::Left));
+        assert_eq!(consequences.damages(), 5);
+        assert!(consequences.armor_damages().is_none());
+
+        let consequences = victim.resolve_hit_at(5, &BodyPartKind::Torso);
+        assert_eq!(consequences.damages(), 2);
+        if let Some(armor_damages) = consequences.armor_damages() {
+            assert!(matches!(armor_damages.body_part_kind(), BodyPartKind::Torso));
+            assert_eq!(armor_damages.damages(), 1);
+        }
+    }
+}
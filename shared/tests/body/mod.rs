@@ -1 +1,2 @@
-mod stat_modifier_impl;
\ No newline at end of file
+mod stat_modifier_impl;
+mod hit_location;
\ No newline at end of file
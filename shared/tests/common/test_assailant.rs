@@ -14,16 +14,18 @@ use shared::assault::clumsiness::ResolveClumsiness;
 use shared::assault::common_traits::{DealDamages, ReduceDamages, ResolveBreakWeapon, ResolveDropWeapon, ResolveGougeRandomEye, ResolveMissAssaults, TakeDamage};
 use shared::assault::critical_hit::{DealCriticalHit, ResolveCriticalHit, ResolveCriticalHitSelf};
 use shared::assault::critical_parry::{DealCriticalParry, ResolveCriticalParry};
-use shared::assault::duration_damages::{DurationDamages, TakeDurationDamages};
 use shared::assault::attack_not_possible::{CanAttack, CanBeAttacked};
 use shared::assault::parry_attempt::{ParryAttempt, ParryThreshold};
 use shared::assault::parry_clumsiness::ResolveParryClumsiness;
 use shared::assault::parry_success::ResolveParrySuccess;
+use shared::equipment::shield::{OptionalMutableShield, Shield};
 use shared::equipment::weapon::{OptionalMutableWeapon, Weapon};
 use shared::health::{Health, IsDead, IsUnconscious, MutableHealth};
 use shared::knock_out::KnockOut;
 use shared::name::Name;
+use shared::stamina::{HasMutableStamina, HasStamina, Stamina};
 use shared::stats::{StatKind, StatModifier, Stats, StatsManager};
+use shared::status_effect::{HasMutableStatusEffects, HasStatusEffects, StatusEffects};
 use shared::temporary_handicap::{
     OptionalAssaultMisses,
     OptionalMutableAssaultMisses,
@@ -35,6 +37,7 @@ use shared::tournament::contestant::TournamentContestant;
 use shared::unique_entity::UniqueEntity;
 
 use shared::warrior::body::{Body, HasBody, HasMutableBody};
+use shared::warrior::{HasStance, Stance};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TestAssailant {
@@ -42,14 +45,17 @@ pub struct TestAssailant {
     name: String,
     health: Health,
     weapon: Option<Weapon>,
+    shield: Option<Shield>,
     current_tournament: Option<Uuid>,
     assault_misses: Option<TemporaryHandicap>,
     parry_misses: Option<TemporaryHandicap>,
     body: Body,
-    duration_damages: Vec<DurationDamages>,
+    status_effects: StatusEffects,
+    stamina: Stamina,
     stats: StatsManager,
     is_unconscious: bool,
     inventory: Inventory,
+    stance: Stance,
 }
 
 impl TestAssailant {
@@ -59,18 +65,36 @@ impl TestAssailant {
             name: String::from("Rando"),
             health: Health::new(30, 30),
             weapon: None,
+            shield: None,
             current_tournament: None,
             assault_misses: None,
             parry_misses: None,
             body: Body::new(),
-            duration_damages: vec![],
+            status_effects: StatusEffects::default(),
+            stamina: Stamina::default(),
             stats: StatsManager::random(),
             is_unconscious: false,
             inventory: Inventory::new(),
+            stance: Stance::Balanced,
         }
     }
 }
 
+impl OptionalMutableShield for TestAssailant {
+    fn shield(&self) -> &Option<Shield> {
+        &self.shield
+    }
+    fn shield_mut(&mut self) -> &mut Option<Shield> {
+        &mut self.shield
+    }
+}
+
+impl HasStance for TestAssailant {
+    fn stance(&self) -> &Stance {
+        &self.stance
+    }
+}
+
 impl UniqueEntity for TestAssailant {
     fn uuid(&self) -> &Uuid {
         &self.uuid
@@ -154,12 +178,27 @@ impl HasMutableBody for TestAssailant {
     }
 }
 
-impl TakeDurationDamages for TestAssailant {
-    fn duration_damages(&self) -> &Vec<DurationDamages> {
-        &self.duration_damages
+impl HasStamina for TestAssailant {
+    fn stamina(&self) -> &Stamina {
+        &self.stamina
     }
-    fn duration_damages_mut(&mut self) -> &mut Vec<DurationDamages> {
-        &mut self.duration_damages
+}
+
+impl HasMutableStamina for TestAssailant {
+    fn stamina_mut(&mut self) -> &mut Stamina {
+        &mut self.stamina
+    }
+}
+
+impl HasStatusEffects for TestAssailant {
+    fn status_effects(&self) -> &StatusEffects {
+        &self.status_effects
+    }
+}
+
+impl HasMutableStatusEffects for TestAssailant {
+    fn status_effects_mut(&mut self) -> &mut StatusEffects {
+        &mut self.status_effects
     }
 }
 
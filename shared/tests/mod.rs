@@ -1,3 +1,4 @@
 mod common;
 mod assault_summary;
-mod body;
\ No newline at end of file
+mod body;
+mod turn_summary;
\ No newline at end of file
@@ -0,0 +1,28 @@
+use crate::common::TestAssailant;
+use shared::equipment::weapon::{OptionalMutableWeapon, Weapon};
+use shared::random::Random;
+use shared::replay::turn_summary::TurnSummary;
+use shared::unique_entity::UniqueEntity;
+
+fn armed_assailant() -> TestAssailant {
+    let mut assailant = TestAssailant::new();
+    assailant.weapon_mut().replace(Weapon::random());
+    assailant
+}
+
+#[test]
+fn the_opening_assault_always_counts_as_an_attack() {
+    let mut first_assailant = armed_assailant();
+    let mut second_assailant = armed_assailant();
+    let turn = TurnSummary::new(&mut first_assailant, &mut second_assailant);
+    assert_eq!(turn.attacks_made_by(first_assailant.uuid()), 1);
+}
+
+#[test]
+fn impossible_attacks_are_not_counted() {
+    let mut first_assailant = armed_assailant();
+    let mut second_assailant = TestAssailant::new();
+    let turn = TurnSummary::new(&mut first_assailant, &mut second_assailant);
+    assert_eq!(turn.attacks_made_by(first_assailant.uuid()), 0);
+    assert_eq!(turn.attacks_made_by(second_assailant.uuid()), 0);
+}
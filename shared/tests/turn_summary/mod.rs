@@ -0,0 +1,2 @@
+mod attacks_made;
+mod status_effects;
@@ -0,0 +1,44 @@
+use crate::common::TestAssailant;
+use shared::assault::assault_consequence::IndividualConsequences;
+use shared::assault::end_turn_consequences::EndTurnConsequencesBuilder;
+use shared::equipment::weapon::{OptionalMutableWeapon, Weapon};
+use shared::random::Random;
+use shared::replay::turn_summary::TurnSummary;
+use shared::status_effect::{HasStatusEffects, StatusEffectKind};
+
+fn armed_assailant() -> TestAssailant {
+    let mut assailant = TestAssailant::new();
+    assailant.weapon_mut().replace(Weapon::random());
+    assailant
+}
+
+fn stuns(consequences: &IndividualConsequences) -> bool {
+    consequences.status_effect().as_ref().is_some_and(|effect| effect.kind() == &StatusEffectKind::Stunned)
+}
+
+#[test]
+fn stun_from_the_second_assault_lasts_through_the_next_turn() {
+    let mut stuns_seen = 0;
+    for _ in 0..20_000 {
+        let mut first_assailant = armed_assailant();
+        let mut second_assailant = armed_assailant();
+        let turn = TurnSummary::new(&mut first_assailant, &mut second_assailant);
+        let second_assault = turn.assaults()[1].consequences();
+        // The second assault is led by the second assailant
+        if stuns(second_assault.for_victim()) {
+            stuns_seen += 1;
+            assert!(first_assailant.status_effects().has(&StatusEffectKind::Stunned));
+            assert!(!turn.first_assailant_turn_end().expired_effects().contains(&StatusEffectKind::Stunned));
+            first_assailant.end_turn().apply(&mut first_assailant);
+            assert!(!first_assailant.status_effects().has(&StatusEffectKind::Stunned));
+        }
+        if stuns(second_assault.for_assailant()) {
+            stuns_seen += 1;
+            assert!(second_assailant.status_effects().has(&StatusEffectKind::Stunned));
+            assert!(!turn.second_assailant_turn_end().expired_effects().contains(&StatusEffectKind::Stunned));
+            second_assailant.end_turn().apply(&mut second_assailant);
+            assert!(!second_assailant.status_effects().has(&StatusEffectKind::Stunned));
+        }
+    }
+    assert!(stuns_seen > 0, "No stun was sampled");
+}